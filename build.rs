@@ -0,0 +1,39 @@
+/// The last minor version of GSL 2 with a `v2_*` feature.
+const LAST_MINOR: u32 = 7;
+
+fn main() {
+    // Exported by the build script of GSL-sys.
+    let version = std::env::var("DEP_GSL_VERSION").unwrap_or_default();
+    println!("cargo:rustc-env=RGSL_BUILD_GSL_VERSION={}", version);
+    for minor in 1..=LAST_MINOR {
+        println!("cargo:rustc-check-cfg=cfg(gsl_v2_{})", minor);
+    }
+
+    // GSL-sys only sets it when it enabled the `gsl_v2_*` cfgs of the detected version, which this crate mirrors.
+    let minor = std::env::var("DEP_GSL_CFG_MINOR")
+        .ok()
+        .and_then(|minor| minor.parse::<u32>().ok())
+        .unwrap_or(0);
+    for m in 1..=minor.min(LAST_MINOR) {
+        println!("cargo:rustc-cfg=gsl_v2_{}", m);
+    }
+
+    // The `char` and `long` containers of GSL only map to a Rust integer type on some targets.
+    let target = |key: &str| std::env::var(format!("CARGO_CFG_TARGET_{}", key)).unwrap_or_default();
+    let windows = target("FAMILY")
+        .split(',')
+        .any(|family| family == "windows");
+    println!("cargo:rustc-check-cfg=cfg(gsl_char_signed)");
+    println!("cargo:rustc-check-cfg=cfg(gsl_long_64)");
+    // C `char` is only known to be signed on these targets.
+    if ["x86", "x86_64"].contains(&target("ARCH").as_str())
+        || target("VENDOR") == "apple"
+        || windows
+    {
+        println!("cargo:rustc-cfg=gsl_char_signed");
+    }
+    // C `long` has 64 bits on the 64-bit targets, except on Windows.
+    if target("POINTER_WIDTH") == "64" && !windows {
+        println!("cargo:rustc-cfg=gsl_long_64");
+    }
+}
@@ -4,7 +4,7 @@
 
 extern crate rgsl;
 
-use rgsl::{multilinear, stats};
+use rgsl::{multilinear, statistics};
 use rgsl::{BSpLineWorkspace, MatrixF64, MultifitLinearWorkspace, Rng, RngType, VectorF64};
 
 const N: usize = 200;
@@ -64,11 +64,12 @@ fn main() {
     let (_, chisq) = mw.wlinear(&mat_x, &w, &y, &mut c, &mut cov);
 
     let dof = N - NCOEFFS;
-    let tss = stats::wtss(
+    let tss = statistics::wtss(
         w.as_slice().expect("as_slice failed"),
         1,
         y.as_slice().expect("as_slice failed"),
         1,
+        N,
     );
     let rsq = 1. - chisq / tss;
 
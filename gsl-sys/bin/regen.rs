@@ -94,6 +94,7 @@ const VERSIONS: &[(&str, &str)] = &[
     ("pub fn gsl_spmatrix_norm1(", "v2_7"),
     ("pub fn gsl_matrix_complex_conjtrans_memcpy(", "v2_7"),
     ("pub fn gsl_linalg_QL_*(", "v2_7"),
+    ("pub fn gsl_linalg_QR_U*(", "v2_7"),
     ("pub fn gsl_linalg_complex_QR_*(", "v2_7"),
     ("pub fn gsl_vector_sum(", "v2_7"),
     ("pub fn gsl_matrix_scale_rows(", "v2_7"),
@@ -310,7 +311,7 @@ fn add_features(content: &mut Vec<String>) {
             println!("==> Adding feature for {:?}", versions[pos].3);
             line.insert_str(
                 0,
-                &format!("{0}#[cfg(feature = \"{1}\")]\n\
+                &format!("{0}#[cfg(any(feature = \"{1}\", gsl_{1}))]\n\
                     {0}#[cfg_attr(feature = \"dox\", doc(cfg(feature = \"{1}\")))]\n",
                 tmp_str, versions[pos].1),
             );
@@ -1,16 +1,142 @@
+#[cfg(feature = "vendored")]
+extern crate cc;
 extern crate pkg_config;
 
+#[cfg(feature = "vendored")]
+mod vendored;
+
+/// CBLAS implementations which can replace `gslcblas`, in order of preference: the cargo feature enabling it, its
+/// pkg-config package and the library to link if pkg-config doesn't know it.
+const BACKENDS: &[(&str, &str, &str)] = &[
+    ("CARGO_FEATURE_CBLAS_OPENBLAS", "openblas", "openblas"),
+    ("CARGO_FEATURE_CBLAS_BLIS", "blis", "blis"),
+    ("CARGO_FEATURE_CBLAS_SYSTEM", "cblas", "cblas"),
+];
+
+/// The last minor version of GSL 2 with a `v2_*` feature.
+const LAST_MINOR: u32 = 7;
+
 fn main() {
-    if std::process::Command::new("pkg-config").output().is_err() {
-        println!("cargo:rustc-link-lib=gsl");
-        println!("cargo:rustc-link-lib=gslcblas");
-        return;
+    let enabled = BACKENDS
+        .iter()
+        .filter(|(feature, _, _)| std::env::var_os(feature).is_some())
+        .collect::<Vec<_>>();
+    if enabled.len() > 1 {
+        println!(
+            "cargo:warning=several CBLAS backends are enabled, using {}",
+            enabled[0].1
+        );
+    }
+    let backend = enabled.first().map(|&&(_, package, lib)| (package, lib));
+    println!(
+        "cargo:rustc-env=GSL_SYS_CBLAS_BACKEND={}",
+        backend.map_or("gslcblas", |(package, _)| package)
+    );
+
+    // The vendored build compiles `gslcblas` too, unless another CBLAS is used.
+    #[cfg(feature = "vendored")]
+    vendored::build(backend.is_none());
+    #[cfg(not(feature = "vendored"))]
+    link_gsl(backend.is_some());
+
+    if let Some((package, lib)) = backend {
+        link_library(package, lib);
+    } else if cfg!(not(feature = "vendored")) {
+        link_library("gslcblas", "gslcblas");
     }
 
-    if pkg_config::probe_library("gsl").is_err() {
-        println!("cargo:rustc-link-lib=gsl");
+    check_version();
+}
+
+/// Returns the version of the GSL library which will be linked, if it can be found.
+fn detected_version() -> Option<String> {
+    if cfg!(feature = "vendored") {
+        return Some("2.7".to_owned());
+    }
+    if has_pkg_config() {
+        if let Ok(gsl) = pkg_config::Config::new().cargo_metadata(false).probe("gsl") {
+            return Some(gsl.version);
+        }
+    }
+    let output = std::process::Command::new("gsl-config")
+        .arg("--version")
+        .output()
+        .ok()?;
+    Some(String::from_utf8(output.stdout).ok()?.trim().to_owned()).filter(|v| !v.is_empty())
+}
+
+/// Parses the major and minor numbers of a version like `2.7.1` or `2.6+`.
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts
+        .next()?
+        .trim_end_matches(|c: char| !c.is_ascii_digit())
+        .parse()
+        .ok()?;
+    Some((major, minor))
+}
+
+/// Warns when the enabled `v2_*` feature needs a more recent GSL than the detected one. When no such feature is
+/// enabled, emits the `gsl_v2_*` cfgs matching the detected version instead, which gate the same items as the features.
+/// The version is also exported to the build scripts of the dependent crates as `DEP_GSL_VERSION`.
+fn check_version() {
+    for minor in 1..=LAST_MINOR {
+        println!("cargo:rustc-check-cfg=cfg(gsl_v2_{})", minor);
+    }
+    let version = match detected_version() {
+        Some(version) => version,
+        None => return,
+    };
+    println!("cargo:version={}", version);
+    let (major, minor) = match parse_version(&version) {
+        Some((2, minor)) => (2, minor),
+        Some((major, _)) if major > 2 => (major, LAST_MINOR),
+        _ => return,
+    };
+    let enabled = (1..=LAST_MINOR)
+        .filter(|m| std::env::var_os(format!("CARGO_FEATURE_V2_{}", m)).is_some())
+        .max();
+    match enabled {
+        Some(enabled) if major == 2 && enabled > minor => println!(
+            "cargo:warning=the `v2_{}` feature needs GSL 2.{}, but GSL {} was found: linking will likely fail",
+            enabled, enabled, version
+        ),
+        Some(_) => {}
+        None => {
+            let minor = minor.min(LAST_MINOR);
+            for m in 1..=minor {
+                println!("cargo:rustc-cfg=gsl_v2_{}", m);
+            }
+            // Lets the dependent crates emit the same cfgs, as `DEP_GSL_CFG_MINOR`.
+            println!("cargo:cfg_minor={}", minor);
+        }
+    }
+}
+
+fn has_pkg_config() -> bool {
+    std::process::Command::new("pkg-config").output().is_ok()
+}
+
+fn link_library(package: &str, lib: &str) {
+    if !has_pkg_config() || pkg_config::probe_library(package).is_err() {
+        println!("cargo:rustc-link-lib={}", lib);
+    }
+}
+
+#[cfg(not(feature = "vendored"))]
+fn link_gsl(other_cblas: bool) {
+    if !other_cblas {
+        link_library("gsl", "gsl");
+        return;
     }
-    if pkg_config::probe_library("gslcblas").is_err() {
-        println!("cargo:rustc-link-lib=gslcblas");
+    // `gsl.pc` lists `gslcblas` in its libraries, so only its search paths are kept.
+    if has_pkg_config() {
+        if let Ok(gsl) = pkg_config::Config::new().cargo_metadata(false).probe("gsl") {
+            for path in gsl.link_paths {
+                println!("cargo:rustc-link-search=native={}", path.display());
+            }
+        }
     }
+    println!("cargo:rustc-link-lib=gsl");
 }
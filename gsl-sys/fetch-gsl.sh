@@ -0,0 +1,16 @@
+#!/bin/sh
+# Downloads the GSL release tarball used by the `vendored` feature, if it isn't there yet, and checks it against the
+# committed checksum.
+set -e
+cd "$(dirname "$0")"
+TARBALL=gsl-2.7.1.tar.gz
+
+if [ ! -f "$TARBALL" ]; then
+    curl -fL -o "$TARBALL.part" "https://ftp.gnu.org/gnu/gsl/$TARBALL"
+    mv "$TARBALL.part" "$TARBALL"
+fi
+if command -v sha256sum > /dev/null; then
+    sha256sum -c "$TARBALL.sha256"
+else
+    shasum -a 256 -c "$TARBALL.sha256"
+fi
@@ -842,7 +842,7 @@ pub type gsl_function_fdf = gsl_function_fdf_struct;
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_function_vec_struct;
 pub type gsl_function_vec = gsl_function_vec_struct;
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub type gsl_bst_cmp_function = ::std::option::Option<
     unsafe extern "C" fn(
@@ -853,66 +853,66 @@ pub type gsl_bst_cmp_function = ::std::option::Option<
 >;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub struct gsl_bst_allocator;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub struct gsl_bst_avl_node;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub struct gsl_bst_avl_table;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub struct gsl_bst_avl_traverser;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub struct gsl_bst_rb_node;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub struct gsl_bst_rb_table;
 #[repr(C)]
 #[derive(Copy, Clone)]
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub struct gsl_bst_rb_traverser;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub struct gsl_bst_type;
 #[repr(C)]
 #[derive(Copy, Clone)]
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub struct gsl_bst_workspace;
 #[repr(C)]
 #[derive(Copy, Clone)]
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub struct gsl_bst_trav;
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub static mut gsl_bst_avl: *const gsl_bst_type;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub static mut gsl_bst_rb: *const gsl_bst_type;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_alloc(
         T: *const gsl_bst_type,
@@ -922,17 +922,17 @@ extern "C" {
     ) -> *mut gsl_bst_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_free(w: *mut gsl_bst_workspace);
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_empty(w: *mut gsl_bst_workspace) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_insert(
         item: *mut ::std::os::raw::c_void,
@@ -940,7 +940,7 @@ extern "C" {
     ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_find(
         item: *const ::std::os::raw::c_void,
@@ -948,7 +948,7 @@ extern "C" {
     ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_remove(
         item: *const ::std::os::raw::c_void,
@@ -956,22 +956,22 @@ extern "C" {
     ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_nodes(w: *const gsl_bst_workspace) -> usize;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_node_size(w: *const gsl_bst_workspace) -> usize;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_name(w: *const gsl_bst_workspace) -> *const ::std::os::raw::c_char;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_init(
         trav: *mut gsl_bst_trav,
@@ -979,7 +979,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_first(
         trav: *mut gsl_bst_trav,
@@ -987,7 +987,7 @@ extern "C" {
     ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_last(
         trav: *mut gsl_bst_trav,
@@ -995,7 +995,7 @@ extern "C" {
     ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_find(
         item: *const ::std::os::raw::c_void,
@@ -1004,7 +1004,7 @@ extern "C" {
     ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_insert(
         item: *mut ::std::os::raw::c_void,
@@ -1013,7 +1013,7 @@ extern "C" {
     ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_copy(
         dest: *mut gsl_bst_trav,
@@ -1021,22 +1021,22 @@ extern "C" {
     ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_next(trav: *mut gsl_bst_trav) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_prev(trav: *mut gsl_bst_trav) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_cur(trav: *const gsl_bst_trav) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_replace(
         trav: *mut gsl_bst_trav,
@@ -4505,7 +4505,7 @@ extern "C" {
     pub fn gsl_vector_add_constant(a: *mut gsl_vector, x: f64) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_vector_axpby(
         alpha: f64,
@@ -4515,7 +4515,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_vector_sum(a: *const gsl_vector) -> f64;
 }
@@ -5188,7 +5188,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_matrix_complex_conjtrans_memcpy(
         dest: *mut gsl_matrix_complex,
@@ -7861,7 +7861,7 @@ extern "C" {
     pub fn gsl_matrix_isnonneg(m: *const gsl_matrix) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_matrix_norm1(m: *const gsl_matrix) -> f64;
 }
@@ -7887,13 +7887,13 @@ extern "C" {
     pub fn gsl_matrix_scale(a: *mut gsl_matrix, x: f64) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_matrix_scale_rows(a: *mut gsl_matrix, x: *const gsl_vector)
         -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_matrix_scale_columns(
         a: *mut gsl_matrix,
@@ -8049,7 +8049,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_spmatrix_min_index(
         m: *const gsl_spmatrix,
@@ -8061,7 +8061,7 @@ extern "C" {
     pub fn gsl_spmatrix_scale(m: *mut gsl_spmatrix, x: f64) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_spmatrix_scale_columns(
         m: *mut gsl_spmatrix,
@@ -8069,7 +8069,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_spmatrix_scale_rows(
         m: *mut gsl_spmatrix,
@@ -8090,7 +8090,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_spmatrix_dense_sub(
         a: *mut gsl_matrix,
@@ -8104,7 +8104,7 @@ extern "C" {
     pub fn gsl_spmatrix_sp2d(A: *mut gsl_matrix, S: *const gsl_spmatrix) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_spmatrix_add_to_dense(
         a: *mut gsl_matrix,
@@ -8118,7 +8118,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_spmatrix_norm1(a: *const gsl_spmatrix) -> f64;
 }
@@ -17072,7 +17072,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_3")]
+    #[cfg(any(feature = "v2_3", gsl_v2_3))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_multifit_linear_tsvd(
         X: *const gsl_matrix,
@@ -17098,7 +17098,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_3")]
+    #[cfg(any(feature = "v2_3", gsl_v2_3))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_multifit_linear_rank(tol: f64, work: *const gsl_multifit_linear_workspace) -> usize;
 }
@@ -17261,7 +17261,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_3")]
+    #[cfg(any(feature = "v2_3", gsl_v2_3))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_multifit_wlinear_tsvd(
         X: *const gsl_matrix,
@@ -17311,7 +17311,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multifit_linear_rcond(w: *const gsl_multifit_linear_workspace) -> f64;
 }
@@ -18460,7 +18460,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_permute_matrix(
         p: *const gsl_permutation,
@@ -20687,7 +20687,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_LU_band_decomp(
         M: usize,
@@ -20698,7 +20698,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_LU_band_solve(
         lb: usize,
@@ -20710,7 +20710,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_LU_band_svx(
         lb: usize,
@@ -20721,7 +20721,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_LU_band_unpack(
         M: usize,
@@ -20950,7 +20950,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_decomp(
         A: *mut gsl_matrix_complex,
@@ -20958,7 +20958,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_decomp_r(
         A: *mut gsl_matrix_complex,
@@ -20966,7 +20966,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_solve(
         QR: *const gsl_matrix_complex,
@@ -20976,7 +20976,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_solve_r(
         QR: *const gsl_matrix_complex,
@@ -20986,7 +20986,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_svx(
         QR: *const gsl_matrix_complex,
@@ -20995,7 +20995,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_lssolve(
         QR: *const gsl_matrix_complex,
@@ -21006,7 +21006,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_lssolve_r(
         QR: *const gsl_matrix_complex,
@@ -21017,7 +21017,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_QHvec(
         QR: *const gsl_matrix_complex,
@@ -21026,7 +21026,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_QHvec_r(
         QR: *const gsl_matrix_complex,
@@ -21036,7 +21036,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_Qvec(
         QR: *const gsl_matrix_complex,
@@ -21045,7 +21045,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_unpack(
         QR: *const gsl_matrix_complex,
@@ -21055,7 +21055,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_complex_QR_unpack_r(
         QR: *const gsl_matrix_complex,
@@ -21113,7 +21113,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_QRPT_lssolve(
         QR: *const gsl_matrix,
@@ -21125,7 +21125,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_QRPT_lssolve2(
         QR: *const gsl_matrix,
@@ -21182,7 +21182,7 @@ extern "C" {
     pub fn gsl_linalg_QRPT_rank(QR: *const gsl_matrix, tol: f64) -> usize;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_QRPT_rcond(
         QR: *const gsl_matrix,
@@ -21198,12 +21198,12 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_QL_decomp(A: *mut gsl_matrix, tau: *mut gsl_vector) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_QL_unpack(
         QL: *const gsl_matrix,
@@ -21213,7 +21213,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_COD_decomp(
         A: *mut gsl_matrix,
@@ -21225,7 +21225,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_COD_decomp_e(
         A: *mut gsl_matrix,
@@ -21238,7 +21238,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_COD_lssolve(
         QRZT: *const gsl_matrix,
@@ -21252,7 +21252,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_COD_lssolve2(
         lambda: f64,
@@ -21269,7 +21269,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_COD_unpack(
         QRZT: *const gsl_matrix,
@@ -21282,7 +21282,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_COD_matZ(
         QRZT: *const gsl_matrix,
@@ -21296,7 +21296,7 @@ extern "C" {
     pub fn gsl_linalg_LQ_decomp(A: *mut gsl_matrix, tau: *mut gsl_vector) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_LQ_lssolve(
         LQ: *const gsl_matrix,
@@ -21538,7 +21538,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_cholesky_rcond(
         LLT: *const gsl_matrix,
@@ -21568,7 +21568,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_pcholesky_decomp(
         A: *mut gsl_matrix,
@@ -21576,7 +21576,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_pcholesky_solve(
         LDLT: *const gsl_matrix,
@@ -21586,7 +21586,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_pcholesky_svx(
         LDLT: *const gsl_matrix,
@@ -21595,7 +21595,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_pcholesky_decomp2(
         A: *mut gsl_matrix,
@@ -21604,7 +21604,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_pcholesky_solve2(
         LDLT: *const gsl_matrix,
@@ -21615,7 +21615,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_pcholesky_svx2(
         LDLT: *const gsl_matrix,
@@ -21625,7 +21625,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_pcholesky_invert(
         LDLT: *const gsl_matrix,
@@ -21634,7 +21634,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_pcholesky_rcond(
         LDLT: *const gsl_matrix,
@@ -21644,7 +21644,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_mcholesky_decomp(
         A: *mut gsl_matrix,
@@ -21653,7 +21653,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_mcholesky_solve(
         LDLT: *const gsl_matrix,
@@ -21663,7 +21663,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_mcholesky_svx(
         LDLT: *const gsl_matrix,
@@ -21672,7 +21672,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_mcholesky_rcond(
         LDLT: *const gsl_matrix,
@@ -21682,7 +21682,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_mcholesky_invert(
         LDLT: *const gsl_matrix,
@@ -21691,12 +21691,12 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_cholesky_band_decomp(A: *mut gsl_matrix) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_cholesky_band_solve(
         LLT: *const gsl_matrix,
@@ -21705,7 +21705,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_cholesky_band_svx(
         LLT: *const gsl_matrix,
@@ -21713,7 +21713,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_cholesky_band_solvem(
         LLT: *const gsl_matrix,
@@ -21722,7 +21722,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_linalg_cholesky_band_svxm(
         LLT: *const gsl_matrix,
@@ -21730,7 +21730,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_cholesky_band_invert(
         LLT: *const gsl_matrix,
@@ -21738,7 +21738,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_cholesky_band_unpack(
         LLT: *const gsl_matrix,
@@ -21746,7 +21746,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_cholesky_band_rcond(
         LLT: *const gsl_matrix,
@@ -21755,12 +21755,12 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_ldlt_decomp(A: *mut gsl_matrix) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_ldlt_solve(
         LDLT: *const gsl_matrix,
@@ -21769,7 +21769,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_ldlt_svx(
         LDLT: *const gsl_matrix,
@@ -21777,7 +21777,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_ldlt_rcond(
         LDLT: *const gsl_matrix,
@@ -21786,12 +21786,12 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_ldlt_band_decomp(A: *mut gsl_matrix) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_ldlt_band_solve(
         LDLT: *const gsl_matrix,
@@ -21800,7 +21800,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_ldlt_band_svx(
         LDLT: *const gsl_matrix,
@@ -21808,7 +21808,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_ldlt_band_unpack(
         LDLT: *const gsl_matrix,
@@ -21817,7 +21817,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_6")]
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_ldlt_band_rcond(
         LDLT: *const gsl_matrix,
@@ -21965,7 +21965,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_tri_rcond(
         Uplo: CBLAS_UPLO_t,
@@ -21975,7 +21975,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_tri_upper_rcond(
         A: *const gsl_matrix,
@@ -21984,7 +21984,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_tri_lower_rcond(
         A: *const gsl_matrix,
@@ -22008,27 +22008,27 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_tri_upper_invert(T: *mut gsl_matrix) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_tri_lower_invert(T: *mut gsl_matrix) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_tri_upper_unit_invert(T: *mut gsl_matrix) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_tri_lower_unit_invert(T: *mut gsl_matrix) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_tri_invert(
         Uplo: CBLAS_UPLO_t,
@@ -22044,12 +22044,12 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_tri_LTL(L: *mut gsl_matrix) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_linalg_tri_UL(LU: *mut gsl_matrix) -> ::std::os::raw::c_int;
 }
@@ -25345,45 +25345,45 @@ extern "C" {
         compare: gsl_comparison_fn_t,
     ) -> ::std::os::raw::c_int;
 }
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_movstat_end_t_GSL_MOVSTAT_END_PADZERO: gsl_movstat_end_t = 0;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_movstat_end_t_GSL_MOVSTAT_END_PADVALUE: gsl_movstat_end_t = 1;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_movstat_end_t_GSL_MOVSTAT_END_TRUNCATE: gsl_movstat_end_t = 2;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub type gsl_movstat_end_t = ::std::os::raw::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub struct gsl_movstat_accum;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub struct gsl_movstat_function;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub struct gsl_movstat_workspace;
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_alloc(K: usize) -> *mut gsl_movstat_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_alloc2(H: usize, J: usize) -> *mut gsl_movstat_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_alloc_with_size(
         accum_state_size: usize,
@@ -25392,12 +25392,12 @@ extern "C" {
     ) -> *mut gsl_movstat_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_free(w: *mut gsl_movstat_workspace);
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_apply_accum(
         endtype: gsl_movstat_end_t,
@@ -25410,7 +25410,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_apply(
         endtype: gsl_movstat_end_t,
@@ -25421,7 +25421,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_fill(
         endtype: gsl_movstat_end_t,
@@ -25433,7 +25433,7 @@ extern "C" {
     ) -> usize;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_mean(
         endtype: gsl_movstat_end_t,
@@ -25443,7 +25443,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_variance(
         endtype: gsl_movstat_end_t,
@@ -25453,7 +25453,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_sd(
         endtype: gsl_movstat_end_t,
@@ -25463,7 +25463,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_median(
         endtype: gsl_movstat_end_t,
@@ -25473,7 +25473,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_min(
         endtype: gsl_movstat_end_t,
@@ -25483,7 +25483,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_max(
         endtype: gsl_movstat_end_t,
@@ -25493,7 +25493,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_minmax(
         endtype: gsl_movstat_end_t,
@@ -25504,7 +25504,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_mad0(
         endtype: gsl_movstat_end_t,
@@ -25515,7 +25515,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_mad(
         endtype: gsl_movstat_end_t,
@@ -25526,7 +25526,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_qqr(
         endtype: gsl_movstat_end_t,
@@ -25537,7 +25537,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_Sn(
         endtype: gsl_movstat_end_t,
@@ -25547,7 +25547,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_Qn(
         endtype: gsl_movstat_end_t,
@@ -25557,7 +25557,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_movstat_sum(
         endtype: gsl_movstat_end_t,
@@ -25567,114 +25567,114 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_mad: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_max: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_mean: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_median: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_min: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_minmax: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_sd: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_Sn: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_sum: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_Qn: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_qqr: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_userfunc: *const gsl_movstat_accum;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub static mut gsl_movstat_accum_variance: *const gsl_movstat_accum;
 }
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_filter_end_t_GSL_FILTER_END_PADZERO: gsl_filter_end_t = 0;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_filter_end_t_GSL_FILTER_END_PADVALUE: gsl_filter_end_t = 1;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_filter_end_t_GSL_FILTER_END_TRUNCATE: gsl_filter_end_t = 2;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub type gsl_filter_end_t = ::std::os::raw::c_uint;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_filter_scale_t_GSL_FILTER_SCALE_MAD: gsl_filter_scale_t = 0;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_filter_scale_t_GSL_FILTER_SCALE_IQR: gsl_filter_scale_t = 1;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_filter_scale_t_GSL_FILTER_SCALE_SN: gsl_filter_scale_t = 2;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_filter_scale_t_GSL_FILTER_SCALE_QN: gsl_filter_scale_t = 3;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub type gsl_filter_scale_t = ::std::os::raw::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub struct gsl_filter_gaussian_workspace;
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_gaussian_alloc(K: usize) -> *mut gsl_filter_gaussian_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_gaussian_free(w: *mut gsl_filter_gaussian_workspace);
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_gaussian(
         endtype: gsl_filter_end_t,
@@ -25686,7 +25686,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_gaussian_kernel(
         alpha: f64,
@@ -25697,21 +25697,21 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub struct gsl_filter_median_workspace;
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_median_alloc(K: usize) -> *mut gsl_filter_median_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_median_free(w: *mut gsl_filter_median_workspace);
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_median(
         endtype: gsl_filter_end_t,
@@ -25722,21 +25722,21 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub struct gsl_filter_rmedian_workspace;
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_rmedian_alloc(K: usize) -> *mut gsl_filter_rmedian_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_rmedian_free(w: *mut gsl_filter_rmedian_workspace);
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_rmedian(
         arg1: gsl_filter_end_t,
@@ -25747,21 +25747,21 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub struct gsl_filter_impulse_workspace;
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_impulse_alloc(K: usize) -> *mut gsl_filter_impulse_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_impulse_free(w: *mut gsl_filter_impulse_workspace);
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_filter_impulse(
         endtype: gsl_filter_end_t,
@@ -26094,26 +26094,26 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub struct gsl_multilarge_linear_type;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub struct gsl_multilarge_linear_workspace;
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_linear_normal: *const gsl_multilarge_linear_type;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_linear_tsqr: *const gsl_multilarge_linear_type;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_alloc(
         T: *const gsl_multilarge_linear_type,
@@ -26121,26 +26121,26 @@ extern "C" {
     ) -> *mut gsl_multilarge_linear_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_free(w: *mut gsl_multilarge_linear_workspace);
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_name(
         w: *const gsl_multilarge_linear_workspace,
     ) -> *const ::std::os::raw::c_char;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_reset(
         w: *mut gsl_multilarge_linear_workspace,
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_accumulate(
         X: *mut gsl_matrix,
@@ -26149,7 +26149,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_solve(
         lambda: f64,
@@ -26160,7 +26160,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_rcond(
         rcond: *mut f64,
@@ -26168,7 +26168,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_multilarge_linear_lcurve(
         reg_param: *mut gsl_vector,
@@ -26178,7 +26178,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_wstdform1(
         L: *const gsl_vector,
@@ -26191,7 +26191,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_stdform1(
         L: *const gsl_vector,
@@ -26203,7 +26203,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_L_decomp(
         L: *mut gsl_matrix,
@@ -26211,7 +26211,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_wstdform2(
         LQR: *const gsl_matrix,
@@ -26225,7 +26225,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_stdform2(
         LQR: *const gsl_matrix,
@@ -26238,7 +26238,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_genform1(
         L: *const gsl_vector,
@@ -26248,7 +26248,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_genform2(
         LQR: *const gsl_matrix,
@@ -26259,14 +26259,14 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_multilarge_linear_matrix_ptr(
         work: *const gsl_multilarge_linear_workspace,
     ) -> *const gsl_matrix;
 }
 extern "C" {
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     pub fn gsl_multilarge_linear_rhs_ptr(
         work: *const gsl_multilarge_linear_workspace,
@@ -28218,7 +28218,7 @@ extern "C" {
     );
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_stats_select(data: *mut f64, stride: usize, n: usize, k: usize) -> f64;
 }
@@ -28230,7 +28230,7 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_stats_median(sorted_data: *mut f64, stride: usize, n: usize) -> f64;
 }
@@ -28243,7 +28243,7 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_stats_trmean_from_sorted_data(
         trim: f64,
@@ -28253,7 +28253,7 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_stats_gastwirth_from_sorted_data(
         sorted_data: *const f64,
@@ -28262,12 +28262,12 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_stats_mad0(data: *const f64, stride: usize, n: usize, work: *mut f64) -> f64;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_stats_mad(data: *const f64, stride: usize, n: usize, work: *mut f64) -> f64;
 }
@@ -28280,7 +28280,7 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_stats_Sn_from_sorted_data(
         sorted_data: *const f64,
@@ -28299,7 +28299,7 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_stats_Qn_from_sorted_data(
         sorted_data: *const f64,
@@ -32005,21 +32005,21 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub struct gsl_integration_romberg_workspace;
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_integration_romberg_alloc(n: usize) -> *mut gsl_integration_romberg_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_integration_romberg_free(w: *mut gsl_integration_romberg_workspace);
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_integration_romberg(
         f: *const gsl_function,
@@ -32929,7 +32929,7 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_ran_multivariate_gaussian(
         r: *const gsl_rng,
@@ -32939,7 +32939,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_ran_multivariate_gaussian_log_pdf(
         x: *const gsl_vector,
@@ -32950,7 +32950,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_ran_multivariate_gaussian_pdf(
         x: *const gsl_vector,
@@ -32961,7 +32961,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_ran_multivariate_gaussian_mean(
         X: *const gsl_matrix,
@@ -32969,7 +32969,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_ran_multivariate_gaussian_vcov(
         X: *const gsl_matrix,
@@ -32977,7 +32977,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_ran_wishart(
         r: *const gsl_rng,
@@ -32988,7 +32988,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_ran_wishart_log_pdf(
         X: *const gsl_matrix,
@@ -33000,7 +33000,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_5")]
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
     pub fn gsl_ran_wishart_pdf(
         X: *const gsl_matrix,
@@ -33403,46 +33403,46 @@ pub const gsl_multilarge_nlinear_fdtype_GSL_MULTILARGE_NLINEAR_CTRDIFF:
 pub type gsl_multilarge_nlinear_fdtype = ::std::os::raw::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub struct gsl_multilarge_nlinear_fdf;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub struct gsl_multilarge_nlinear_trs;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub struct gsl_multilarge_nlinear_scale;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub struct gsl_multilarge_nlinear_solver;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub struct gsl_multilarge_nlinear_parameters;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub struct gsl_multilarge_nlinear_type;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub struct gsl_multilarge_nlinear_trust_state;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub struct gsl_multilarge_nlinear_workspace;
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_alloc(
         T: *const gsl_multilarge_nlinear_type,
@@ -33452,17 +33452,17 @@ extern "C" {
     ) -> *mut gsl_multilarge_nlinear_workspace;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_free(w: *mut gsl_multilarge_nlinear_workspace);
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_default_parameters() -> gsl_multilarge_nlinear_parameters;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_init(
         x: *const gsl_vector,
@@ -33471,7 +33471,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_winit(
         x: *const gsl_vector,
@@ -33481,19 +33481,19 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_iterate(
         w: *mut gsl_multilarge_nlinear_workspace,
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_avratio(w: *const gsl_multilarge_nlinear_workspace) -> f64;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_rcond(
         rcond: *mut f64,
@@ -33501,7 +33501,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_covar(
         covar: *mut gsl_matrix,
@@ -33509,7 +33509,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_driver(
         maxiter: usize,
@@ -33529,47 +33529,47 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_name(
         w: *const gsl_multilarge_nlinear_workspace,
     ) -> *const ::std::os::raw::c_char;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_position(
         w: *const gsl_multilarge_nlinear_workspace,
     ) -> *mut gsl_vector;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_residual(
         w: *const gsl_multilarge_nlinear_workspace,
     ) -> *mut gsl_vector;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_step(
         w: *const gsl_multilarge_nlinear_workspace,
     ) -> *mut gsl_vector;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_niter(w: *const gsl_multilarge_nlinear_workspace) -> usize;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_trs_name(
         w: *const gsl_multilarge_nlinear_workspace,
     ) -> *const ::std::os::raw::c_char;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_eval_f(
         fdf: *mut gsl_multilarge_nlinear_fdf,
@@ -33579,7 +33579,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_eval_df(
         TransJ: CBLAS_TRANSPOSE_t,
@@ -33596,7 +33596,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_eval_fvv(
         h: f64,
@@ -33610,7 +33610,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_test(
         xtol: f64,
@@ -33621,7 +33621,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_df(
         h: f64,
@@ -33635,7 +33635,7 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_fdfvv(
         h: f64,
@@ -33650,67 +33650,67 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_trust: *const gsl_multilarge_nlinear_type;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_trs_lm: *const gsl_multilarge_nlinear_trs;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_trs_lmaccel: *const gsl_multilarge_nlinear_trs;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_trs_dogleg: *const gsl_multilarge_nlinear_trs;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_trs_ddogleg: *const gsl_multilarge_nlinear_trs;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_trs_subspace2D: *const gsl_multilarge_nlinear_trs;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_trs_cgst: *const gsl_multilarge_nlinear_trs;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_scale_levenberg: *const gsl_multilarge_nlinear_scale;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_scale_marquardt: *const gsl_multilarge_nlinear_scale;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_scale_more: *const gsl_multilarge_nlinear_scale;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_solver_cholesky: *const gsl_multilarge_nlinear_solver;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_solver_mcholesky: *const gsl_multilarge_nlinear_solver;
 }
 extern "C" {
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub static mut gsl_multilarge_nlinear_solver_none: *const gsl_multilarge_nlinear_solver;
 }
@@ -33724,7 +33724,7 @@ extern "C" {
     pub fn gsl_rstat_quantile_free(w: *mut gsl_rstat_quantile_workspace);
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_rstat_quantile_reset(w: *mut gsl_rstat_quantile_workspace) -> ::std::os::raw::c_int;
 }
@@ -33768,7 +33768,7 @@ extern "C" {
     pub fn gsl_rstat_sd(w: *const gsl_rstat_workspace) -> f64;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     pub fn gsl_rstat_rms(w: *const gsl_rstat_workspace) -> f64;
 }
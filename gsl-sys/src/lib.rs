@@ -13,5 +13,13 @@
 pub extern crate libc;
 
 mod auto;
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+mod manual;
 
 pub use auto::*;
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+pub use manual::*;
+
+/// The pkg-config name of the CBLAS implementation linked by the build script: `gslcblas` unless one of the
+/// `cblas-*` features is enabled.
+pub const CBLAS_BACKEND: &str = env!("GSL_SYS_CBLAS_BACKEND");
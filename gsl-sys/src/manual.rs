@@ -0,0 +1,102 @@
+//
+// FFI binding for the GSL library
+//
+
+//! Declarations written by hand because `auto.rs` was generated from headers that predate them. Remove them from here
+//! once `auto.rs` is regenerated (see the README): the glob re-exports would otherwise be ambiguous.
+
+use auto::{gsl_matrix, gsl_vector};
+
+extern "C" {
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    pub fn gsl_linalg_QR_UR_decomp(
+        S: *mut gsl_matrix,
+        A: *mut gsl_matrix,
+        T: *mut gsl_matrix,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    pub fn gsl_linalg_QR_UR_lssolve(
+        R: *const gsl_matrix,
+        Y: *const gsl_matrix,
+        T: *const gsl_matrix,
+        b: *const gsl_vector,
+        x: *mut gsl_vector,
+        work: *mut gsl_vector,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    pub fn gsl_linalg_QR_UR_QTvec(
+        Y: *const gsl_matrix,
+        T: *const gsl_matrix,
+        b: *mut gsl_vector,
+        work: *mut gsl_vector,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    pub fn gsl_linalg_QR_UU_decomp(
+        U: *mut gsl_matrix,
+        S: *mut gsl_matrix,
+        T: *mut gsl_matrix,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    pub fn gsl_linalg_QR_UU_lssolve(
+        R: *const gsl_matrix,
+        Y: *const gsl_matrix,
+        T: *const gsl_matrix,
+        b: *const gsl_vector,
+        x: *mut gsl_vector,
+        work: *mut gsl_vector,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    pub fn gsl_linalg_QR_UU_QTvec(
+        Y: *const gsl_matrix,
+        T: *const gsl_matrix,
+        b: *mut gsl_vector,
+        work: *mut gsl_vector,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    pub fn gsl_linalg_QR_UZ_decomp(
+        S: *mut gsl_matrix,
+        A: *mut gsl_matrix,
+        T: *mut gsl_matrix,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    pub fn gsl_linalg_QR_UD_decomp(
+        U: *mut gsl_matrix,
+        D: *const gsl_vector,
+        Y: *mut gsl_matrix,
+        T: *mut gsl_matrix,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    pub fn gsl_linalg_QR_UD_lssolve(
+        R: *const gsl_matrix,
+        Y: *const gsl_matrix,
+        T: *const gsl_matrix,
+        b: *const gsl_vector,
+        x: *mut gsl_vector,
+        work: *mut gsl_vector,
+    ) -> ::std::os::raw::c_int;
+}
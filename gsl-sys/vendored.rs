@@ -0,0 +1,197 @@
+//! Build of the bundled GSL sources, for the `vendored` feature.
+//!
+//! The sources are those of a GSL release (the tarball, which contains the `configure` script). They are taken from the
+//! directory given by the `GSL_SRC_DIR` environment variable, else from the `gsl` directory of this crate, else from
+//! the release tarball packaged with this crate, which is extracted in `OUT_DIR`. `configure` is only run to generate
+//! `config.h`: the library itself is compiled with the `cc` crate and linked statically.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The GSL version the `vendored` feature expects: it enables the matching `v2_7` feature.
+const VERSION: &str = "2.7";
+/// The release tarball packaged with this crate, next to `Cargo.toml`.
+const TARBALL: &str = "gsl-2.7.1.tar.gz";
+
+pub fn build(with_cblas: bool) {
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let src = sources(&out);
+    println!("cargo:rerun-if-changed={}", src.display());
+    check_version(&src);
+
+    let build_dir = out.join("gsl-build");
+    let include_dir = out.join("include");
+    configure(&src, &build_dir);
+    copy_headers(&src, &build_dir, &include_dir.join("gsl"));
+
+    let makefile = read(&src.join("Makefile.am"));
+    let mut gsl = new_build(&src, &build_dir, &include_dir);
+    gsl.file(src.join("version.c"));
+    for lib in variable(&makefile, "SUBLIBS") {
+        add_sources(&mut gsl, &src, &lib);
+    }
+    gsl.compile("gsl");
+
+    if with_cblas {
+        let mut cblas = new_build(&src, &build_dir, &include_dir);
+        add_sources(&mut cblas, &src, "cblas/libgslcblas.la");
+        cblas.compile("gslcblas");
+    }
+    if env::var("CARGO_CFG_UNIX").is_ok() {
+        println!("cargo:rustc-link-lib=m");
+    }
+}
+
+/// Returns the directory of the GSL sources, extracting the packaged tarball if no other sources are given.
+fn sources(out: &Path) -> PathBuf {
+    println!("cargo:rerun-if-env-changed=GSL_SRC_DIR");
+    if let Some(dir) = env::var_os("GSL_SRC_DIR") {
+        return PathBuf::from(dir);
+    }
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let dir = manifest_dir.join("gsl");
+    let tarball = manifest_dir.join(TARBALL);
+    if dir.join("configure.ac").exists() {
+        return dir;
+    }
+    if !tarball.exists() {
+        panic!(
+            "the `vendored` feature needs the sources of GSL {}, but neither `{}` nor the `gsl` directory is next to {}: \
+             run `fetch-gsl.sh` there to download the tarball, or set `GSL_SRC_DIR` to the directory of the sources",
+            VERSION,
+            TARBALL,
+            manifest_dir.join("Cargo.toml").display()
+        );
+    }
+    println!("cargo:rerun-if-changed={}", tarball.display());
+    let extracted = out.join(TARBALL.trim_end_matches(".tar.gz"));
+    if !extracted.join("configure.ac").exists() {
+        let status = Command::new("tar")
+            .arg("xzf")
+            .arg(&tarball)
+            .arg("-C")
+            .arg(out)
+            .status()
+            .expect("failed to run tar to extract the GSL sources");
+        if !status.success() {
+            panic!("failed to extract {}: {}", tarball.display(), status);
+        }
+    }
+    extracted
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| {
+        panic!(
+            "the `vendored` feature needs the sources of GSL {} (from a release tarball): `{}` next to the \
+             Cargo.toml of GSL-sys, its `gsl` directory or `GSL_SRC_DIR`, but {} can't be read: {}",
+            VERSION,
+            TARBALL,
+            path.display(),
+            e
+        )
+    })
+}
+
+fn check_version(src: &Path) {
+    let configure_ac = read(&src.join("configure.ac"));
+    let init = configure_ac
+        .lines()
+        .find(|line| line.starts_with("AC_INIT"))
+        .unwrap_or("");
+    if !init.contains(&format!("[{}", VERSION)) {
+        panic!(
+            "the `vendored` feature needs GSL {}, but {} contains `{}`",
+            VERSION,
+            src.display(),
+            init
+        );
+    }
+}
+
+/// Runs `configure` out of the source tree to generate `config.h` and `gsl_version.h`.
+fn configure(src: &Path, build_dir: &Path) {
+    if build_dir.join("config.h").exists() {
+        return;
+    }
+    fs::create_dir_all(build_dir).unwrap();
+    let compiler = cc::Build::new().get_compiler();
+    let status = Command::new("sh")
+        .arg(src.join("configure"))
+        .arg("--disable-shared")
+        .arg("--enable-static")
+        .env("CC", compiler.path())
+        .env("CFLAGS", compiler.cflags_env())
+        .current_dir(build_dir)
+        .status()
+        .expect("failed to run GSL's configure script");
+    if !status.success() {
+        panic!("GSL's configure script failed: {}", status);
+    }
+}
+
+/// Gathers the public headers into `gsl/`, like GSL's `make` does with links.
+fn copy_headers(src: &Path, build_dir: &Path, dest: &Path) {
+    fs::create_dir_all(dest).unwrap();
+    let mut dirs = vec![src.to_path_buf(), build_dir.to_path_buf()];
+    for entry in fs::read_dir(src).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    for dir in dirs {
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            if name.starts_with("gsl_") && name.ends_with(".h") {
+                fs::copy(&path, dest.join(&name)).unwrap();
+            }
+        }
+    }
+}
+
+fn new_build(src: &Path, build_dir: &Path, include_dir: &Path) -> cc::Build {
+    let mut build = cc::Build::new();
+    build
+        .include(build_dir)
+        .include(include_dir)
+        .include(src)
+        .define("HAVE_CONFIG_H", None)
+        .warnings(false);
+    build
+}
+
+/// Adds the sources of the libtool library `lib` (`dir/libname.la`), as listed in the `Makefile.am` of its directory.
+fn add_sources(build: &mut cc::Build, src: &Path, lib: &str) {
+    let (dir, name) = lib.split_at(lib.rfind('/').expect("bad library path"));
+    let dir = src.join(dir);
+    let name = name[1..].trim_end_matches(".la").replace(['.', '-'], "_");
+    let makefile = read(&dir.join("Makefile.am"));
+    for file in variable(&makefile, &format!("{}_la_SOURCES", name)) {
+        if file.ends_with(".c") {
+            build.file(dir.join(file));
+        }
+    }
+}
+
+/// Returns the words of the `name` variable of a `Makefile.am`.
+fn variable(makefile: &str, name: &str) -> Vec<String> {
+    let joined = makefile.replace("\\\n", " ");
+    joined
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next()?.trim();
+            let value = parts.next()?;
+            if key == name {
+                Some(value.split_whitespace().map(String::from).collect())
+            } else {
+                None
+            }
+        })
+        .next()
+        .unwrap_or_default()
+}
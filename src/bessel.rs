@@ -602,6 +602,16 @@ pub fn Knu_scaled_e(nu: f64, x: f64) -> (Value, ::types::Result) {
     (::Value::from(ret), unsafe { result.assume_init() }.into())
 }
 
+/// This routine computes the scaled irregular modified Bessel function of fractional order \nu, \exp(+|x|) K_\nu(x) for x>0, \nu>0,
+/// using the [`ResultE10`](../types/result/struct.ResultE10.html) type to return a result with extended range.
+#[doc(alias = "gsl_sf_bessel_Knu_scaled_e10_e")]
+pub fn Knu_scaled_e10_e(nu: f64, x: f64) -> (Value, ::types::ResultE10) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result_e10>::uninit();
+    let ret = unsafe { sys::gsl_sf_bessel_Knu_scaled_e10_e(nu, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
 /// This routine computes the irregular cylindrical Bessel function of zeroth order, Y_0(x), for x>0.
 #[doc(alias = "gsl_sf_bessel_Y0")]
 pub fn Y0(x: f64) -> f64 {
@@ -2,6 +2,13 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+/// Returns the name of the CBLAS implementation this crate is linked with: `gslcblas` (the reference implementation
+/// shipped with GSL) by default, or `openblas`, `blis` or `cblas` when the `cblas-openblas`, `cblas-blis` or
+/// `cblas-system` feature is enabled. GSL's own routines use it too.
+pub fn backend_name() -> &'static str {
+    sys::CBLAS_BACKEND
+}
+
 #[derive(Clone, Copy)]
 pub struct Index(pub(crate) sys::CBLAS_INDEX_t);
 
@@ -3358,3 +3365,43 @@ pub mod level3 {
         }
     }
 }
+
+#[test]
+fn backend_dgemm() {
+    use enums::{CblasOrder, CblasTranspose};
+
+    // Any backend must give the result of the naive product, up to rounding.
+    let (m, n, k) = (7, 5, 9);
+    let a: Vec<f64> = (0..m * k).map(|i| ((i * 7) % 11) as f64 - 5.).collect();
+    let b: Vec<f64> = (0..k * n).map(|i| ((i * 3) % 13) as f64 / 4.).collect();
+    let mut c = vec![1.; m * n];
+    level3::dgemm(
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTranspose,
+        CblasTranspose::NoTranspose,
+        m as i32,
+        n as i32,
+        k as i32,
+        2.,
+        &a,
+        k as i32,
+        &b,
+        n as i32,
+        -1.,
+        &mut c,
+        n as i32,
+    );
+    for i in 0..m {
+        for j in 0..n {
+            let expected = 2. * (0..k).map(|l| a[i * k + l] * b[l * n + j]).sum::<f64>() - 1.;
+            assert!(
+                (c[i * n + j] - expected).abs() < 1e-12,
+                "{} differs at ({}, {})",
+                backend_name(),
+                i,
+                j
+            );
+        }
+    }
+    assert!(["gslcblas", "openblas", "blis", "cblas"].contains(&backend_name()));
+}
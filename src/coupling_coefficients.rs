@@ -95,6 +95,48 @@ pub fn _6j_e(
     (::Value::from(ret), unsafe { result.assume_init() }.into())
 }
 
+/// This routine computes the Racah W coefficient,
+///
+/// W(a b c d; e f) = (-1)^{a+b+c+d} {a b e
+///                                  d c f}
+///
+/// where the arguments are given in half-integer units, ja = two_ja/2, ma = two_ma/2, etc.
+#[doc(alias = "gsl_sf_coupling_RacahW")]
+pub fn RacahW(two_ja: i32, two_jb: i32, two_jc: i32, two_jd: i32, two_je: i32, two_jf: i32) -> f64 {
+    unsafe { ::sys::gsl_sf_coupling_RacahW(two_ja, two_jb, two_jc, two_jd, two_je, two_jf) }
+}
+
+/// This routine computes the Racah W coefficient,
+///
+/// W(a b c d; e f) = (-1)^{a+b+c+d} {a b e
+///                                  d c f}
+///
+/// where the arguments are given in half-integer units, ja = two_ja/2, ma = two_ma/2, etc.
+#[doc(alias = "gsl_sf_coupling_RacahW_e")]
+pub fn RacahW_e(
+    two_ja: i32,
+    two_jb: i32,
+    two_jc: i32,
+    two_jd: i32,
+    two_je: i32,
+    two_jf: i32,
+) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        ::sys::gsl_sf_coupling_RacahW_e(
+            two_ja,
+            two_jb,
+            two_jc,
+            two_jd,
+            two_je,
+            two_jf,
+            result.as_mut_ptr(),
+        )
+    };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
 /// This routine computes the Wigner 9-j coefficient,
 ///
 /// {ja jb jc
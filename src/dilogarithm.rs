@@ -42,3 +42,52 @@ pub fn complex_dilog_e(r: f64, theta: f64) -> (Value, ::types::Result, ::types::
         unsafe { result_im.assume_init() }.into(),
     )
 }
+
+/// This function computes the full complex-valued dilogarithm for the complex argument z = x + i y.
+/// The real and imaginary parts of the result are returned in result_re, result_im.
+#[doc(alias = "gsl_sf_complex_dilog_xy_e")]
+pub fn complex_dilog_xy_e(x: f64, y: f64) -> (Value, ::types::Result, ::types::Result) {
+    let mut result_re = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let mut result_im = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        ::sys::gsl_sf_complex_dilog_xy_e(x, y, result_re.as_mut_ptr(), result_im.as_mut_ptr())
+    };
+
+    (
+        Value::from(ret),
+        unsafe { result_re.assume_init() }.into(),
+        unsafe { result_im.assume_init() }.into(),
+    )
+}
+
+/// This function computes the complex-valued Spence integral S(z) = Li_2(1-z) for the complex argument z = x + i y,
+/// which is the convention used by Abramowitz & Stegun.
+/// The real and imaginary parts of the result are returned in result_re, result_im.
+#[doc(alias = "gsl_sf_complex_spence_xy_e")]
+pub fn complex_spence_xy_e(x: f64, y: f64) -> (Value, ::types::Result, ::types::Result) {
+    let mut result_re = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let mut result_im = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        ::sys::gsl_sf_complex_spence_xy_e(x, y, result_re.as_mut_ptr(), result_im.as_mut_ptr())
+    };
+
+    (
+        Value::from(ret),
+        unsafe { result_re.assume_init() }.into(),
+        unsafe { result_im.assume_init() }.into(),
+    )
+}
+
+#[test]
+fn complex_dilog() {
+    // Li_2(1) = pi^2 / 6
+    let (ret, re, im) = complex_dilog_xy_e(1., 0.);
+    assert_eq!(ret, Value::Success);
+    assert_eq!(format!("{:.6} {:.6}", re.val, im.val), "1.644934 0.000000");
+
+    // S(0) = Li_2(1) and S(1) = Li_2(0) = 0
+    let (_, re, _) = complex_spence_xy_e(0., 0.);
+    assert_eq!(format!("{:.6}", re.val), "1.644934");
+    let (_, re, im) = complex_spence_xy_e(1., 0.);
+    assert_eq!(format!("{:.6} {:.6}", re.val, im.val), "0.000000 0.000000");
+}
@@ -17,10 +17,35 @@ LAPACK Users’ Guide (Third Edition, 1999), Published by SIAM, ISBN 0-89871-447
 http://www.netlib.org/lapack
 
 The LAPACK source code can be found at the website above along with an online copy of the users guide.
+
+## High-level API
+
+The [`symmetric`], [`hermitian`], [`nonsymmetric`] and [`generalized_symmetric`] functions work on a copy of their
+input, allocate the workspace, sort the results and return them in owned structs:
+
+```
+use rgsl::{eigen, EigenSort, MatrixF64};
+
+let mut a = MatrixF64::new(2, 2).unwrap();
+a.set(0, 0, 2.);
+a.set(0, 1, 1.);
+a.set(1, 0, 1.);
+a.set(1, 1, 2.);
+
+let e = eigen::symmetric(&a, EigenSort::ValAsc).unwrap();
+assert!((e.values.get(0) - 1.).abs() < 1e-12);
+assert!((e.values.get(1) - 3.).abs() < 1e-12);
+```
+
+The `*_with` variants take the workspace as argument, so it can be reused across calls on matrices of the same size.
 !*/
 
 use crate::Value;
 use ffi::FFI;
+use types::{
+    EigenGenSymmVWorkspace, EigenHermitianVWorkspace, EigenNonSymmetricVWorkspace,
+    EigenSymmetricVWorkspace,
+};
 use types::{MatrixComplexF64, MatrixF64, VectorComplexF64, VectorF64};
 
 /// This function simultaneously sorts the eigenvalues stored in the vector eval and the corresponding real eigenvectors stored in the columns
@@ -100,3 +125,276 @@ pub fn genv_sort(
         )
     })
 }
+
+fn check_square(n1: usize, n2: usize) -> Result<usize, Value> {
+    if n1 == n2 {
+        Ok(n1)
+    } else {
+        Err(Value::NotSquare)
+    }
+}
+
+/// Eigenvalues and eigenvectors of a real symmetric matrix.
+#[derive(Debug)]
+pub struct SymmetricEigen {
+    /// The eigenvalues.
+    pub values: VectorF64,
+    /// The eigenvectors, stored in the columns in the same order as `values`. They are orthonormal.
+    pub vectors: MatrixF64,
+}
+
+/// Eigenvalues and eigenvectors of a complex hermitian matrix.
+#[derive(Debug)]
+pub struct HermitianEigen {
+    /// The eigenvalues, which are real.
+    pub values: VectorF64,
+    /// The eigenvectors, stored in the columns in the same order as `values`. They are orthonormal.
+    pub vectors: MatrixComplexF64,
+}
+
+/// Schur decomposition A = Z T Z^T of a real nonsymmetric matrix.
+#[derive(Debug)]
+pub struct Schur {
+    /// The Schur form T, quasi upper triangular.
+    pub t: MatrixF64,
+    /// The orthogonal matrix Z of the Schur vectors.
+    pub z: MatrixF64,
+}
+
+/// Eigenvalues and right eigenvectors of a real nonsymmetric matrix.
+#[derive(Debug)]
+pub struct NonSymmetricEigen {
+    /// The eigenvalues.
+    pub values: VectorComplexF64,
+    /// The eigenvectors, stored in the columns in the same order as `values` and normalized to unit magnitude.
+    pub vectors: MatrixComplexF64,
+    /// The Schur decomposition, if requested. It isn't reordered by the sort.
+    pub schur: Option<Schur>,
+}
+
+/// Eigenvalues and eigenvectors of a real generalized symmetric-definite eigensystem A x = lambda B x.
+#[derive(Debug)]
+pub struct GeneralizedSymmetricEigen {
+    /// The eigenvalues.
+    pub values: VectorF64,
+    /// The eigenvectors, stored in the columns in the same order as `values` and normalized to unit magnitude.
+    pub vectors: MatrixF64,
+}
+
+/// Computes the eigenvalues and eigenvectors of the real symmetric matrix `A`, sorted according to `sort_type`. Only
+/// the diagonal and lower triangular part of `A` are referenced; `A` itself is left untouched.
+#[doc(alias = "gsl_eigen_symmv")]
+pub fn symmetric(A: &MatrixF64, sort_type: ::EigenSort) -> Result<SymmetricEigen, Value> {
+    let n = check_square(A.size1(), A.size2())?;
+    let mut w = EigenSymmetricVWorkspace::new(n).ok_or(Value::NoMemory)?;
+    symmetric_with(&mut w, A, sort_type)
+}
+
+/// Same as [`symmetric`], using the workspace `w`, which must have been allocated for the size of `A`.
+pub fn symmetric_with(
+    w: &mut EigenSymmetricVWorkspace,
+    A: &MatrixF64,
+    sort_type: ::EigenSort,
+) -> Result<SymmetricEigen, Value> {
+    let n = check_square(A.size1(), A.size2())?;
+    let mut a = A.clone().ok_or(Value::NoMemory)?;
+    let mut values = VectorF64::new(n).ok_or(Value::NoMemory)?;
+    let mut vectors = MatrixF64::new(n, n).ok_or(Value::NoMemory)?;
+    w.symmv(&mut a, &mut values, &mut vectors).to_result()?;
+    symmv_sort(&mut values, &mut vectors, sort_type).to_result()?;
+    Ok(SymmetricEigen { values, vectors })
+}
+
+/// Computes the eigenvalues and eigenvectors of the complex hermitian matrix `A`, sorted according to `sort_type`.
+/// Only the diagonal and lower triangular part of `A` are referenced; `A` itself is left untouched.
+#[doc(alias = "gsl_eigen_hermv")]
+pub fn hermitian(A: &MatrixComplexF64, sort_type: ::EigenSort) -> Result<HermitianEigen, Value> {
+    let n = check_square(A.size1(), A.size2())?;
+    let mut w = EigenHermitianVWorkspace::new(n).ok_or(Value::NoMemory)?;
+    hermitian_with(&mut w, A, sort_type)
+}
+
+/// Same as [`hermitian`], using the workspace `w`, which must have been allocated for the size of `A`.
+pub fn hermitian_with(
+    w: &mut EigenHermitianVWorkspace,
+    A: &MatrixComplexF64,
+    sort_type: ::EigenSort,
+) -> Result<HermitianEigen, Value> {
+    let n = check_square(A.size1(), A.size2())?;
+    let mut a = A.clone().ok_or(Value::NoMemory)?;
+    let mut values = VectorF64::new(n).ok_or(Value::NoMemory)?;
+    let mut vectors = MatrixComplexF64::new(n, n).ok_or(Value::NoMemory)?;
+    w.hermv(&mut a, &mut values, &mut vectors).to_result()?;
+    hermv_sort(&mut values, &mut vectors, sort_type).to_result()?;
+    Ok(HermitianEigen { values, vectors })
+}
+
+/// Computes the eigenvalues and right eigenvectors of the real nonsymmetric matrix `A`, sorted according to
+/// `sort_type`, which must be `EigenSort::AbsAsc` or `EigenSort::AbsDesc`. If `schur` is `true`, the Schur form and
+/// the Schur vectors are returned too. `A` itself is left untouched.
+#[doc(alias = "gsl_eigen_nonsymmv")]
+pub fn nonsymmetric(
+    A: &MatrixF64,
+    sort_type: ::EigenSort,
+    schur: bool,
+) -> Result<NonSymmetricEigen, Value> {
+    let n = check_square(A.size1(), A.size2())?;
+    let mut w = EigenNonSymmetricVWorkspace::new(n).ok_or(Value::NoMemory)?;
+    nonsymmetric_with(&mut w, A, sort_type, schur)
+}
+
+/// Same as [`nonsymmetric`], using the workspace `w`, which must have been allocated for the size of `A`.
+pub fn nonsymmetric_with(
+    w: &mut EigenNonSymmetricVWorkspace,
+    A: &MatrixF64,
+    sort_type: ::EigenSort,
+    schur: bool,
+) -> Result<NonSymmetricEigen, Value> {
+    let n = check_square(A.size1(), A.size2())?;
+    let mut a = A.clone().ok_or(Value::NoMemory)?;
+    let mut values = VectorComplexF64::new(n).ok_or(Value::NoMemory)?;
+    let mut vectors = MatrixComplexF64::new(n, n).ok_or(Value::NoMemory)?;
+    let schur = if schur {
+        let mut z = MatrixF64::new(n, n).ok_or(Value::NoMemory)?;
+        w.nonsymmv_Z(&mut a, &mut values, &mut vectors, &mut z)
+            .to_result()?;
+        // Only the upper Hessenberg part of `a` holds the Schur form, the rest is left over from the computation.
+        for i in 2..n {
+            for j in 0..i - 1 {
+                a.set(i, j, 0.);
+            }
+        }
+        Some(Schur { t: a, z })
+    } else {
+        w.nonsymmv(&mut a, &mut values, &mut vectors).to_result()?;
+        None
+    };
+    nonsymmv_sort(&mut values, &mut vectors, sort_type).to_result()?;
+    Ok(NonSymmetricEigen {
+        values,
+        vectors,
+        schur,
+    })
+}
+
+/// Computes the eigenvalues and eigenvectors of the real generalized symmetric-definite matrix pair (A, B), sorted
+/// according to `sort_type`. `A` and `B` are left untouched.
+///
+/// GSL reports a `B` which isn't positive definite through its error handler, which aborts by default. Turn it off with
+/// [`set_error_handler_off`](../error/fn.set_error_handler_off.html) first to get `Value::Domain` instead.
+#[doc(alias = "gsl_eigen_gensymmv")]
+pub fn generalized_symmetric(
+    A: &MatrixF64,
+    B: &MatrixF64,
+    sort_type: ::EigenSort,
+) -> Result<GeneralizedSymmetricEigen, Value> {
+    let n = check_square(A.size1(), A.size2())?;
+    let mut w = EigenGenSymmVWorkspace::new(n).ok_or(Value::NoMemory)?;
+    generalized_symmetric_with(&mut w, A, B, sort_type)
+}
+
+/// Same as [`generalized_symmetric`], using the workspace `w`, which must have been allocated for the size of `A`.
+pub fn generalized_symmetric_with(
+    w: &mut EigenGenSymmVWorkspace,
+    A: &MatrixF64,
+    B: &MatrixF64,
+    sort_type: ::EigenSort,
+) -> Result<GeneralizedSymmetricEigen, Value> {
+    let n = check_square(A.size1(), A.size2())?;
+    if (B.size1(), B.size2()) != (n, n) {
+        return Err(Value::BadLength);
+    }
+    let a = A.clone().ok_or(Value::NoMemory)?;
+    let mut b = B.clone().ok_or(Value::NoMemory)?;
+    let mut values = VectorF64::new(n).ok_or(Value::NoMemory)?;
+    let mut vectors = MatrixF64::new(n, n).ok_or(Value::NoMemory)?;
+    w.gensymmv(a, &mut b, &mut values, &mut vectors)
+        .to_result()?;
+    gensymmv_sort(&mut values, &mut vectors, sort_type).to_result()?;
+    Ok(GeneralizedSymmetricEigen { values, vectors })
+}
+
+#[test]
+fn high_level() {
+    use ComplexF64;
+    use EigenSort;
+
+    let mut a = MatrixF64::new(3, 3).unwrap();
+    a.set(0, 0, 2.);
+    a.set(1, 1, 3.);
+    a.set(2, 2, 1.);
+    a.set(0, 1, 1.);
+    a.set(1, 0, 1.);
+    let e = symmetric(&a, EigenSort::ValAsc).unwrap();
+    let vals: Vec<f64> = (0..3).map(|i| e.values.get(i)).collect();
+    assert!(vals.windows(2).all(|w| w[0] <= w[1]));
+    assert!((vals.iter().sum::<f64>() - 6.).abs() < 1e-12);
+    assert_eq!(a.get(0, 0), 2.);
+    assert!(symmetric(&MatrixF64::new(2, 3).unwrap(), EigenSort::ValAsc).is_err());
+
+    let mut w = EigenSymmetricVWorkspace::new(3).unwrap();
+    for k in 0..3 {
+        a.set(2, 2, k as f64);
+        let e = symmetric_with(&mut w, &a, EigenSort::ValDesc).unwrap();
+        assert!(e.values.get(0) >= e.values.get(2));
+    }
+
+    let mut h = MatrixComplexF64::new(2, 2).unwrap();
+    h.set(0, 0, &ComplexF64::rect(2., 0.));
+    h.set(1, 1, &ComplexF64::rect(2., 0.));
+    h.set(1, 0, &ComplexF64::rect(0., 1.));
+    h.set(0, 1, &ComplexF64::rect(0., -1.));
+    let e = hermitian(&h, EigenSort::ValAsc).unwrap();
+    assert!((e.values.get(0) - 1.).abs() < 1e-12);
+    assert!((e.values.get(1) - 3.).abs() < 1e-12);
+
+    // Rotation by 90 degrees: eigenvalues +/- i.
+    let mut r = MatrixF64::new(2, 2).unwrap();
+    r.set(0, 1, -1.);
+    r.set(1, 0, 1.);
+    let e = nonsymmetric(&r, EigenSort::AbsAsc, true).unwrap();
+    for i in 0..2 {
+        let v = e.values.get(i);
+        assert!(v.real().abs() < 1e-12);
+        assert!((v.imaginary().abs() - 1.).abs() < 1e-12);
+    }
+    let schur = e.schur.unwrap();
+    assert_eq!((schur.t.size1(), schur.z.size2()), (2, 2));
+    assert!(nonsymmetric(&r, EigenSort::AbsAsc, false)
+        .unwrap()
+        .schur
+        .is_none());
+
+    let mut m = MatrixF64::new(3, 3).unwrap();
+    for (k, x) in [1., 2., 3., 4., 5., 6., 7., 8., 10.].iter().enumerate() {
+        m.set(k / 3, k % 3, *x);
+    }
+    let schur = nonsymmetric(&m, EigenSort::AbsAsc, true)
+        .unwrap()
+        .schur
+        .unwrap();
+    let (t, z) = (&schur.t, &schur.z);
+    for i in 0..3 {
+        for j in 0..3 {
+            // Quasi upper triangular: only the 2 x 2 blocks of the complex eigenvalues reach the subdiagonal.
+            if i > j + 1 {
+                assert_eq!(t.get(i, j), 0.);
+            }
+            // Z T Z^T = A
+            let ztzt: f64 = (0..3)
+                .flat_map(|k| (0..3).map(move |l| (k, l)))
+                .map(|(k, l)| z.get(i, k) * t.get(k, l) * z.get(j, l))
+                .sum();
+            assert!((ztzt - m.get(i, j)).abs() < 1e-10);
+        }
+    }
+
+    let mut b = MatrixF64::new(3, 3).unwrap();
+    b.set_identity();
+    b.scale(2.);
+    let e = generalized_symmetric(&a, &b, EigenSort::ValAsc).unwrap();
+    let f = symmetric(&a, EigenSort::ValAsc).unwrap();
+    for i in 0..3 {
+        assert!((2. * e.values.get(i) - f.values.get(i)).abs() < 1e-12);
+    }
+}
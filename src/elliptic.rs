@@ -71,6 +71,23 @@ pub mod legendre {
 
             (::Value::from(ret), unsafe { result.assume_init() }.into())
         }
+
+        /// This routine computes the complete elliptic integral D(k) to the accuracy specified by the mode variable mode,
+        /// which is the incomplete integral D(\phi,k) evaluated at \phi = \pi/2.
+        #[doc(alias = "gsl_sf_ellint_Dcomp")]
+        pub fn ellint_Dcomp(k: f64, mode: ::Mode) -> f64 {
+            unsafe { ::sys::gsl_sf_ellint_Dcomp(k, mode.into()) }
+        }
+
+        /// This routine computes the complete elliptic integral D(k) to the accuracy specified by the mode variable mode,
+        /// which is the incomplete integral D(\phi,k) evaluated at \phi = \pi/2.
+        #[doc(alias = "gsl_sf_ellint_Dcomp_e")]
+        pub fn ellint_Dcomp_e(k: f64, mode: ::Mode) -> (Value, ::types::Result) {
+            let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+            let ret = unsafe { ::sys::gsl_sf_ellint_Dcomp_e(k, mode.into(), result.as_mut_ptr()) };
+
+            (::Value::from(ret), unsafe { result.assume_init() }.into())
+        }
     }
 
     pub mod incomplete {
@@ -113,6 +113,16 @@ impl Value {
     pub fn is_success(self) -> bool {
         self == Self::Success
     }
+
+    /// Returns `Ok(())` for [`Value::Success`] and `Err(self)` for any other value, so that the status returned by
+    /// GSL can be propagated with `?`.
+    pub fn to_result(self) -> Result<(), Value> {
+        if self.is_success() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -634,7 +644,7 @@ impl From<sys::CBLAS_SIDE> for CblasDiag {
     }
 }
 
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 pub enum FilterEnd {
@@ -645,7 +655,7 @@ pub enum FilterEnd {
 
 #[doc(hidden)]
 #[allow(clippy::from_over_into)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 impl Into<sys::gsl_filter_end_t> for FilterEnd {
     fn into(self) -> sys::gsl_filter_end_t {
         match self {
@@ -657,7 +667,7 @@ impl Into<sys::gsl_filter_end_t> for FilterEnd {
 }
 
 #[doc(hidden)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 impl From<sys::gsl_filter_end_t> for FilterEnd {
     fn from(v: sys::gsl_filter_end_t) -> FilterEnd {
         match v {
@@ -669,7 +679,7 @@ impl From<sys::gsl_filter_end_t> for FilterEnd {
     }
 }
 
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 pub enum FilterScale {
@@ -681,7 +691,7 @@ pub enum FilterScale {
 
 #[doc(hidden)]
 #[allow(clippy::from_over_into)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 impl Into<sys::gsl_filter_scale_t> for FilterScale {
     fn into(self) -> sys::gsl_filter_scale_t {
         match self {
@@ -694,7 +704,7 @@ impl Into<sys::gsl_filter_scale_t> for FilterScale {
 }
 
 #[doc(hidden)]
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 impl From<sys::gsl_filter_scale_t> for FilterScale {
     fn from(v: sys::gsl_filter_scale_t) -> FilterScale {
         match v {
@@ -706,3 +716,93 @@ impl From<sys::gsl_filter_scale_t> for FilterScale {
         }
     }
 }
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum IeeePrecision {
+    Single,
+    Double,
+    Extended,
+}
+
+#[doc(hidden)]
+#[allow(clippy::from_over_into)]
+impl Into<c_int> for IeeePrecision {
+    fn into(self) -> c_int {
+        let v = match self {
+            Self::Single => sys::GSL_IEEE_SINGLE_PRECISION,
+            Self::Double => sys::GSL_IEEE_DOUBLE_PRECISION,
+            Self::Extended => sys::GSL_IEEE_EXTENDED_PRECISION,
+        };
+        v as _
+    }
+}
+
+#[doc(hidden)]
+impl From<c_int> for IeeePrecision {
+    fn from(v: c_int) -> IeeePrecision {
+        match v as _ {
+            sys::GSL_IEEE_SINGLE_PRECISION => Self::Single,
+            sys::GSL_IEEE_DOUBLE_PRECISION => Self::Double,
+            sys::GSL_IEEE_EXTENDED_PRECISION => Self::Extended,
+            _ => panic!("Unknown IeeePrecision value"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum IeeeRounding {
+    ToNearest,
+    Down,
+    Up,
+    ToZero,
+}
+
+#[doc(hidden)]
+#[allow(clippy::from_over_into)]
+impl Into<c_int> for IeeeRounding {
+    fn into(self) -> c_int {
+        let v = match self {
+            Self::ToNearest => sys::GSL_IEEE_ROUND_TO_NEAREST,
+            Self::Down => sys::GSL_IEEE_ROUND_DOWN,
+            Self::Up => sys::GSL_IEEE_ROUND_UP,
+            Self::ToZero => sys::GSL_IEEE_ROUND_TO_ZERO,
+        };
+        v as _
+    }
+}
+
+#[doc(hidden)]
+impl From<c_int> for IeeeRounding {
+    fn from(v: c_int) -> IeeeRounding {
+        match v as _ {
+            sys::GSL_IEEE_ROUND_TO_NEAREST => Self::ToNearest,
+            sys::GSL_IEEE_ROUND_DOWN => Self::Down,
+            sys::GSL_IEEE_ROUND_UP => Self::Up,
+            sys::GSL_IEEE_ROUND_TO_ZERO => Self::ToZero,
+            _ => panic!("Unknown IeeeRounding value"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum IeeeType {
+    Nan,
+    Inf,
+    Normal,
+    Denormal,
+    Zero,
+}
+
+#[doc(hidden)]
+impl From<c_int> for IeeeType {
+    fn from(v: c_int) -> IeeeType {
+        match v as _ {
+            sys::GSL_IEEE_TYPE_NAN => Self::Nan,
+            sys::GSL_IEEE_TYPE_INF => Self::Inf,
+            sys::GSL_IEEE_TYPE_NORMAL => Self::Normal,
+            sys::GSL_IEEE_TYPE_DENORMAL => Self::Denormal,
+            sys::GSL_IEEE_TYPE_ZERO => Self::Zero,
+            _ => panic!("Unknown IeeeType value"),
+        }
+    }
+}
@@ -62,6 +62,51 @@ pub fn En_e(n: i32, x: f64) -> (Value, ::types::Result) {
     (::Value::from(ret), unsafe { result.assume_init() }.into())
 }
 
+/// This routine computes the scaled exponential integral \exp(x) E_1(x).
+#[doc(alias = "gsl_sf_expint_E1_scaled")]
+pub fn E1_scaled(x: f64) -> f64 {
+    unsafe { sys::gsl_sf_expint_E1_scaled(x) }
+}
+
+/// This routine computes the scaled exponential integral \exp(x) E_1(x).
+#[doc(alias = "gsl_sf_expint_E1_scaled_e")]
+pub fn E1_scaled_e(x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_expint_E1_scaled_e(x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine computes the scaled second-order exponential integral \exp(x) E_2(x).
+#[doc(alias = "gsl_sf_expint_E2_scaled")]
+pub fn E2_scaled(x: f64) -> f64 {
+    unsafe { sys::gsl_sf_expint_E2_scaled(x) }
+}
+
+/// This routine computes the scaled second-order exponential integral \exp(x) E_2(x).
+#[doc(alias = "gsl_sf_expint_E2_scaled_e")]
+pub fn E2_scaled_e(x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_expint_E2_scaled_e(x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine computes the scaled exponential integral \exp(x) E_n(x) of order n.
+#[doc(alias = "gsl_sf_expint_En_scaled")]
+pub fn En_scaled(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_expint_En_scaled(n, x) }
+}
+
+/// This routine computes the scaled exponential integral \exp(x) E_n(x) of order n.
+#[doc(alias = "gsl_sf_expint_En_scaled_e")]
+pub fn En_scaled_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_expint_En_scaled_e(n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
 /// This routine computes the exponential integral Ei(x),
 ///
 /// Ei(x) := - PV(\int_{-x}^\infty dt \exp(-t)/t)
@@ -85,6 +130,21 @@ pub fn Ei_e(x: f64) -> (Value, ::types::Result) {
     (::Value::from(ret), unsafe { result.assume_init() }.into())
 }
 
+/// This routine computes the scaled exponential integral \exp(-x) Ei(x).
+#[doc(alias = "gsl_sf_expint_Ei_scaled")]
+pub fn Ei_scaled(x: f64) -> f64 {
+    unsafe { sys::gsl_sf_expint_Ei_scaled(x) }
+}
+
+/// This routine computes the scaled exponential integral \exp(-x) Ei(x).
+#[doc(alias = "gsl_sf_expint_Ei_scaled_e")]
+pub fn Ei_scaled_e(x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_expint_Ei_scaled_e(x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
 /// This routine computes the integral Shi(x) = \int_0^x dt \sinh(t)/t.
 #[doc(alias = "gsl_sf_Shi")]
 pub fn Shi(x: f64) -> f64 {
@@ -174,3 +234,17 @@ pub fn atanint_e(x: f64) -> (Value, ::types::Result) {
 
     (::Value::from(ret), unsafe { result.assume_init() }.into())
 }
+
+#[test]
+fn scaled_exponential_integrals() {
+    // e * E_1(1) = 0.596347..., e^{-1} * Ei(1) = 0.697174...
+    assert_eq!(format!("{:.6}", E1_scaled(1.)), "0.596347");
+    assert_eq!(format!("{:.6}", En_scaled(1, 1.)), "0.596347");
+    // E_2(1) = e^{-1} - E_1(1)
+    assert_eq!(format!("{:.6}", E2_scaled(1.)), "0.403653");
+    assert_eq!(format!("{:.6}", Ei_scaled(1.)), "0.697175");
+
+    let (ret, res) = E1_scaled_e(1.);
+    assert_eq!(ret, Value::Success);
+    assert_eq!(format!("{:.6}", res.val), "0.596347");
+}
@@ -159,72 +159,84 @@ The mixed-radix algorithm does not have this restriction, and it stores the real
 is desirable for better locality of memory accesses).
 !*/
 
+use crate::paste::paste;
+
 /// These functions compute forward, backward and inverse FFTs of length n with stride stride, on the packed complex array data using an in-place radix-2
 /// decimation-in-time algorithm. The length of the transform is restricted to powers of two. For the transform version of the function
 /// the sign argument can be either forward (-1) or backward (+1).
 ///
 /// The functions return a value of ::Value::Success if no errors were detected, or Value::Dom if the length n is not a power of two.
 pub mod radix2 {
+    use super::FftElement;
     use crate::Value;
 
     #[doc(alias = "gsl_fft_complex_radix2_forward")]
-    pub fn forward(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe { sys::gsl_fft_complex_radix2_forward(data.as_mut_ptr(), stride, n) })
+    #[doc(alias = "gsl_fft_complex_float_radix2_forward")]
+    pub fn forward<T: FftElement>(data: &mut [T], stride: usize, n: usize) -> Value {
+        Value::from(unsafe { T::radix2_forward(data.as_mut_ptr(), stride, n) })
     }
 
     #[doc(alias = "gsl_fft_complex_radix2_transform")]
-    pub fn transform(data: &mut [f64], stride: usize, n: usize, sign: ::FftDirection) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_complex_radix2_transform(data.as_mut_ptr(), stride, n, sign.into())
-        })
+    #[doc(alias = "gsl_fft_complex_float_radix2_transform")]
+    pub fn transform<T: FftElement>(
+        data: &mut [T],
+        stride: usize,
+        n: usize,
+        sign: ::FftDirection,
+    ) -> Value {
+        Value::from(unsafe { T::radix2_transform(data.as_mut_ptr(), stride, n, sign) })
     }
 
     #[doc(alias = "gsl_fft_complex_radix2_backward")]
-    pub fn backward(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe { sys::gsl_fft_complex_radix2_backward(data.as_mut_ptr(), stride, n) })
+    #[doc(alias = "gsl_fft_complex_float_radix2_backward")]
+    pub fn backward<T: FftElement>(data: &mut [T], stride: usize, n: usize) -> Value {
+        Value::from(unsafe { T::radix2_backward(data.as_mut_ptr(), stride, n) })
     }
 
     #[doc(alias = "gsl_fft_complex_radix2_inverse")]
-    pub fn inverse(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe { sys::gsl_fft_complex_radix2_inverse(data.as_mut_ptr(), stride, n) })
+    #[doc(alias = "gsl_fft_complex_float_radix2_inverse")]
+    pub fn inverse<T: FftElement>(data: &mut [T], stride: usize, n: usize) -> Value {
+        Value::from(unsafe { T::radix2_inverse(data.as_mut_ptr(), stride, n) })
     }
 
     /// This is decimation-in-frequency version of the radix-2 FFT function.
     #[doc(alias = "gsl_fft_complex_radix2_dif_forward")]
-    pub fn dif_forward(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_complex_radix2_dif_forward(data.as_mut_ptr(), stride, n)
-        })
+    #[doc(alias = "gsl_fft_complex_float_radix2_dif_forward")]
+    pub fn dif_forward<T: FftElement>(data: &mut [T], stride: usize, n: usize) -> Value {
+        Value::from(unsafe { T::radix2_dif_forward(data.as_mut_ptr(), stride, n) })
     }
 
     /// This is decimation-in-frequency version of the radix-2 FFT function.
     #[doc(alias = "gsl_fft_complex_radix2_dif_transform")]
-    pub fn dif_transform(data: &mut [f64], stride: usize, n: usize, sign: ::FftDirection) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_complex_radix2_dif_transform(data.as_mut_ptr(), stride, n, sign.into())
-        })
+    #[doc(alias = "gsl_fft_complex_float_radix2_dif_transform")]
+    pub fn dif_transform<T: FftElement>(
+        data: &mut [T],
+        stride: usize,
+        n: usize,
+        sign: ::FftDirection,
+    ) -> Value {
+        Value::from(unsafe { T::radix2_dif_transform(data.as_mut_ptr(), stride, n, sign) })
     }
 
     /// This is decimation-in-frequency version of the radix-2 FFT function.
     #[doc(alias = "gsl_fft_complex_radix2_dif_backward")]
-    pub fn dif_backward(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_complex_radix2_dif_backward(data.as_mut_ptr(), stride, n)
-        })
+    #[doc(alias = "gsl_fft_complex_float_radix2_dif_backward")]
+    pub fn dif_backward<T: FftElement>(data: &mut [T], stride: usize, n: usize) -> Value {
+        Value::from(unsafe { T::radix2_dif_backward(data.as_mut_ptr(), stride, n) })
     }
 
     /// This is decimation-in-frequency version of the radix-2 FFT function.
     #[doc(alias = "gsl_fft_complex_radix2_dif_inverse")]
-    pub fn dif_inverse(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_complex_radix2_dif_inverse(data.as_mut_ptr(), stride, n)
-        })
+    #[doc(alias = "gsl_fft_complex_float_radix2_dif_inverse")]
+    pub fn dif_inverse<T: FftElement>(data: &mut [T], stride: usize, n: usize) -> Value {
+        Value::from(unsafe { T::radix2_dif_inverse(data.as_mut_ptr(), stride, n) })
     }
 }
 
 /// This section describes radix-2 FFT algorithms for real data. They use the Cooley-Tukey algorithm to compute in-place FFTs for lengths which
 /// are a power of 2.
 pub mod real_radix2 {
+    use super::FftElement;
     use crate::Value;
 
     /// This function computes an in-place radix-2 FFT of length n and stride stride on the real array data. The output is a half-complex sequence,
@@ -258,26 +270,25 @@ pub mod real_radix2 {
     /// Note that the output data can be converted into the full complex sequence using the function gsl_fft_halfcomplex_radix2_unpack described
     /// below.
     #[doc(alias = "gsl_fft_real_radix2_transform")]
-    pub fn transform(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe { sys::gsl_fft_real_radix2_transform(data.as_mut_ptr(), stride, n) })
+    #[doc(alias = "gsl_fft_real_float_radix2_transform")]
+    pub fn transform<T: FftElement>(data: &mut [T], stride: usize, n: usize) -> Value {
+        Value::from(unsafe { T::real_radix2_transform(data.as_mut_ptr(), stride, n) })
     }
 
     /// This function computes the inverse or backwards in-place radix-2 FFT of length n and stride stride on the half-complex sequence data
     /// stored according the output scheme used by gsl_fft_real_radix2. The result is a real array stored in natural order.
     #[doc(alias = "gsl_fft_halfcomplex_radix2_inverse")]
-    pub fn inverse(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_halfcomplex_radix2_inverse(data.as_mut_ptr(), stride, n)
-        })
+    #[doc(alias = "gsl_fft_halfcomplex_float_radix2_inverse")]
+    pub fn inverse<T: FftElement>(data: &mut [T], stride: usize, n: usize) -> Value {
+        Value::from(unsafe { T::halfcomplex_radix2_inverse(data.as_mut_ptr(), stride, n) })
     }
 
     /// This function computes the inverse or backwards in-place radix-2 FFT of length n and stride stride on the half-complex sequence data
     /// stored according the output scheme used by gsl_fft_real_radix2. The result is a real array stored in natural order.
     #[doc(alias = "gsl_fft_halfcomplex_radix2_backward")]
-    pub fn backward(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_halfcomplex_radix2_backward(data.as_mut_ptr(), stride, n)
-        })
+    #[doc(alias = "gsl_fft_halfcomplex_float_radix2_backward")]
+    pub fn backward<T: FftElement>(data: &mut [T], stride: usize, n: usize) -> Value {
+        Value::from(unsafe { T::halfcomplex_radix2_backward(data.as_mut_ptr(), stride, n) })
     }
 
     /// This function converts halfcomplex_coefficient, an array of half-complex coefficients as returned by gsl_fft_real_radix2_transform,
@@ -311,14 +322,15 @@ pub mod real_radix2 {
     ///   }
     /// ```
     #[doc(alias = "gsl_fft_halfcomplex_radix2_unpack")]
-    pub fn unpack(
-        halfcomplex_coefficient: &mut [f64],
-        complex_coefficient: &mut [f64],
+    #[doc(alias = "gsl_fft_halfcomplex_float_radix2_unpack")]
+    pub fn unpack<T: FftElement>(
+        halfcomplex_coefficient: &mut [T],
+        complex_coefficient: &mut [T],
         stride: usize,
         n: usize,
     ) -> Value {
         Value::from(unsafe {
-            sys::gsl_fft_halfcomplex_radix2_unpack(
+            T::halfcomplex_radix2_unpack(
                 halfcomplex_coefficient.as_mut_ptr(),
                 complex_coefficient.as_mut_ptr(),
                 stride,
@@ -327,3 +339,686 @@ pub mod real_radix2 {
         })
     }
 }
+
+/// Computes the forward transform of `input` with a mixed-radix algorithm and returns the result
+/// as a new vector. Use a [`FftPlan`](crate::FftPlan) to avoid reallocating the wavetable when
+/// many transforms of the same length are needed.
+#[doc(alias = "gsl_fft_complex_forward")]
+pub fn fft(input: &[::ComplexF64]) -> Result<Vec<::ComplexF64>, ::Value> {
+    let mut out = input.to_vec();
+    plan_for(input.len())?.forward(&mut out)?;
+    Ok(out)
+}
+
+/// Computes the inverse transform (including the 1/n scaling) of `input` and returns the result
+/// as a new vector.
+#[doc(alias = "gsl_fft_complex_inverse")]
+pub fn ifft(input: &[::ComplexF64]) -> Result<Vec<::ComplexF64>, ::Value> {
+    let mut out = input.to_vec();
+    plan_for(input.len())?.inverse(&mut out)?;
+    Ok(out)
+}
+
+/// Computes the forward transform of the real sequence `input` and returns its `n / 2 + 1`
+/// non-redundant complex coefficients.
+#[doc(alias = "gsl_fft_real_transform")]
+pub fn rfft(input: &[f64]) -> Result<Vec<::ComplexF64>, ::Value> {
+    let mut plan = ::FftRealPlan::new(input.len()).ok_or(::Value::BadLength)?;
+    let mut out = vec![::ComplexF64::default(); plan.complex_len()];
+    plan.forward(input, &mut out)?;
+    Ok(out)
+}
+
+/// Computes the real sequence of length `n` whose `n / 2 + 1` non-redundant complex coefficients
+/// are given by `input`. This is the inverse of [`rfft`].
+#[doc(alias = "gsl_fft_halfcomplex_inverse")]
+pub fn irfft(input: &[::ComplexF64], n: usize) -> Result<Vec<f64>, ::Value> {
+    let mut plan = ::FftRealPlan::new(n).ok_or(::Value::BadLength)?;
+    let mut out = vec![0.; n];
+    plan.inverse(input, &mut out)?;
+    Ok(out)
+}
+
+/// The element types supported by the [`radix2`](radix2/index.html) and [`real_radix2`](real_radix2/index.html)
+/// functions: `f64` uses the `gsl_fft_*_radix2_*` functions and `f32` the `gsl_fft_*_float_radix2_*` ones, which
+/// avoids converting single precision signals back and forth.
+///
+/// # Safety
+///
+/// GSL reads the data as a `double` or `float` array depending on the function called, so implementations must use
+/// the radix-2 functions of their own precision.
+pub unsafe trait FftElement: Copy {
+    #[doc(hidden)]
+    unsafe fn radix2_forward(data: *mut Self, stride: usize, n: usize) -> i32;
+    #[doc(hidden)]
+    unsafe fn radix2_backward(data: *mut Self, stride: usize, n: usize) -> i32;
+    #[doc(hidden)]
+    unsafe fn radix2_inverse(data: *mut Self, stride: usize, n: usize) -> i32;
+    #[doc(hidden)]
+    unsafe fn radix2_transform(
+        data: *mut Self,
+        stride: usize,
+        n: usize,
+        sign: ::FftDirection,
+    ) -> i32;
+    #[doc(hidden)]
+    unsafe fn radix2_dif_forward(data: *mut Self, stride: usize, n: usize) -> i32;
+    #[doc(hidden)]
+    unsafe fn radix2_dif_backward(data: *mut Self, stride: usize, n: usize) -> i32;
+    #[doc(hidden)]
+    unsafe fn radix2_dif_inverse(data: *mut Self, stride: usize, n: usize) -> i32;
+    #[doc(hidden)]
+    unsafe fn radix2_dif_transform(
+        data: *mut Self,
+        stride: usize,
+        n: usize,
+        sign: ::FftDirection,
+    ) -> i32;
+    #[doc(hidden)]
+    unsafe fn real_radix2_transform(data: *mut Self, stride: usize, n: usize) -> i32;
+    #[doc(hidden)]
+    unsafe fn halfcomplex_radix2_inverse(data: *mut Self, stride: usize, n: usize) -> i32;
+    #[doc(hidden)]
+    unsafe fn halfcomplex_radix2_backward(data: *mut Self, stride: usize, n: usize) -> i32;
+    #[doc(hidden)]
+    unsafe fn halfcomplex_radix2_unpack(
+        halfcomplex: *const Self,
+        complex: *mut Self,
+        stride: usize,
+        n: usize,
+    ) -> i32;
+}
+
+macro_rules! fft_element {
+    ($ty:ty, $complex:ident, $real:ident, $half:ident) => {
+        paste! {
+            unsafe impl FftElement for $ty {
+                unsafe fn radix2_forward(data: *mut Self, stride: usize, n: usize) -> i32 {
+                    sys::[<$complex _radix2_forward>](data, stride, n)
+                }
+
+                unsafe fn radix2_backward(data: *mut Self, stride: usize, n: usize) -> i32 {
+                    sys::[<$complex _radix2_backward>](data, stride, n)
+                }
+
+                unsafe fn radix2_inverse(data: *mut Self, stride: usize, n: usize) -> i32 {
+                    sys::[<$complex _radix2_inverse>](data, stride, n)
+                }
+
+                unsafe fn radix2_transform(data: *mut Self, stride: usize, n: usize, sign: ::FftDirection) -> i32 {
+                    sys::[<$complex _radix2_transform>](data, stride, n, sign.into())
+                }
+
+                unsafe fn radix2_dif_forward(data: *mut Self, stride: usize, n: usize) -> i32 {
+                    sys::[<$complex _radix2_dif_forward>](data, stride, n)
+                }
+
+                unsafe fn radix2_dif_backward(data: *mut Self, stride: usize, n: usize) -> i32 {
+                    sys::[<$complex _radix2_dif_backward>](data, stride, n)
+                }
+
+                unsafe fn radix2_dif_inverse(data: *mut Self, stride: usize, n: usize) -> i32 {
+                    sys::[<$complex _radix2_dif_inverse>](data, stride, n)
+                }
+
+                unsafe fn radix2_dif_transform(data: *mut Self, stride: usize, n: usize, sign: ::FftDirection) -> i32 {
+                    sys::[<$complex _radix2_dif_transform>](data, stride, n, sign.into())
+                }
+
+                unsafe fn real_radix2_transform(data: *mut Self, stride: usize, n: usize) -> i32 {
+                    sys::[<$real _radix2_transform>](data, stride, n)
+                }
+
+                unsafe fn halfcomplex_radix2_inverse(data: *mut Self, stride: usize, n: usize) -> i32 {
+                    sys::[<$half _radix2_inverse>](data, stride, n)
+                }
+
+                unsafe fn halfcomplex_radix2_backward(data: *mut Self, stride: usize, n: usize) -> i32 {
+                    sys::[<$half _radix2_backward>](data, stride, n)
+                }
+
+                unsafe fn halfcomplex_radix2_unpack(
+                    halfcomplex: *const Self,
+                    complex: *mut Self,
+                    stride: usize,
+                    n: usize,
+                ) -> i32 {
+                    sys::[<$half _radix2_unpack>](halfcomplex, complex, stride, n)
+                }
+            }
+        }
+    };
+}
+
+fft_element!(f64, gsl_fft_complex, gsl_fft_real, gsl_fft_halfcomplex);
+fft_element!(
+    f32,
+    gsl_fft_complex_float,
+    gsl_fft_real_float,
+    gsl_fft_halfcomplex_float
+);
+
+fn plan_for(n: usize) -> Result<::FftPlan, ::Value> {
+    ::FftPlan::new(n).ok_or(::Value::BadLength)
+}
+
+#[test]
+fn fft_round_trip() {
+    use ComplexF64;
+
+    let input = [1., 2., 0., -1., 3., 0.5];
+    let complex: Vec<ComplexF64> = input.iter().map(|&x| ComplexF64::rect(x, 0.)).collect();
+
+    let full = fft(&complex).unwrap();
+    let half = rfft(&input).unwrap();
+    assert_eq!(half.len(), 4);
+    for (a, b) in half.iter().zip(full.iter()) {
+        assert_eq!(
+            format!("{:.6} {:.6}", a.real(), a.imaginary()),
+            format!("{:.6} {:.6}", b.real(), b.imaginary())
+        );
+    }
+
+    let back = ifft(&full).unwrap();
+    let real_back = irfft(&half, input.len()).unwrap();
+    for ((x, z), r) in input.iter().zip(back.iter()).zip(real_back.iter()) {
+        assert_eq!(format!("{:.6}", x), format!("{:.6}", z.real()));
+        assert_eq!(format!("{:.6}", x), format!("{:.6}", r));
+    }
+
+    assert_eq!(irfft(&half, 5), Err(::Value::BadLength));
+    assert_eq!(fft(&[]), Err(::Value::BadLength));
+}
+
+#[test]
+fn fft_float() {
+    let input = [1f32, 2., 0., -1.];
+    let mut packed = [0f32; 8];
+    for (i, x) in input.iter().enumerate() {
+        packed[2 * i] = *x;
+    }
+    let mut real = input;
+    assert_eq!(radix2::forward(&mut packed, 1, 4), ::Value::Success);
+    assert_eq!(real_radix2::transform(&mut real, 1, 4), ::Value::Success);
+    assert_eq!(real[0], packed[0]);
+    assert_eq!(real[1], packed[2]);
+    assert_eq!(real[3], packed[3]);
+
+    let mut data = [1f32, 2., 0., -1., 3., 0.5];
+    let mut workspace = ::FftRealF32Workspace::new(6).unwrap();
+    let real_table = ::FftRealF32WaveTable::new(6).unwrap();
+    let half_table = ::FftHalfComplexF32WaveTable::new(6).unwrap();
+    assert_eq!(
+        workspace.transform(&mut data, 1, 6, &real_table),
+        ::Value::Success
+    );
+    assert_eq!(
+        workspace.halfcomplex_inverse(&mut data, 1, 6, &half_table),
+        ::Value::Success
+    );
+    assert_eq!(format!("{:.4}", data[4]), "3.0000");
+}
+
+#[test]
+#[should_panic]
+fn real_workspace_bounds() {
+    let mut workspace = ::FftRealF64Workspace::new(8).unwrap();
+    let table = ::FftRealF64WaveTable::new(8).unwrap();
+    workspace.transform(&mut [0.; 8], 2, 8, &table);
+}
+
+/// Multi-dimensional FFTs.
+///
+/// GSL only provides one-dimensional transforms. The functions of this module compute an
+/// n-dimensional transform by applying the one-dimensional mixed-radix transform along each axis
+/// in turn, using the stride argument of the GSL routines to walk along the non-contiguous axes
+/// in-place. As for the one-dimensional routines, the forward transform uses a negative exponential
+/// and the inverse transform includes the 1/N scaling.
+///
+/// The transforms along a given axis are independent of each other, so they can be spread across
+/// several threads with [`FftNd::with_threads`].
+///
+/// ```
+/// use rgsl::fft::nd;
+/// use rgsl::{ComplexF64, MatrixComplexF64};
+///
+/// let mut m = MatrixComplexF64::new(4, 6).unwrap();
+/// m.set_all(&ComplexF64::rect(1., 0.));
+/// nd::fft2d(&mut m).unwrap();
+/// assert_eq!(m.get(0, 0).real(), 24.);
+/// ```
+pub mod nd {
+    use crate::{ComplexF64, FftComplexData, FftPlan, FftRealPlan, Value};
+    use crate::{MatrixComplexF64, MatrixF64};
+    use ffi::FFI;
+    use std::marker::PhantomData;
+
+    /// A n-dimensional array of complex numbers described by its shape and by the stride (in
+    /// complex elements) of each axis.
+    pub struct FftNdBuffer<'a> {
+        data: *mut f64,
+        shape: Vec<usize>,
+        strides: Vec<usize>,
+        phantom: PhantomData<&'a mut [ComplexF64]>,
+    }
+
+    impl<'a> FftNdBuffer<'a> {
+        /// Creates a buffer over `data` stored in row-major order (the last axis being
+        /// contiguous). Returns `None` if the product of `shape` doesn't match `data.len()`.
+        pub fn new(data: &'a mut [ComplexF64], shape: &[usize]) -> Option<Self> {
+            if shape.iter().product::<usize>() != data.len() {
+                return None;
+            }
+            let mut strides = vec![1; shape.len()];
+            for i in (1..shape.len()).rev() {
+                strides[i - 1] = strides[i] * shape[i];
+            }
+            Self::with_strides(data, shape, &strides)
+        }
+
+        /// Creates a buffer over `data` where the element at index `(i_0, ..., i_k)` is stored at
+        /// `data[i_0 * strides[0] + ... + i_k * strides[k]]`.
+        ///
+        /// Returns `None` if the buffer would read past the end of `data` or if two different
+        /// indices would map to the same element.
+        pub fn with_strides(
+            data: &'a mut [ComplexF64],
+            shape: &[usize],
+            strides: &[usize],
+        ) -> Option<Self> {
+            if shape.len() != strides.len() || shape.contains(&0) {
+                return None;
+            }
+            let last = shape
+                .iter()
+                .zip(strides.iter())
+                .map(|(&n, &s)| (n - 1) * s)
+                .sum::<usize>();
+            if !shape.is_empty() && last >= data.len() {
+                return None;
+            }
+            // Sorted by stride, each axis must step over the whole extent of the previous ones.
+            let mut axes: Vec<usize> = (0..shape.len()).filter(|&i| shape[i] > 1).collect();
+            axes.sort_by_key(|&i| strides[i]);
+            let mut extent = 1;
+            for &i in axes.iter() {
+                if strides[i] < extent {
+                    return None;
+                }
+                extent = strides[i] * shape[i];
+            }
+            Some(Self {
+                data: data.as_mut_ptr() as *mut f64,
+                shape: shape.to_vec(),
+                strides: strides.to_vec(),
+                phantom: PhantomData,
+            })
+        }
+
+        /// Creates a two-dimensional buffer over the elements of `m`, honouring its row stride.
+        pub fn from_matrix(m: &'a mut MatrixComplexF64) -> Option<Self> {
+            let ptr = m.unwrap_unique();
+            if ptr.is_null() {
+                return None;
+            }
+            let (data, size1, size2, tda) =
+                unsafe { ((*ptr).data, (*ptr).size1, (*ptr).size2, (*ptr).tda) };
+            if data.is_null() || size1 == 0 || size2 == 0 {
+                return None;
+            }
+            Some(Self {
+                data,
+                shape: vec![size1, size2],
+                strides: vec![tda, 1],
+                phantom: PhantomData,
+            })
+        }
+
+        pub fn shape(&self) -> &[usize] {
+            &self.shape
+        }
+
+        pub fn strides(&self) -> &[usize] {
+            &self.strides
+        }
+    }
+
+    /// A line of `n` complex numbers, `stride` elements apart, inside a [`FftNdBuffer`].
+    struct Line {
+        data: *mut f64,
+        stride: usize,
+        n: usize,
+    }
+
+    unsafe impl FftComplexData for Line {
+        fn fft_parts(&mut self) -> (*mut f64, usize, usize) {
+            (self.data, self.stride, self.n)
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct SharedPtr(*mut f64);
+
+    // The lines handed to each thread never overlap, which `FftNdBuffer` guarantees.
+    unsafe impl Send for SharedPtr {}
+    unsafe impl Sync for SharedPtr {}
+
+    #[derive(Clone, Copy)]
+    enum Kind {
+        Transform(::FftDirection),
+        Inverse,
+    }
+
+    /// Runner for multi-dimensional transforms.
+    #[derive(Clone, Copy, Debug)]
+    pub struct FftNd {
+        threads: usize,
+    }
+
+    impl Default for FftNd {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl FftNd {
+        /// Creates a runner computing every transform on the current thread.
+        pub fn new() -> Self {
+            Self { threads: 1 }
+        }
+
+        /// Creates a runner spreading the one-dimensional transforms along each axis over
+        /// `threads` threads. If `threads` is zero, the available parallelism of the machine is
+        /// used.
+        pub fn with_threads(threads: usize) -> Self {
+            let threads = if threads == 0 {
+                ::std::thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1)
+            } else {
+                threads
+            };
+            Self { threads }
+        }
+
+        pub fn threads(&self) -> usize {
+            self.threads
+        }
+
+        /// Computes the transform of `buffer` in-place in the given direction.
+        pub fn transform(
+            &self,
+            buffer: &mut FftNdBuffer,
+            sign: ::FftDirection,
+        ) -> Result<(), Value> {
+            let axes: Vec<usize> = (0..buffer.shape.len()).collect();
+            self.run(buffer, &axes, Kind::Transform(sign))
+        }
+
+        /// Computes the inverse transform of `buffer` in-place, including the 1/N scaling.
+        pub fn inverse(&self, buffer: &mut FftNdBuffer) -> Result<(), Value> {
+            let axes: Vec<usize> = (0..buffer.shape.len()).collect();
+            self.run(buffer, &axes, Kind::Inverse)
+        }
+
+        /// Computes the forward transform of the real row-major array `input` of the given
+        /// `shape`. Since the result has the half-complex symmetry, only the first `n / 2 + 1`
+        /// coefficients along the last axis are returned, in row-major order.
+        pub fn rfft(&self, input: &[f64], shape: &[usize]) -> Result<Vec<ComplexF64>, Value> {
+            let (rows, n, m) = real_dims(shape)?;
+            if input.len() != rows * n {
+                return Err(Value::BadLength);
+            }
+            let mut out = vec![ComplexF64::default(); rows * m];
+            {
+                let src = SharedPtr(input.as_ptr() as *mut f64);
+                let dst = SharedPtr(out.as_mut_ptr() as *mut f64);
+                let offsets: Vec<usize> = (0..rows).collect();
+                self.parallel(&offsets, &|chunk: &[usize]| {
+                    let mut plan = FftRealPlan::new(n).ok_or(Value::NoMemory)?;
+                    for &row in chunk {
+                        let (i, o) = unsafe {
+                            (
+                                ::std::slice::from_raw_parts(src.0.add(row * n), n),
+                                ::std::slice::from_raw_parts_mut(
+                                    (dst.0 as *mut ComplexF64).add(row * m),
+                                    m,
+                                ),
+                            )
+                        };
+                        plan.forward(i, o)?;
+                    }
+                    Ok(())
+                })?;
+            }
+            let mut half_shape = shape.to_vec();
+            *half_shape.last_mut().unwrap() = m;
+            let axes: Vec<usize> = (0..shape.len() - 1).collect();
+            let mut buffer = FftNdBuffer::new(&mut out, &half_shape).ok_or(Value::BadLength)?;
+            self.run(&mut buffer, &axes, Kind::Transform(::FftDirection::Forward))?;
+            Ok(out)
+        }
+
+        /// Computes the real array of the given `shape` whose non-redundant coefficients, as
+        /// returned by [`rfft`](Self::rfft), are `input`.
+        pub fn irfft(&self, input: &[ComplexF64], shape: &[usize]) -> Result<Vec<f64>, Value> {
+            let (rows, n, m) = real_dims(shape)?;
+            if input.len() != rows * m {
+                return Err(Value::BadLength);
+            }
+            let mut tmp = input.to_vec();
+            {
+                let mut half_shape = shape.to_vec();
+                *half_shape.last_mut().unwrap() = m;
+                let axes: Vec<usize> = (0..shape.len() - 1).collect();
+                let mut buffer = FftNdBuffer::new(&mut tmp, &half_shape).ok_or(Value::BadLength)?;
+                self.run(&mut buffer, &axes, Kind::Inverse)?;
+            }
+            let mut out = vec![0.; rows * n];
+            let src = SharedPtr(tmp.as_ptr() as *mut f64);
+            let dst = SharedPtr(out.as_mut_ptr());
+            let offsets: Vec<usize> = (0..rows).collect();
+            self.parallel(&offsets, &|chunk: &[usize]| {
+                let mut plan = FftRealPlan::new(n).ok_or(Value::NoMemory)?;
+                for &row in chunk {
+                    let (i, o) = unsafe {
+                        (
+                            ::std::slice::from_raw_parts(
+                                (src.0 as *const ComplexF64).add(row * m),
+                                m,
+                            ),
+                            ::std::slice::from_raw_parts_mut(dst.0.add(row * n), n),
+                        )
+                    };
+                    plan.inverse(i, o)?;
+                }
+                Ok(())
+            })?;
+            Ok(out)
+        }
+
+        fn run(&self, buffer: &mut FftNdBuffer, axes: &[usize], kind: Kind) -> Result<(), Value> {
+            let data = SharedPtr(buffer.data);
+            for &axis in axes {
+                let n = buffer.shape[axis];
+                if n < 2 {
+                    continue;
+                }
+                let stride = buffer.strides[axis];
+                let offsets = line_offsets(&buffer.shape, &buffer.strides, axis);
+                self.parallel(&offsets, &|chunk: &[usize]| {
+                    let mut plan = FftPlan::new(n).ok_or(Value::NoMemory)?;
+                    for &offset in chunk {
+                        let mut line = Line {
+                            data: unsafe { data.0.add(2 * offset) },
+                            stride,
+                            n,
+                        };
+                        match kind {
+                            Kind::Transform(sign) => plan.transform(&mut line, sign)?,
+                            Kind::Inverse => plan.inverse(&mut line)?,
+                        }
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        }
+
+        fn parallel(
+            &self,
+            offsets: &[usize],
+            f: &(dyn Fn(&[usize]) -> Result<(), Value> + Sync),
+        ) -> Result<(), Value> {
+            if self.threads < 2 || offsets.len() < 2 {
+                return f(offsets);
+            }
+            let chunk = (offsets.len() + self.threads - 1) / self.threads;
+            ::std::thread::scope(|s| {
+                let handles: Vec<_> = offsets
+                    .chunks(chunk)
+                    .map(|c| s.spawn(move || f(c)))
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|h| h.join().unwrap_or(Err(Value::Failure)))
+                    .collect::<Result<Vec<()>, Value>>()
+            })?;
+            Ok(())
+        }
+    }
+
+    /// Returns the offset of the first element of every line along `axis`.
+    fn line_offsets(shape: &[usize], strides: &[usize], axis: usize) -> Vec<usize> {
+        let mut offsets = vec![0];
+        for (i, (&n, &s)) in shape.iter().zip(strides.iter()).enumerate() {
+            if i == axis {
+                continue;
+            }
+            offsets = offsets
+                .iter()
+                .flat_map(|&o| (0..n).map(move |k| o + k * s))
+                .collect();
+        }
+        offsets
+    }
+
+    /// Returns the number of rows, the length of the last axis and the number of non-redundant
+    /// coefficients along it.
+    fn real_dims(shape: &[usize]) -> Result<(usize, usize, usize), Value> {
+        match shape.split_last() {
+            Some((&n, rest)) if n > 0 && rest.iter().all(|&d| d > 0) => {
+                Ok((rest.iter().product(), n, n / 2 + 1))
+            }
+            _ => Err(Value::BadLength),
+        }
+    }
+
+    /// Computes the forward two-dimensional transform of `m` in-place.
+    pub fn fft2d(m: &mut MatrixComplexF64) -> Result<(), Value> {
+        let mut buffer = FftNdBuffer::from_matrix(m).ok_or(Value::BadLength)?;
+        FftNd::new().transform(&mut buffer, ::FftDirection::Forward)
+    }
+
+    /// Computes the inverse two-dimensional transform of `m` in-place, including the 1/N
+    /// scaling.
+    pub fn ifft2d(m: &mut MatrixComplexF64) -> Result<(), Value> {
+        let mut buffer = FftNdBuffer::from_matrix(m).ok_or(Value::BadLength)?;
+        FftNd::new().inverse(&mut buffer)
+    }
+
+    /// Computes the forward two-dimensional transform of the real matrix `m`. The result has
+    /// `m.size2() / 2 + 1` columns.
+    pub fn rfft2d(m: &MatrixF64) -> Result<MatrixComplexF64, Value> {
+        let (n1, n2) = (m.size1(), m.size2());
+        let mut input = Vec::with_capacity(n1 * n2);
+        for i in 0..n1 {
+            for j in 0..n2 {
+                input.push(m.get(i, j));
+            }
+        }
+        let out = FftNd::new().rfft(&input, &[n1, n2])?;
+        let m2 = n2 / 2 + 1;
+        let mut res = MatrixComplexF64::new(n1, m2).ok_or(Value::NoMemory)?;
+        for (k, z) in out.iter().enumerate() {
+            res.set(k / m2, k % m2, z);
+        }
+        Ok(res)
+    }
+
+    /// Computes the real matrix with `n2` columns whose non-redundant coefficients, as returned
+    /// by [`rfft2d`], are `m`.
+    pub fn irfft2d(m: &MatrixComplexF64, n2: usize) -> Result<MatrixF64, Value> {
+        let (n1, m2) = (m.size1(), m.size2());
+        if m2 != n2 / 2 + 1 {
+            return Err(Value::BadLength);
+        }
+        let mut input = Vec::with_capacity(n1 * m2);
+        for i in 0..n1 {
+            for j in 0..m2 {
+                input.push(m.get(i, j));
+            }
+        }
+        let out = FftNd::new().irfft(&input, &[n1, n2])?;
+        let mut res = MatrixF64::new(n1, n2).ok_or(Value::NoMemory)?;
+        for (k, &x) in out.iter().enumerate() {
+            res.set(k / n2, k % n2, x);
+        }
+        Ok(res)
+    }
+
+    /// Computes the forward n-dimensional transform of `buffer` in-place.
+    pub fn fftn(buffer: &mut FftNdBuffer) -> Result<(), Value> {
+        FftNd::new().transform(buffer, ::FftDirection::Forward)
+    }
+
+    /// Computes the inverse n-dimensional transform of `buffer` in-place, including the 1/N
+    /// scaling.
+    pub fn ifftn(buffer: &mut FftNdBuffer) -> Result<(), Value> {
+        FftNd::new().inverse(buffer)
+    }
+
+    /// See [`FftNd::rfft`].
+    pub fn rfftn(input: &[f64], shape: &[usize]) -> Result<Vec<ComplexF64>, Value> {
+        FftNd::new().rfft(input, shape)
+    }
+
+    /// See [`FftNd::irfft`].
+    pub fn irfftn(input: &[ComplexF64], shape: &[usize]) -> Result<Vec<f64>, Value> {
+        FftNd::new().irfft(input, shape)
+    }
+
+    #[test]
+    fn fft_nd() {
+        let shape = [3, 4, 5];
+        let input: Vec<f64> = (0..60).map(|i| ((i * 7) % 11) as f64 - 3.).collect();
+        let mut data: Vec<ComplexF64> = input.iter().map(|&x| ComplexF64::rect(x, 0.)).collect();
+
+        let mut buffer = FftNdBuffer::new(&mut data, &shape).unwrap();
+        FftNd::with_threads(3)
+            .transform(&mut buffer, ::FftDirection::Forward)
+            .unwrap();
+        let sum: f64 = input.iter().sum();
+        assert_eq!(format!("{:.6}", data[0].real()), format!("{:.6}", sum));
+
+        let half = rfftn(&input, &shape).unwrap();
+        assert_eq!(half.len(), 3 * 4 * 3);
+        for (k, z) in half.iter().enumerate() {
+            let full = data[(k / 3) * 5 + k % 3];
+            assert_eq!(
+                format!("{:.6} {:.6}", z.real(), z.imaginary()),
+                format!("{:.6} {:.6}", full.real(), full.imaginary())
+            );
+        }
+
+        let back = irfftn(&half, &shape).unwrap();
+        let mut buffer = FftNdBuffer::new(&mut data, &shape).unwrap();
+        ifftn(&mut buffer).unwrap();
+        for ((x, z), r) in input.iter().zip(data.iter()).zip(back.iter()) {
+            assert_eq!(format!("{:.6}", x), format!("{:.6}", z.real()));
+            assert_eq!(format!("{:.6}", x), format!("{:.6}", r));
+        }
+
+        assert!(FftNdBuffer::with_strides(&mut data, &[2, 2], &[1, 1]).is_none());
+    }
+}
@@ -337,6 +337,16 @@ pub mod beta {
 
         (::Value::from(ret), unsafe { result.assume_init() }.into())
     }
+
+    /// This routine computes the logarithm of the magnitude of the Beta Function, \log(|B(a,b)|) subject to a and b not being negative integers.
+    /// The sign of the Beta Function is returned in sgn. If B(a,b) = 0 then sgn is set to 0.
+    #[doc(alias = "gsl_sf_lnbeta_sgn_e")]
+    pub fn lnbeta_sgn_e(a: f64, b: f64, sgn: &mut f64) -> (Value, ::types::Result) {
+        let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe { sys::gsl_sf_lnbeta_sgn_e(a, b, result.as_mut_ptr(), sgn) };
+
+        (::Value::from(ret), unsafe { result.assume_init() }.into())
+    }
 }
 
 pub mod incomplete_gamma {
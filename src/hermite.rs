@@ -0,0 +1,364 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+Hermite polynomials and functions are discussed in Abramowitz & Stegun, Chapter 22 and Szego,
+Gabor (1939, 1958, 1967), Orthogonal Polynomials, American Mathematical Society.
+
+The Hermite polynomials exist in two variants: the probabilists' version He_n(x), orthogonal with
+respect to the weight \exp(-x^2/2), and the physicists' version H_n(x), orthogonal with respect to
+\exp(-x^2). They are related by H_n(x) = 2^{n/2} He_n(\sqrt{2} x).
+
+The Hermite functions are the normalized eigenfunctions of the quantum harmonic oscillator,
+
+\psi_n(x) = (2^n n! \sqrt\pi)^{-1/2} e^{-x^2/2} H_n(x).
+
+In the array functions below, the number of computed terms is given by the length of the output
+slice, so that `result[n]` holds the term of order (or derivative) `n`. Likewise the number of terms
+of a series is given by the length of its coefficient slice.
+!*/
+
+use crate::Value;
+use std::mem::MaybeUninit;
+
+/// This routine evaluates the probabilists' Hermite polynomial He_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_prob")]
+pub fn hermite_prob(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_prob(n, x) }
+}
+
+/// This routine evaluates the probabilists' Hermite polynomial He_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_prob_e")]
+pub fn hermite_prob_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_prob_e(n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the m-th derivative of the probabilists' Hermite polynomial He_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_prob_deriv")]
+pub fn hermite_prob_deriv(m: i32, n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_prob_deriv(m, n, x) }
+}
+
+/// This routine evaluates the m-th derivative of the probabilists' Hermite polynomial He_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_prob_deriv_e")]
+pub fn hermite_prob_deriv_e(m: i32, n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_prob_deriv_e(m, n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the physicists' Hermite polynomial H_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite")]
+pub fn hermite(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite(n, x) }
+}
+
+/// This routine evaluates the physicists' Hermite polynomial H_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_e")]
+pub fn hermite_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_e(n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the m-th derivative of the physicists' Hermite polynomial H_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_deriv")]
+pub fn hermite_deriv(m: i32, n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_deriv(m, n, x) }
+}
+
+/// This routine evaluates the m-th derivative of the physicists' Hermite polynomial H_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_deriv_e")]
+pub fn hermite_deriv_e(m: i32, n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_deriv_e(m, n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the Hermite function \\psi_n(x) of order n at position x using a three-term recurrence relation. The algorithm complexity is O(n).
+#[doc(alias = "gsl_sf_hermite_func")]
+pub fn hermite_func(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_func(n, x) }
+}
+
+/// This routine evaluates the Hermite function \\psi_n(x) of order n at position x using a three-term recurrence relation. The algorithm complexity is O(n).
+#[doc(alias = "gsl_sf_hermite_func_e")]
+pub fn hermite_func_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_func_e(n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the Hermite function \\psi_n(x) of order n at position x using an asymptotic approximation for large n (n > 1000), and falls back to [`hermite_func`] otherwise. The algorithm complexity is O(1) for large n.
+#[doc(alias = "gsl_sf_hermite_func_fast")]
+pub fn hermite_func_fast(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_func_fast(n, x) }
+}
+
+/// This routine evaluates the Hermite function \\psi_n(x) of order n at position x using an asymptotic approximation for large n (n > 1000), and falls back to [`hermite_func`] otherwise. The algorithm complexity is O(1) for large n.
+#[doc(alias = "gsl_sf_hermite_func_fast_e")]
+pub fn hermite_func_fast_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_func_fast_e(n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the m-th derivative of the Hermite function \\psi_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_func_der")]
+pub fn hermite_func_der(m: i32, n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_func_der(m, n, x) }
+}
+
+/// This routine evaluates the m-th derivative of the Hermite function \\psi_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_func_der_e")]
+pub fn hermite_func_der_e(m: i32, n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_func_der_e(m, n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the probabilists' Hermite polynomials He_n(x) up to order `result_array.len() - 1` at position x.
+#[doc(alias = "gsl_sf_hermite_prob_array")]
+pub fn hermite_prob_array(x: f64, result_array: &mut [f64]) -> Value {
+    if result_array.is_empty() {
+        return Value::BadLength;
+    }
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_prob_array(result_array.len() as i32 - 1, x, result_array.as_mut_ptr())
+    })
+}
+
+/// This routine evaluates the m-th derivative of the probabilists' Hermite polynomials He_n(x) up to order `result_array.len() - 1` at position x.
+#[doc(alias = "gsl_sf_hermite_prob_array_deriv")]
+pub fn hermite_prob_array_deriv(m: i32, x: f64, result_array: &mut [f64]) -> Value {
+    if result_array.is_empty() {
+        return Value::BadLength;
+    }
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_prob_array_deriv(
+            m,
+            result_array.len() as i32 - 1,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This routine evaluates all derivative orders from 0 to `result_array.len() - 1` of the probabilists' Hermite polynomial of order n, He_n(x), at position x.
+#[doc(alias = "gsl_sf_hermite_prob_deriv_array")]
+pub fn hermite_prob_deriv_array(n: i32, x: f64, result_array: &mut [f64]) -> Value {
+    if result_array.is_empty() {
+        return Value::BadLength;
+    }
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_prob_deriv_array(
+            result_array.len() as i32 - 1,
+            n,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This routine evaluates the series \\sum_{j=0}^n a_j He_j(x), where n is `a.len() - 1`, using the Clenshaw algorithm.
+#[doc(alias = "gsl_sf_hermite_prob_series")]
+pub fn hermite_prob_series(x: f64, a: &[f64]) -> f64 {
+    if a.is_empty() {
+        return 0.;
+    }
+    unsafe { sys::gsl_sf_hermite_prob_series(a.len() as i32 - 1, x, a.as_ptr()) }
+}
+
+/// This routine evaluates the series \\sum_{j=0}^n a_j He_j(x), where n is `a.len() - 1`, using the Clenshaw algorithm.
+#[doc(alias = "gsl_sf_hermite_prob_series_e")]
+pub fn hermite_prob_series_e(x: f64, a: &[f64]) -> (Value, ::types::Result) {
+    if a.is_empty() {
+        return (Value::BadLength, ::types::Result::new());
+    }
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        sys::gsl_sf_hermite_prob_series_e(a.len() as i32 - 1, x, a.as_ptr(), result.as_mut_ptr())
+    };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the physicists' Hermite polynomials H_n(x) up to order `result_array.len() - 1` at position x.
+#[doc(alias = "gsl_sf_hermite_array")]
+pub fn hermite_array(x: f64, result_array: &mut [f64]) -> Value {
+    if result_array.is_empty() {
+        return Value::BadLength;
+    }
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_array(result_array.len() as i32 - 1, x, result_array.as_mut_ptr())
+    })
+}
+
+/// This routine evaluates the m-th derivative of the physicists' Hermite polynomials H_n(x) up to order `result_array.len() - 1` at position x.
+#[doc(alias = "gsl_sf_hermite_array_deriv")]
+pub fn hermite_array_deriv(m: i32, x: f64, result_array: &mut [f64]) -> Value {
+    if result_array.is_empty() {
+        return Value::BadLength;
+    }
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_array_deriv(
+            m,
+            result_array.len() as i32 - 1,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This routine evaluates all derivative orders from 0 to `result_array.len() - 1` of the physicists' Hermite polynomial of order n, H_n(x), at position x.
+#[doc(alias = "gsl_sf_hermite_deriv_array")]
+pub fn hermite_deriv_array(n: i32, x: f64, result_array: &mut [f64]) -> Value {
+    if result_array.is_empty() {
+        return Value::BadLength;
+    }
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_deriv_array(
+            result_array.len() as i32 - 1,
+            n,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This routine evaluates the series \\sum_{j=0}^n a_j H_j(x), where n is `a.len() - 1`, using the Clenshaw algorithm.
+#[doc(alias = "gsl_sf_hermite_series")]
+pub fn hermite_series(x: f64, a: &[f64]) -> f64 {
+    if a.is_empty() {
+        return 0.;
+    }
+    unsafe { sys::gsl_sf_hermite_series(a.len() as i32 - 1, x, a.as_ptr()) }
+}
+
+/// This routine evaluates the series \\sum_{j=0}^n a_j H_j(x), where n is `a.len() - 1`, using the Clenshaw algorithm.
+#[doc(alias = "gsl_sf_hermite_series_e")]
+pub fn hermite_series_e(x: f64, a: &[f64]) -> (Value, ::types::Result) {
+    if a.is_empty() {
+        return (Value::BadLength, ::types::Result::new());
+    }
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        sys::gsl_sf_hermite_series_e(a.len() as i32 - 1, x, a.as_ptr(), result.as_mut_ptr())
+    };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the Hermite functions \\psi_n(x) up to order `result_array.len() - 1` at position x.
+#[doc(alias = "gsl_sf_hermite_func_array")]
+pub fn hermite_func_array(x: f64, result_array: &mut [f64]) -> Value {
+    if result_array.is_empty() {
+        return Value::BadLength;
+    }
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_func_array(result_array.len() as i32 - 1, x, result_array.as_mut_ptr())
+    })
+}
+
+/// This routine evaluates the series \\sum_{j=0}^n a_j \\psi_j(x), where n is `a.len() - 1`, using the Clenshaw algorithm.
+#[doc(alias = "gsl_sf_hermite_func_series")]
+pub fn hermite_func_series(x: f64, a: &[f64]) -> f64 {
+    if a.is_empty() {
+        return 0.;
+    }
+    unsafe { sys::gsl_sf_hermite_func_series(a.len() as i32 - 1, x, a.as_ptr()) }
+}
+
+/// This routine evaluates the series \\sum_{j=0}^n a_j \\psi_j(x), where n is `a.len() - 1`, using the Clenshaw algorithm.
+#[doc(alias = "gsl_sf_hermite_func_series_e")]
+pub fn hermite_func_series_e(x: f64, a: &[f64]) -> (Value, ::types::Result) {
+    if a.is_empty() {
+        return (Value::BadLength, ::types::Result::new());
+    }
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        sys::gsl_sf_hermite_func_series_e(a.len() as i32 - 1, x, a.as_ptr(), result.as_mut_ptr())
+    };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the s-th zero of the probabilists' Hermite polynomial He_n(x) of order n. Only positive zeros are returned, counted from s = 1, and for odd n the zero at the origin is returned for s = 0.
+#[doc(alias = "gsl_sf_hermite_prob_zero")]
+pub fn hermite_prob_zero(n: i32, s: i32) -> f64 {
+    unsafe { sys::gsl_sf_hermite_prob_zero(n, s) }
+}
+
+/// This routine evaluates the s-th zero of the probabilists' Hermite polynomial He_n(x) of order n. Only positive zeros are returned, counted from s = 1, and for odd n the zero at the origin is returned for s = 0.
+#[doc(alias = "gsl_sf_hermite_prob_zero_e")]
+pub fn hermite_prob_zero_e(n: i32, s: i32) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_prob_zero_e(n, s, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the s-th zero of the physicists' Hermite polynomial H_n(x) of order n. Only positive zeros are returned, counted from s = 1, and for odd n the zero at the origin is returned for s = 0.
+#[doc(alias = "gsl_sf_hermite_zero")]
+pub fn hermite_zero(n: i32, s: i32) -> f64 {
+    unsafe { sys::gsl_sf_hermite_zero(n, s) }
+}
+
+/// This routine evaluates the s-th zero of the physicists' Hermite polynomial H_n(x) of order n. Only positive zeros are returned, counted from s = 1, and for odd n the zero at the origin is returned for s = 0.
+#[doc(alias = "gsl_sf_hermite_zero_e")]
+pub fn hermite_zero_e(n: i32, s: i32) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_zero_e(n, s, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This routine evaluates the s-th zero of the Hermite function \\psi_n(x) of order n. Only positive zeros are returned, counted from s = 1, and for odd n the zero at the origin is returned for s = 0.
+#[doc(alias = "gsl_sf_hermite_func_zero")]
+pub fn hermite_func_zero(n: i32, s: i32) -> f64 {
+    unsafe { sys::gsl_sf_hermite_func_zero(n, s) }
+}
+
+/// This routine evaluates the s-th zero of the Hermite function \\psi_n(x) of order n. Only positive zeros are returned, counted from s = 1, and for odd n the zero at the origin is returned for s = 0.
+#[doc(alias = "gsl_sf_hermite_func_zero_e")]
+pub fn hermite_func_zero_e(n: i32, s: i32) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_func_zero_e(n, s, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+#[test]
+fn hermite_values() {
+    // H_3(x) = 8x^3 - 12x, He_3(x) = x^3 - 3x
+    assert_eq!(format!("{:.4}", hermite(3, 0.5)), "-5.0000");
+    assert_eq!(format!("{:.4}", hermite_prob(3, 0.5)), "-1.3750");
+    assert_eq!(format!("{:.4}", hermite_deriv(1, 3, 0.5)), "-6.0000");
+
+    let mut h = [0.; 4];
+    assert_eq!(hermite_array(0.5, &mut h), Value::Success);
+    assert_eq!(
+        format!("{:.4} {:.4} {:.4} {:.4}", h[0], h[1], h[2], h[3]),
+        "1.0000 1.0000 -1.0000 -5.0000"
+    );
+    assert_eq!(
+        format!("{:.4}", hermite_series(0.5, &[1., 2., 3.])),
+        "0.0000"
+    );
+
+    // \psi_0(0) = \pi^{-1/4}
+    assert_eq!(format!("{:.6}", hermite_func(0, 0.)), "0.751126");
+    // The positive zero of H_2(x) = 4x^2 - 2 is 1/\sqrt{2}.
+    assert_eq!(format!("{:.6}", hermite_zero(2, 1)), "0.707107");
+    assert_eq!(format!("{:.6}", hermite_prob_zero(2, 1)), "1.000000");
+}
@@ -0,0 +1,669 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Hypothesis tests
+
+This module provides the classical hypothesis tests built on top of the [`statistics`](../statistics/index.html) routines and
+the cumulative distribution functions of [`randist`](../randist/index.html):
+
+ * Student's one-sample, two-sample and paired t-tests, and Welch's unequal variances t-test,
+ * the chi-square goodness-of-fit and independence tests,
+ * the F-test for the equality of two variances,
+ * the one- and two-sample Kolmogorov-Smirnov tests,
+ * the Mann-Whitney U test,
+ * the Shapiro-Wilk test for normality,
+ * the test of a Spearman rank correlation against zero.
+
+Each test returns a [`TestResult`](struct.TestResult.html) holding the test statistic, its degrees of freedom, the p-value and,
+where the test estimates a parameter, a confidence interval for it. Invalid input (samples that are too small, mismatched
+lengths, a confidence level outside (0, 1), ...) is reported as an `Err(Value)` before any computation is done.
+
+```
+use rgsl::hypothesis::{t_test, Alternative};
+
+let x = [5.1, 4.9, 5.6, 5.8, 6.0, 6.3, 5.5, 5.2];
+let res = t_test(&x, 5., Alternative::TwoSided, 0.95).unwrap();
+assert!(res.p_value < 0.05);
+let (lo, hi) = res.confidence_interval.unwrap();
+assert!(lo > 5. && hi < 6.);
+```
+!*/
+
+use crate::Value;
+use randist::chi_squared::chisq_Q;
+use randist::f_distribution::{fdist_P, fdist_Pinv, fdist_Q};
+use randist::gaussian::{ugaussian_P, ugaussian_Pinv, ugaussian_Q};
+use randist::t_distribution::{tdist_P, tdist_Q, tdist_Qinv};
+use statistics;
+
+/// The alternative hypothesis of a test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alternative {
+    /// The tested quantity differs from its value under the null hypothesis.
+    TwoSided,
+    /// The tested quantity is lower than its value under the null hypothesis.
+    Less,
+    /// The tested quantity is greater than its value under the null hypothesis.
+    Greater,
+}
+
+/// The outcome of a hypothesis test.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TestResult {
+    /// The value of the test statistic.
+    pub statistic: f64,
+    /// The degrees of freedom of the statistic's distribution, or the numerator degrees of freedom for the F-test. `None`
+    /// for tests whose statistic isn't described by degrees of freedom.
+    pub df: Option<f64>,
+    /// The denominator degrees of freedom of the F-test.
+    pub df_denominator: Option<f64>,
+    /// The probability, under the null hypothesis, of a statistic at least as extreme as the one observed.
+    pub p_value: f64,
+    /// The confidence interval of the estimated parameter (a mean, a difference of means or a ratio of variances), if the
+    /// test estimates one. One-sided alternatives give intervals with an infinite bound.
+    pub confidence_interval: Option<(f64, f64)>,
+}
+
+impl TestResult {
+    fn new(statistic: f64, df: Option<f64>, p_value: f64) -> TestResult {
+        TestResult {
+            statistic,
+            df,
+            df_denominator: None,
+            p_value: p_value.clamp(0., 1.),
+            confidence_interval: None,
+        }
+    }
+}
+
+fn check_confidence(confidence: f64) -> Result<(), Value> {
+    if confidence > 0. && confidence < 1. {
+        Ok(())
+    } else {
+        Err(Value::Domain)
+    }
+}
+
+fn mean_var(data: &[f64]) -> (f64, f64) {
+    let mean = statistics::mean(data, 1, data.len());
+    (mean, statistics::variance_m(data, 1, data.len(), mean))
+}
+
+// Shared by all the t-tests: `estimate` is the estimated mean (or difference of means), `null` its value under the null
+// hypothesis and `se` the standard error of the estimate.
+fn t_result(
+    estimate: f64,
+    null: f64,
+    se: f64,
+    df: f64,
+    alternative: Alternative,
+    confidence: f64,
+) -> TestResult {
+    let t = (estimate - null) / se;
+    let alpha = 1. - confidence;
+    let (p_value, interval) = match alternative {
+        Alternative::TwoSided => {
+            let q = tdist_Qinv(alpha / 2., df);
+            (
+                2. * tdist_Q(t.abs(), df),
+                (estimate - q * se, estimate + q * se),
+            )
+        }
+        Alternative::Less => (
+            tdist_P(t, df),
+            (f64::NEG_INFINITY, estimate + tdist_Qinv(alpha, df) * se),
+        ),
+        Alternative::Greater => (
+            tdist_Q(t, df),
+            (estimate - tdist_Qinv(alpha, df) * se, f64::INFINITY),
+        ),
+    };
+    let mut res = TestResult::new(t, Some(df), p_value);
+    res.confidence_interval = Some(interval);
+    res
+}
+
+/// Student's one-sample t-test of the null hypothesis that `data` comes from a normal distribution of mean `mu`.
+///
+/// The confidence interval is for the mean of `data`, at the level `confidence` (0.95 for instance). At least two
+/// samples are needed.
+pub fn t_test(
+    data: &[f64],
+    mu: f64,
+    alternative: Alternative,
+    confidence: f64,
+) -> Result<TestResult, Value> {
+    check_confidence(confidence)?;
+    if data.len() < 2 {
+        return Err(Value::BadLength);
+    }
+    let n = data.len() as f64;
+    let (mean, var) = mean_var(data);
+    Ok(t_result(
+        mean,
+        mu,
+        (var / n).sqrt(),
+        n - 1.,
+        alternative,
+        confidence,
+    ))
+}
+
+/// Student's two-sample t-test of the null hypothesis that `data1` and `data2` come from normal distributions with the same
+/// mean and the same (unknown) variance. The pooled variance is used for the standard error, with n1 + n2 - 2 degrees of
+/// freedom.
+///
+/// The confidence interval is for the difference of the means, mean(data1) - mean(data2).
+pub fn t_test_two_sample(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+    confidence: f64,
+) -> Result<TestResult, Value> {
+    check_confidence(confidence)?;
+    if data1.len() < 2 || data2.len() < 2 {
+        return Err(Value::BadLength);
+    }
+    let (n1, n2) = (data1.len() as f64, data2.len() as f64);
+    let (mean1, var1) = mean_var(data1);
+    let (mean2, var2) = mean_var(data2);
+    let df = n1 + n2 - 2.;
+    let pooled = ((n1 - 1.) * var1 + (n2 - 1.) * var2) / df;
+    Ok(t_result(
+        mean1 - mean2,
+        0.,
+        (pooled * (1. / n1 + 1. / n2)).sqrt(),
+        df,
+        alternative,
+        confidence,
+    ))
+}
+
+/// Welch's t-test of the null hypothesis that `data1` and `data2` come from normal distributions with the same mean, without
+/// assuming equal variances. The degrees of freedom are given by the Welch-Satterthwaite equation.
+///
+/// The confidence interval is for the difference of the means, mean(data1) - mean(data2).
+pub fn welch_t_test(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+    confidence: f64,
+) -> Result<TestResult, Value> {
+    check_confidence(confidence)?;
+    if data1.len() < 2 || data2.len() < 2 {
+        return Err(Value::BadLength);
+    }
+    let (n1, n2) = (data1.len() as f64, data2.len() as f64);
+    let (mean1, var1) = mean_var(data1);
+    let (mean2, var2) = mean_var(data2);
+    let (v1, v2) = (var1 / n1, var2 / n2);
+    let df = (v1 + v2) * (v1 + v2) / (v1 * v1 / (n1 - 1.) + v2 * v2 / (n2 - 1.));
+    Ok(t_result(
+        mean1 - mean2,
+        0.,
+        (v1 + v2).sqrt(),
+        df,
+        alternative,
+        confidence,
+    ))
+}
+
+/// The paired t-test of the null hypothesis that the differences `data1[i] - data2[i]` have zero mean. Both slices must have
+/// the same length, of at least two.
+///
+/// The confidence interval is for the mean of the differences.
+pub fn paired_t_test(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+    confidence: f64,
+) -> Result<TestResult, Value> {
+    if data1.len() != data2.len() {
+        return Err(Value::BadLength);
+    }
+    let diff = data1
+        .iter()
+        .zip(data2)
+        .map(|(a, b)| a - b)
+        .collect::<Vec<_>>();
+    t_test(&diff, 0., alternative, confidence)
+}
+
+/// Pearson's chi-square goodness-of-fit test of the `observed` counts against the `expected` ones, with k - 1 degrees of
+/// freedom for k categories.
+///
+/// All the expected counts must be positive.
+pub fn chi_square_test(observed: &[f64], expected: &[f64]) -> Result<TestResult, Value> {
+    if observed.len() < 2 || observed.len() != expected.len() {
+        return Err(Value::BadLength);
+    }
+    if expected.iter().any(|&e| e.is_nan() || e <= 0.) {
+        return Err(Value::Domain);
+    }
+    let statistic = observed
+        .iter()
+        .zip(expected)
+        .map(|(&o, &e)| (o - e) * (o - e) / e)
+        .sum::<f64>();
+    let df = (observed.len() - 1) as f64;
+    Ok(TestResult::new(statistic, Some(df), chisq_Q(statistic, df)))
+}
+
+/// Pearson's chi-square test of independence of the rows and columns of the contingency table `table`, with
+/// (rows - 1)(columns - 1) degrees of freedom.
+///
+/// The table must be at least 2x2 and every row and column must have a positive total.
+pub fn chi_square_independence_test(table: &::MatrixF64) -> Result<TestResult, Value> {
+    let (rows, cols) = (table.size1(), table.size2());
+    if rows < 2 || cols < 2 {
+        return Err(Value::BadLength);
+    }
+    let mut row_sums = vec![0.; rows];
+    let mut col_sums = vec![0.; cols];
+    for (i, row_sum) in row_sums.iter_mut().enumerate() {
+        for (j, col_sum) in col_sums.iter_mut().enumerate() {
+            let x = table.get(i, j);
+            *row_sum += x;
+            *col_sum += x;
+        }
+    }
+    if row_sums
+        .iter()
+        .chain(col_sums.iter())
+        .any(|&s| s.is_nan() || s <= 0.)
+    {
+        return Err(Value::Domain);
+    }
+    let total = row_sums.iter().sum::<f64>();
+    let mut statistic = 0.;
+    for (i, row_sum) in row_sums.iter().enumerate() {
+        for (j, col_sum) in col_sums.iter().enumerate() {
+            let e = row_sum * col_sum / total;
+            let d = table.get(i, j) - e;
+            statistic += d * d / e;
+        }
+    }
+    let df = ((rows - 1) * (cols - 1)) as f64;
+    Ok(TestResult::new(statistic, Some(df), chisq_Q(statistic, df)))
+}
+
+/// The F-test of the null hypothesis that `data1` and `data2` come from normal distributions with the same variance. The
+/// statistic is var(data1) / var(data2), with n1 - 1 and n2 - 1 degrees of freedom.
+///
+/// The confidence interval is for the ratio of the variances.
+pub fn f_test(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+    confidence: f64,
+) -> Result<TestResult, Value> {
+    check_confidence(confidence)?;
+    if data1.len() < 2 || data2.len() < 2 {
+        return Err(Value::BadLength);
+    }
+    let df1 = (data1.len() - 1) as f64;
+    let df2 = (data2.len() - 1) as f64;
+    let f = mean_var(data1).1 / mean_var(data2).1;
+    let alpha = 1. - confidence;
+    let (p_value, interval) = match alternative {
+        Alternative::TwoSided => (
+            2. * fdist_P(f, df1, df2).min(fdist_Q(f, df1, df2)),
+            (
+                f / fdist_Pinv(1. - alpha / 2., df1, df2),
+                f / fdist_Pinv(alpha / 2., df1, df2),
+            ),
+        ),
+        Alternative::Less => (fdist_P(f, df1, df2), (0., f / fdist_Pinv(alpha, df1, df2))),
+        Alternative::Greater => (
+            fdist_Q(f, df1, df2),
+            (f / fdist_Pinv(1. - alpha, df1, df2), f64::INFINITY),
+        ),
+    };
+    let mut res = TestResult::new(f, Some(df1), p_value);
+    res.df_denominator = Some(df2);
+    res.confidence_interval = Some(interval);
+    Ok(res)
+}
+
+fn sorted(data: &[f64]) -> Result<Vec<f64>, Value> {
+    if data.iter().any(|x| x.is_nan()) {
+        return Err(Value::Domain);
+    }
+    let mut v = data.to_vec();
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Ok(v)
+}
+
+// The limiting distribution of the Kolmogorov-Smirnov statistic, Q_KS(x) = 2 \sum_{j>=1} (-1)^{j-1} \exp(-2 j^2 x^2).
+fn kolmogorov_q(x: f64) -> f64 {
+    if x < 0.2 {
+        return 1.;
+    }
+    let mut sum = 0.;
+    let mut sign = 1.;
+    for j in 1..=100 {
+        let j = j as f64;
+        let term = (-2. * j * j * x * x).exp();
+        sum += sign * term;
+        if term < 1e-16 {
+            break;
+        }
+        sign = -sign;
+    }
+    2. * sum
+}
+
+// The p-value of a Kolmogorov-Smirnov statistic d for an effective number of samples n, using Stephens' correction.
+fn kolmogorov_p_value(d: f64, n: f64) -> f64 {
+    let sn = n.sqrt();
+    kolmogorov_q((sn + 0.12 + 0.11 / sn) * d)
+}
+
+/// The one-sample Kolmogorov-Smirnov test of the null hypothesis that `data` is drawn from the continuous distribution with
+/// cumulative distribution function `cdf`. The statistic is the largest distance between the empirical distribution function
+/// and `cdf`, and the p-value is computed from its asymptotic distribution.
+///
+/// Any [`ContinuousDistribution`](../randist/distributions/trait.ContinuousDistribution.html) can be tested with
+/// `|x| dist.cdf(x)`.
+pub fn ks_test<F: Fn(f64) -> f64>(data: &[f64], cdf: F) -> Result<TestResult, Value> {
+    if data.is_empty() {
+        return Err(Value::BadLength);
+    }
+    let data = sorted(data)?;
+    let n = data.len() as f64;
+    let mut d = 0f64;
+    for (i, &x) in data.iter().enumerate() {
+        let f = cdf(x);
+        d = d.max((i + 1) as f64 / n - f).max(f - i as f64 / n);
+    }
+    Ok(TestResult::new(d, None, kolmogorov_p_value(d, n)))
+}
+
+/// The two-sample Kolmogorov-Smirnov test of the null hypothesis that `data1` and `data2` are drawn from the same continuous
+/// distribution. The statistic is the largest distance between the two empirical distribution functions, and the p-value is
+/// computed from its asymptotic distribution.
+pub fn ks_test_two_sample(data1: &[f64], data2: &[f64]) -> Result<TestResult, Value> {
+    if data1.is_empty() || data2.is_empty() {
+        return Err(Value::BadLength);
+    }
+    let (data1, data2) = (sorted(data1)?, sorted(data2)?);
+    let (n1, n2) = (data1.len() as f64, data2.len() as f64);
+    let (mut i, mut j) = (0, 0);
+    let mut d = 0f64;
+    while i < data1.len() && j < data2.len() {
+        let x = data1[i].min(data2[j]);
+        while i < data1.len() && data1[i] <= x {
+            i += 1;
+        }
+        while j < data2.len() && data2[j] <= x {
+            j += 1;
+        }
+        d = d.max((i as f64 / n1 - j as f64 / n2).abs());
+    }
+    Ok(TestResult::new(
+        d,
+        None,
+        kolmogorov_p_value(d, n1 * n2 / (n1 + n2)),
+    ))
+}
+
+// Returns the ranks (starting at 1) of `data`, ties getting the average of their positions, together with the sum of
+// t^3 - t over the groups of t tied values.
+fn ranks(data: &[f64]) -> Result<(Vec<f64>, f64), Value> {
+    if data.iter().any(|x| x.is_nan()) {
+        return Err(Value::Domain);
+    }
+    let mut index = (0..data.len()).collect::<Vec<_>>();
+    index.sort_by(|&a, &b| data[a].partial_cmp(&data[b]).unwrap());
+    let mut ranks = vec![0.; data.len()];
+    let mut ties = 0.;
+    let mut i = 0;
+    while i < index.len() {
+        let mut j = i + 1;
+        while j < index.len() && data[index[j]] == data[index[i]] {
+            j += 1;
+        }
+        let rank = (i + j + 1) as f64 / 2.;
+        for &k in &index[i..j] {
+            ranks[k] = rank;
+        }
+        let t = (j - i) as f64;
+        ties += t * t * t - t;
+        i = j;
+    }
+    Ok((ranks, ties))
+}
+
+/// The Mann-Whitney U test (or Wilcoxon rank-sum test) of the null hypothesis that `data1` and `data2` are drawn from the
+/// same distribution, against the alternative that values of one of them tend to be larger.
+///
+/// The statistic is U for `data1`. The p-value uses the normal approximation with a continuity correction and a correction
+/// for ties, so it is only accurate for moderately large samples (more than about 20 values in total).
+pub fn mann_whitney_u_test(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+) -> Result<TestResult, Value> {
+    if data1.is_empty() || data2.is_empty() {
+        return Err(Value::BadLength);
+    }
+    let combined = data1.iter().chain(data2).cloned().collect::<Vec<_>>();
+    let (ranks, ties) = ranks(&combined)?;
+    let (n1, n2) = (data1.len() as f64, data2.len() as f64);
+    let n = n1 + n2;
+    let r1 = ranks[..data1.len()].iter().sum::<f64>();
+    let u = r1 - n1 * (n1 + 1.) / 2.;
+    let mu = n1 * n2 / 2.;
+    let sigma = (n1 * n2 / 12. * ((n + 1.) - ties / (n * (n - 1.)))).sqrt();
+    let p_value = if sigma > 0. {
+        match alternative {
+            Alternative::TwoSided => 2. * ugaussian_Q(((u - mu).abs() - 0.5) / sigma),
+            Alternative::Less => ugaussian_P((u - mu + 0.5) / sigma),
+            Alternative::Greater => ugaussian_Q((u - mu - 0.5) / sigma),
+        }
+    } else {
+        1.
+    };
+    Ok(TestResult::new(u, None, p_value))
+}
+
+fn poly(c: &[f64], x: f64) -> f64 {
+    c.iter().rev().fold(0., |acc, &c| acc * x + c)
+}
+
+/// The Shapiro-Wilk test of the null hypothesis that `data` is drawn from a normal distribution.
+///
+/// The statistic W and its p-value are computed with Royston's approximations (Applied Statistics algorithm AS R94), which
+/// are valid for 3 to 5000 samples.
+pub fn shapiro_wilk_test(data: &[f64]) -> Result<TestResult, Value> {
+    let n = data.len();
+    if !(3..=5000).contains(&n) {
+        return Err(Value::BadLength);
+    }
+    let x = sorted(data)?;
+    let nf = n as f64;
+    let range = x[n - 1] - x[0];
+    if range.is_nan() || range <= 0. {
+        return Err(Value::Domain);
+    }
+
+    let mut a = vec![0.; n];
+    if n == 3 {
+        a[0] = -::std::f64::consts::FRAC_1_SQRT_2;
+        a[2] = ::std::f64::consts::FRAC_1_SQRT_2;
+    } else {
+        let m = (1..=n)
+            .map(|i| ugaussian_Pinv((i as f64 - 0.375) / (nf + 0.25)))
+            .collect::<Vec<_>>();
+        let mm = m.iter().map(|m| m * m).sum::<f64>();
+        let u = 1. / nf.sqrt();
+        let an = m[n - 1] / mm.sqrt()
+            + poly(&[0., 0.221157, -0.147981, -2.07119, 4.434685, -2.706056], u);
+        let (phi, edge) = if n > 5 {
+            let an1 = m[n - 2] / mm.sqrt()
+                + poly(
+                    &[0., 0.042981, -0.293762, -1.752461, 5.682633, -3.582633],
+                    u,
+                );
+            a[n - 2] = an1;
+            a[1] = -an1;
+            (
+                (mm - 2. * m[n - 1] * m[n - 1] - 2. * m[n - 2] * m[n - 2])
+                    / (1. - 2. * an * an - 2. * an1 * an1),
+                2,
+            )
+        } else {
+            ((mm - 2. * m[n - 1] * m[n - 1]) / (1. - 2. * an * an), 1)
+        };
+        a[n - 1] = an;
+        a[0] = -an;
+        for (a, m) in a[edge..n - edge].iter_mut().zip(&m[edge..n - edge]) {
+            *a = m / phi.sqrt();
+        }
+    }
+
+    let (mean, _) = mean_var(&x);
+    let ssq = x.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>();
+    let b = a.iter().zip(&x).map(|(a, x)| a * x).sum::<f64>();
+    let w = (b * b / ssq).min(1.);
+
+    let p_value = if n == 3 {
+        6. / ::std::f64::consts::PI * (w.sqrt().asin() - (0.75f64).sqrt().asin())
+    } else {
+        let y = (1. - w).ln();
+        let (y, mu, sigma) = if n <= 11 {
+            let gamma = poly(&[-2.273, 0.459], nf);
+            if y >= gamma {
+                return Ok(TestResult::new(w, None, 0.));
+            }
+            (
+                -(gamma - y).ln(),
+                poly(&[0.544, -0.39978, 0.025054, -6.714e-4], nf),
+                poly(&[1.3822, -0.77857, 0.062767, -0.0020322], nf).exp(),
+            )
+        } else {
+            let ln_n = nf.ln();
+            (
+                y,
+                poly(&[-1.5861, -0.31082, -0.083751, 0.0038915], ln_n),
+                poly(&[-0.4803, -0.082676, 0.0030302], ln_n).exp(),
+            )
+        };
+        ugaussian_Q((y - mu) / sigma)
+    };
+    Ok(TestResult::new(w, None, p_value))
+}
+
+/// Tests the null hypothesis that the Spearman rank correlation between `data1` and `data2` is zero. The statistic is
+/// t = r \sqrt{(n - 2) / (1 - r^2)}, which approximately follows a t-distribution with n - 2 degrees of freedom.
+///
+/// The confidence interval is for the correlation coefficient, computed with the Fisher transformation. Both slices must have
+/// the same length, of at least four.
+pub fn spearman_test(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+    confidence: f64,
+) -> Result<TestResult, Value> {
+    check_confidence(confidence)?;
+    let n = data1.len();
+    if n < 4 || n != data2.len() {
+        return Err(Value::BadLength);
+    }
+    let mut work = vec![0.; 2 * n];
+    let r = statistics::spearman(data1, 1, data2, 1, n, &mut work);
+    let nf = n as f64;
+    let df = nf - 2.;
+    let t = r * (df / (1. - r * r)).sqrt();
+    let alpha = 1. - confidence;
+    let z = r.atanh();
+    let se = 1. / (nf - 3.).sqrt();
+    let (p_value, interval) = match alternative {
+        Alternative::TwoSided => {
+            let q = ugaussian_Pinv(1. - alpha / 2.) * se;
+            (2. * tdist_Q(t.abs(), df), ((z - q).tanh(), (z + q).tanh()))
+        }
+        Alternative::Less => (
+            tdist_P(t, df),
+            (-1., (z + ugaussian_Pinv(1. - alpha) * se).tanh()),
+        ),
+        Alternative::Greater => (
+            tdist_Q(t, df),
+            ((z - ugaussian_Pinv(1. - alpha) * se).tanh(), 1.),
+        ),
+    };
+    let mut res = TestResult::new(t, Some(df), p_value);
+    res.confidence_interval = Some(interval);
+    Ok(res)
+}
+
+#[test]
+fn hypothesis() {
+    // mean 3, variance 2.5: t = (3 - 1) / sqrt(0.5)
+    let x = [1., 2., 3., 4., 5.];
+    let res = t_test(&x, 1., Alternative::TwoSided, 0.95).unwrap();
+    assert_eq!(format!("{:.4}", res.statistic), "2.8284");
+    assert_eq!(res.df, Some(4.));
+    let (lo, hi) = res.confidence_interval.unwrap();
+    assert_eq!(format!("{:.4} {:.4}", lo, hi), "1.0368 4.9632");
+    assert_eq!(
+        t_test(&x[..1], 1., Alternative::TwoSided, 0.95),
+        Err(Value::BadLength)
+    );
+    assert_eq!(
+        t_test(&x, 1., Alternative::TwoSided, 1.5),
+        Err(Value::Domain)
+    );
+
+    // With 2 degrees of freedom, Q(t) = 1/2 - t / (2 sqrt(t^2 + 2)).
+    let res = t_test(&[0., 1., 2.], 0., Alternative::Greater, 0.95).unwrap();
+    assert_eq!(format!("{:.4}", res.statistic), "1.7321");
+    assert_eq!(format!("{:.4}", res.p_value), "0.1127");
+
+    let res = paired_t_test(&[2., 3., 4.], &[2., 2., 2.], Alternative::Greater, 0.95).unwrap();
+    assert_eq!(format!("{:.4}", res.p_value), "0.1127");
+
+    // With 2 degrees of freedom, Q(x) = exp(-x / 2).
+    let res = chi_square_test(&[10., 20., 30.], &[20., 20., 20.]).unwrap();
+    assert_eq!(format!("{:.4}", res.statistic), "10.0000");
+    assert_eq!(format!("{:.4}", res.p_value), "0.0067");
+
+    let mut table = ::MatrixF64::new(2, 2).unwrap();
+    table.set(0, 0, 10.);
+    table.set(0, 1, 10.);
+    table.set(1, 0, 10.);
+    table.set(1, 1, 10.);
+    let res = chi_square_independence_test(&table).unwrap();
+    assert_eq!(
+        format!("{:.4} {:.4}", res.statistic, res.p_value),
+        "0.0000 1.0000"
+    );
+
+    let res = f_test(&x, &x, Alternative::TwoSided, 0.95).unwrap();
+    assert_eq!(
+        format!("{:.4} {:.4}", res.statistic, res.p_value),
+        "1.0000 1.0000"
+    );
+    assert_eq!((res.df, res.df_denominator), (Some(4.), Some(4.)));
+
+    let res = ks_test_two_sample(&x, &x).unwrap();
+    assert_eq!(
+        format!("{:.4} {:.4}", res.statistic, res.p_value),
+        "0.0000 1.0000"
+    );
+    let res = ks_test(&[0.1, 0.3, 0.5, 0.7, 0.9], |x| x).unwrap();
+    assert_eq!(format!("{:.4}", res.statistic), "0.1000");
+
+    // U = 0 when every value of data1 is below every value of data2.
+    let res = mann_whitney_u_test(&x, &[6., 7., 8., 9., 10.], Alternative::TwoSided).unwrap();
+    assert_eq!(res.statistic, 0.);
+    assert!(res.p_value < 0.05);
+
+    let res = shapiro_wilk_test(&[1., 2., 3.]).unwrap();
+    assert_eq!(
+        format!("{:.4} {:.4}", res.statistic, res.p_value),
+        "1.0000 1.0000"
+    );
+}
@@ -0,0 +1,369 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# IEEE floating-point arithmetic
+
+This chapter describes functions for examining the representation of floating point numbers and controlling the floating point
+environment of your program.
+
+## Representation of floating point numbers
+
+The IEEE Standard for Binary Floating-Point Arithmetic defines binary formats for single and double precision numbers. Each number is
+composed of three parts: a sign bit (s), an exponent (E) and a fraction (f). The numerical value of the combination (s,E,f) is given by
+the following formula,
+
+(-1)^s (1.fffff...) 2^E
+
+The sign bit is either zero or one. The exponent ranges from a minimum value E_min to a maximum value E_max depending on the precision.
+The exponent is converted to an unsigned number e, known as the biased exponent, for storage by adding a bias parameter, e = E + bias.
+The sequence fffff... represents the digits of the binary fraction f. The binary digits are stored in normalized form, by adjusting the
+exponent to give a leading digit of 1. Since the leading digit is always 1 for normalized numbers it is assumed implicitly and does not
+have to be stored. Numbers smaller than 2^(E_min) are be stored in denormalized form with a leading zero,
+
+(-1)^s (0.fffff...) 2^(E_min)
+
+This allows gradual underflow down to 2^(E_min - p) for p bits of precision. A zero is encoded with the special exponent of 2^(E_min - 1)
+and infinities with the exponent of 2^(E_max + 1).
+
+```
+use rgsl::ieee::FloatRep;
+
+assert_eq!(FloatRep::from_f64(1. / 3.).to_string(),
+           " 1.0101010101010101010101010101010101010101010101010101*2^-2");
+assert_eq!(FloatRep::from_f32(-0.5).to_string(), "-1.00000000000000000000000*2^-1");
+```
+
+## Setting up your IEEE environment
+
+The IEEE standard defines several modes for controlling the behavior of floating point operations. These modes specify the important
+properties of computer arithmetic: the direction used for rounding (e.g. whether numbers should be rounded up, down or to the nearest
+number), the rounding precision and how the program should handle arithmetic exceptions, such as division by zero.
+
+Many of these features can now be controlled via standard functions such as fpsetround(), which should be used whenever they are
+available. Unfortunately in the past there has been no universal API for controlling their behavior—each system has had its own
+low-level way of accessing them. To help you write portable programs GSL allows you to specify modes in a platform-independent way
+using the environment variable GSL_IEEE_MODE, or with [`IeeeMode`](struct.IeeeMode.html).
+
+## References and Further Reading
+
+The reference for the IEEE standard is,
+
+ANSI/IEEE Std 754-1985, IEEE Standard for Binary Floating-Point Arithmetic.
+
+A more pedagogical introduction to the standard can be found in the following paper,
+
+David Goldberg: What Every Computer Scientist Should Know About Floating-Point Arithmetic. ACM Computing Surveys, Vol. 23, No. 1
+(March 1991), pages 5–48.
+!*/
+
+use crate::{IeeePrecision, IeeeRounding, IeeeType, Value};
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::BitOr;
+use std::os::raw::{c_char, c_int, c_void};
+
+// The bindings declare `gsl_ieee_float_rep` and `gsl_ieee_double_rep` as opaque types, these are their C layouts.
+#[repr(C)]
+struct RawFloatRep {
+    sign: c_int,
+    mantissa: [c_char; 24],
+    exponent: c_int,
+    type_: c_int,
+}
+
+#[repr(C)]
+struct RawDoubleRep {
+    sign: c_int,
+    mantissa: [c_char; 53],
+    exponent: c_int,
+    type_: c_int,
+}
+
+/// The broken-down IEEE representation of a floating point number.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FloatRep {
+    /// The sign bit: 0 for positive numbers, 1 for negative ones.
+    pub sign: i32,
+    /// The binary digits of the fraction, as a string of `'0'` and `'1'`.
+    pub mantissa: String,
+    /// The unbiased exponent.
+    pub exponent: i32,
+    pub type_: IeeeType,
+}
+
+impl FloatRep {
+    /// This function converts the double precision number x into its IEEE representation.
+    #[doc(alias = "gsl_ieee_double_to_rep")]
+    pub fn from_f64(x: f64) -> FloatRep {
+        let mut r = RawDoubleRep {
+            sign: 0,
+            mantissa: [0; 53],
+            exponent: 0,
+            type_: 0,
+        };
+        unsafe {
+            sys::gsl_ieee_double_to_rep(&x, &mut r as *mut RawDoubleRep as *mut _);
+        }
+        FloatRep::new(r.sign, &r.mantissa, r.exponent, r.type_)
+    }
+
+    /// This function converts the single precision number x into its IEEE representation.
+    #[doc(alias = "gsl_ieee_float_to_rep")]
+    pub fn from_f32(x: f32) -> FloatRep {
+        let mut r = RawFloatRep {
+            sign: 0,
+            mantissa: [0; 24],
+            exponent: 0,
+            type_: 0,
+        };
+        unsafe {
+            sys::gsl_ieee_float_to_rep(&x, &mut r as *mut RawFloatRep as *mut _);
+        }
+        FloatRep::new(r.sign, &r.mantissa, r.exponent, r.type_)
+    }
+
+    fn new(sign: c_int, mantissa: &[c_char], exponent: c_int, type_: c_int) -> FloatRep {
+        // GSL null-terminates the mantissa, which fills the whole array but for the terminator.
+        let mantissa = unsafe { CStr::from_ptr(mantissa.as_ptr()) }
+            .to_string_lossy()
+            .into_owned();
+        FloatRep {
+            sign,
+            mantissa,
+            exponent,
+            type_: IeeeType::from(type_),
+        }
+    }
+}
+
+/// Formats the representation the way `gsl_ieee_printf_double` does: normalized numbers are printed as `1.fffff...*2^E`,
+/// denormalized numbers as `0.fffff...*2^E_min`, each preceded by a space or a minus sign.
+impl fmt::Display for FloatRep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.sign == 0 { " " } else { "-" };
+        match self.type_ {
+            IeeeType::Nan => write!(f, "NaN"),
+            IeeeType::Inf => write!(f, "{}", if self.sign == 0 { "Inf" } else { "-Inf" }),
+            IeeeType::Normal => write!(f, "{}1.{}*2^{}", sign, self.mantissa, self.exponent),
+            IeeeType::Denormal => write!(f, "{}0.{}*2^{}", sign, self.mantissa, self.exponent + 1),
+            IeeeType::Zero => write!(f, "{}0", sign),
+        }
+    }
+}
+
+/// This function prints the contents of the IEEE representation of the double precision number x to the C `stdout`
+/// stream. The output doesn't go through Rust's `stdout`, use the `Display` implementation of
+/// [`FloatRep`](struct.FloatRep.html) to get the same text as a `String`.
+#[doc(alias = "gsl_ieee_printf_double")]
+pub fn printf_double(x: f64) {
+    unsafe { sys::gsl_ieee_printf_double(&x) }
+}
+
+/// This function prints the contents of the IEEE representation of the single precision number x to the C `stdout`
+/// stream.
+#[doc(alias = "gsl_ieee_printf_float")]
+pub fn printf_float(x: f32) {
+    unsafe { sys::gsl_ieee_printf_float(&x) }
+}
+
+/// A set of floating point exceptions, which can be combined with `|`. The exceptions of the set are masked, i.e. don't
+/// raise a signal when they occur.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ExceptionMask(u32);
+
+impl ExceptionMask {
+    pub const NONE: ExceptionMask = ExceptionMask(0);
+    pub const INVALID: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_INVALID);
+    pub const DENORMALIZED: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_DENORMALIZED);
+    pub const DIVISION_BY_ZERO: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_DIVISION_BY_ZERO);
+    pub const OVERFLOW: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_OVERFLOW);
+    pub const UNDERFLOW: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_UNDERFLOW);
+    pub const ALL: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_ALL);
+    /// Unlike the other flags, this one enables the inexact exception, which is masked by default.
+    pub const TRAP_INEXACT: ExceptionMask = ExceptionMask(sys::GSL_IEEE_TRAP_INEXACT);
+
+    /// Returns `true` if all the exceptions of `other` are in `self`.
+    pub fn contains(self, other: ExceptionMask) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for ExceptionMask {
+    type Output = ExceptionMask;
+
+    fn bitor(self, rhs: ExceptionMask) -> ExceptionMask {
+        ExceptionMask(self.0 | rhs.0)
+    }
+}
+
+/// A floating point mode: the rounding precision and direction, and the masked exceptions. A `None` precision or
+/// rounding leaves the platform default. The exceptions missing from `exception_mask` trap, so the default mode masks
+/// all of them, like the IEEE default environment.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct IeeeMode {
+    pub precision: Option<IeeePrecision>,
+    pub rounding: Option<IeeeRounding>,
+    pub exception_mask: ExceptionMask,
+}
+
+impl Default for IeeeMode {
+    fn default() -> IeeeMode {
+        IeeeMode {
+            precision: None,
+            rounding: None,
+            exception_mask: ExceptionMask::ALL,
+        }
+    }
+}
+
+impl IeeeMode {
+    /// This function parses a mode description in the format of the GSL_IEEE_MODE environment variable, a comma separated
+    /// list of keywords such as `"round-to-nearest,mask-underflow"`.
+    #[doc(alias = "gsl_ieee_read_mode_string")]
+    pub fn parse(description: &str) -> Result<IeeeMode, Value> {
+        let description = CString::new(description).map_err(|_| Value::Invalid)?;
+        let mut precision = 0;
+        let mut rounding = 0;
+        let mut exception_mask = 0;
+        let ret = unsafe {
+            sys::gsl_ieee_read_mode_string(
+                description.as_ptr(),
+                &mut precision,
+                &mut rounding,
+                &mut exception_mask,
+            )
+        };
+        match Value::from(ret) {
+            Value::Success => Ok(IeeeMode {
+                precision: if precision == 0 {
+                    None
+                } else {
+                    Some(IeeePrecision::from(precision))
+                },
+                rounding: if rounding == 0 {
+                    None
+                } else {
+                    Some(IeeeRounding::from(rounding))
+                },
+                exception_mask: ExceptionMask(exception_mask as u32),
+            }),
+            e => Err(e),
+        }
+    }
+
+    /// This function sets the floating point mode until the returned guard is dropped, which restores the previous
+    /// floating point environment of the thread.
+    ///
+    /// Returns the error of `gsl_ieee_set_mode` if the mode isn't supported by the platform.
+    #[doc(alias = "gsl_ieee_set_mode")]
+    pub fn set(&self) -> Result<IeeeModeGuard, Value> {
+        let guard = IeeeModeGuard::new()?;
+        let ret = unsafe {
+            sys::gsl_ieee_set_mode(
+                self.precision.map_or(0, Into::into),
+                self.rounding.map_or(0, Into::into),
+                self.exception_mask.0 as c_int,
+            )
+        };
+        match Value::from(ret) {
+            Value::Success => Ok(guard),
+            e => Err(e),
+        }
+    }
+}
+
+extern "C" {
+    fn fegetenv(envp: *mut c_void) -> c_int;
+    fn fesetenv(envp: *const c_void) -> c_int;
+}
+
+/// Restores the floating point environment saved by [`IeeeMode::set`](struct.IeeeMode.html#method.set) when dropped.
+///
+/// The floating point environment belongs to a thread, so the guard can't be sent to another one.
+#[must_use]
+pub struct IeeeModeGuard {
+    // Large enough for the `fenv_t` of all the supported platforms.
+    env: [u64; 16],
+    phantom: PhantomData<*const ()>,
+}
+
+impl IeeeModeGuard {
+    fn new() -> Result<IeeeModeGuard, Value> {
+        let mut guard = IeeeModeGuard {
+            env: [0; 16],
+            phantom: PhantomData,
+        };
+        if unsafe { fegetenv(guard.env.as_mut_ptr() as *mut c_void) } != 0 {
+            return Err(Value::Failure);
+        }
+        Ok(guard)
+    }
+}
+
+impl Drop for IeeeModeGuard {
+    fn drop(&mut self) {
+        unsafe { fesetenv(self.env.as_ptr() as *const c_void) };
+    }
+}
+
+/// This function reads the environment variable GSL_IEEE_MODE and uses it to set the IEEE arithmetic modes. The variable
+/// holds a comma separated list of keywords such as `"double-precision,round-to-nearest,mask-underflow"`, see
+/// [`IeeeMode::parse`](struct.IeeeMode.html#method.parse). If GSL_IEEE_MODE is empty or undefined then the function
+/// returns immediately and no attempt is made to change the system’s IEEE mode.
+#[doc(alias = "gsl_ieee_env_setup")]
+pub fn env_setup() {
+    unsafe { sys::gsl_ieee_env_setup() }
+}
+
+#[test]
+fn ieee() {
+    assert_eq!(
+        FloatRep::from_f64(1.).to_string(),
+        format!(" 1.{}*2^0", "0".repeat(52))
+    );
+    assert_eq!(FloatRep::from_f64(-0.).to_string(), "-0");
+    assert_eq!(FloatRep::from_f64(f64::INFINITY).to_string(), "Inf");
+    assert_eq!(FloatRep::from_f64(f64::NAN).type_, IeeeType::Nan);
+    let denormal = FloatRep::from_f64(f64::MIN_POSITIVE / 2.);
+    assert_eq!(denormal.type_, IeeeType::Denormal);
+    assert_eq!(
+        denormal.to_string(),
+        format!(" 0.1{}*2^-1022", "0".repeat(51))
+    );
+
+    let mode = IeeeMode::parse("round-to-zero,mask-underflow").unwrap();
+    assert_eq!(mode.rounding, Some(IeeeRounding::ToZero));
+    assert!(mode.exception_mask.contains(ExceptionMask::UNDERFLOW));
+}
+
+#[test]
+fn ieee_mode_guard() {
+    // Read through volatile loads so the additions aren't folded at compile time.
+    let (one, tiny) = unsafe {
+        (
+            std::ptr::read_volatile(&1f64),
+            std::ptr::read_volatile(&1e-30f64),
+        )
+    };
+    {
+        let _guard = IeeeMode {
+            rounding: Some(IeeeRounding::Up),
+            ..IeeeMode::default()
+        }
+        .set()
+        .unwrap();
+        assert!(one + tiny > one);
+    }
+    assert_eq!(one + tiny, one);
+}
+
+#[test]
+fn ieee_mode_default_masks_exceptions() {
+    let zero = unsafe { std::ptr::read_volatile(&0f64) };
+    let _guard = IeeeMode::default().set().unwrap();
+    assert!((zero / zero).is_nan());
+    assert_eq!(1. / zero, f64::INFINITY);
+}
@@ -184,6 +184,94 @@ pub mod associated_polynomials {
         (Value::from(ret), unsafe { result.assume_init() }.into())
     }
 
+    /// This routine computes an array of Legendre polynomials P_l^m(x) for m >= 0, l = m, ..., lmax, |x| <= 1,
+    /// where lmax is derived from the length of result_array (which holds lmax - m + 1 values).
+    #[doc(alias = "gsl_sf_legendre_Plm_array")]
+    pub fn legendre_Plm_array(m: i32, x: f64, result_array: &mut [f64]) -> Value {
+        if result_array.is_empty() {
+            return Value::BadLength;
+        }
+        let lmax = m + result_array.len() as i32 - 1;
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_Plm_array(lmax, m, x, result_array.as_mut_ptr())
+        })
+    }
+
+    /// This routine computes an array of Legendre polynomials P_l^m(x) and their derivatives dP_l^m(x)/dx for m >= 0, l = m, ..., lmax, |x| <= 1,
+    /// where lmax is derived from the length of result_array. Both slices must have the same length.
+    #[doc(alias = "gsl_sf_legendre_Plm_deriv_array")]
+    pub fn legendre_Plm_deriv_array(
+        m: i32,
+        x: f64,
+        result_array: &mut [f64],
+        result_deriv_array: &mut [f64],
+    ) -> Value {
+        if result_array.is_empty() || result_array.len() != result_deriv_array.len() {
+            return Value::BadLength;
+        }
+        let lmax = m + result_array.len() as i32 - 1;
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_Plm_deriv_array(
+                lmax,
+                m,
+                x,
+                result_array.as_mut_ptr(),
+                result_deriv_array.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// This routine computes an array of normalized associated Legendre functions \sqrt{(2l+1)/(4\pi)} \sqrt{(l-m)!/(l+m)!} P_l^m(x)
+    /// for m >= 0, l = m, ..., lmax, |x| <= 1, where lmax is derived from the length of result_array.
+    #[doc(alias = "gsl_sf_legendre_sphPlm_array")]
+    pub fn legendre_sphPlm_array(m: i32, x: f64, result_array: &mut [f64]) -> Value {
+        if result_array.is_empty() {
+            return Value::BadLength;
+        }
+        let lmax = m + result_array.len() as i32 - 1;
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_sphPlm_array(lmax, m, x, result_array.as_mut_ptr())
+        })
+    }
+
+    /// This routine computes an array of normalized associated Legendre functions and their derivatives
+    /// for m >= 0, l = m, ..., lmax, |x| <= 1, where lmax is derived from the length of result_array.
+    /// Both slices must have the same length.
+    #[doc(alias = "gsl_sf_legendre_sphPlm_deriv_array")]
+    pub fn legendre_sphPlm_deriv_array(
+        m: i32,
+        x: f64,
+        result_array: &mut [f64],
+        result_deriv_array: &mut [f64],
+    ) -> Value {
+        if result_array.is_empty() || result_array.len() != result_deriv_array.len() {
+            return Value::BadLength;
+        }
+        let lmax = m + result_array.len() as i32 - 1;
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_sphPlm_deriv_array(
+                lmax,
+                m,
+                x,
+                result_array.as_mut_ptr(),
+                result_deriv_array.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// Returns the size of the array needed by [`legendre_Plm_array`](fn.legendre_Plm_array.html) and friends, which is lmax - m + 1.
+    #[doc(alias = "gsl_sf_legendre_array_size")]
+    pub fn legendre_array_size(lmax: i32, m: i32) -> i32 {
+        unsafe { sys::gsl_sf_legendre_array_size(lmax, m) }
+    }
+
+    /// Returns the total number of associated Legendre functions P_l^m(x) with 0 <= m <= l <= lmax,
+    /// which is (lmax + 1)(lmax + 2)/2.
+    #[doc(alias = "gsl_sf_legendre_nlm")]
+    pub fn legendre_nlm(lmax: usize) -> usize {
+        unsafe { sys::gsl_sf_legendre_nlm(lmax) }
+    }
+
     /// Returns the size of the array needed for these functions, including GSL workspace.
     #[doc(alias = "gsl_sf_legendre_array_n")]
     pub fn legendre_array_n(lmax: usize) -> usize {
@@ -225,6 +313,34 @@ pub mod associated_polynomials {
             )
         })
     }
+
+    /// This routine computes all associated Legendre functions P_l^m(x) with 0 <= m <= l <= lmax together with
+    /// their first and second derivatives with respect to x. Each slice must hold at least
+    /// [`legendre_array_n`](fn.legendre_array_n.html)(lmax) elements, otherwise `Value::BadLength` is returned.
+    #[doc(alias = "gsl_sf_legendre_deriv2_array")]
+    pub fn legendre_deriv2_array(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+        deriv2: &mut [f64],
+    ) -> Value {
+        let n = legendre_array_n(lmax);
+        if result.len() < n || deriv.len() < n || deriv2.len() < n {
+            return Value::BadLength;
+        }
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_deriv2_array(
+                norm.into(),
+                lmax,
+                x,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+                deriv2.as_mut_ptr(),
+            )
+        })
+    }
 }
 
 /// The Conical Functions P^\mu_{-(1/2)+i\lambda}(x) and Q^\mu_{-(1/2)+i\lambda} are described in Abramowitz & Stegun, Section 8.12.
@@ -393,3 +509,19 @@ pub mod radial {
         })
     }
 }
+
+#[test]
+fn legendre_Plm_arrays() {
+    use self::associated_polynomials::*;
+
+    // P_0(0.5), P_1(0.5), P_2(0.5)
+    let mut res = [0.; 3];
+    assert_eq!(legendre_Plm_array(0, 0.5, &mut res), ::Value::Success);
+    assert_eq!(
+        format!("{:.4} {:.4} {:.4}", res[0], res[1], res[2]),
+        "1.0000 0.5000 -0.1250"
+    );
+    assert_eq!(legendre_array_size(2, 0), 3);
+    assert_eq!(legendre_nlm(2), 6);
+    assert_eq!(legendre_Plm_array(0, 0.5, &mut []), ::Value::BadLength);
+}
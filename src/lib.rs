@@ -16,6 +16,12 @@
 #![doc = include_str!("../README.md")]
 
 extern crate gsl_sys as sys;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+#[cfg(feature = "num-complex")]
+extern crate num_complex;
 extern crate paste;
 
 pub use types::*;
@@ -25,6 +31,7 @@ pub use pow::Pow;
 pub use trigonometric::Trigonometric;
 pub use types::rng;
 pub use utilities::IOStream;
+pub use version::{version, GslVersion};
 
 // enums part
 pub use self::enums::*;
@@ -32,6 +39,7 @@ pub use self::enums::*;
 mod enums;
 mod macros;
 mod utilities;
+mod version;
 
 #[doc(hidden)]
 pub mod ffi;
@@ -58,13 +66,18 @@ pub mod exponential;
 pub mod exponential_integrals;
 pub mod fermi_dirac;
 pub mod fft;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub mod filter;
 pub mod fit;
 pub mod gamma_beta;
 pub mod gegenbauer;
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+pub mod hermite;
 pub mod hypergeometric;
+pub mod hypothesis;
+pub mod ieee;
 pub mod integration;
 pub mod interpolation;
 pub mod jacobian_elliptic;
@@ -73,9 +86,10 @@ pub mod lambert_w;
 pub mod legendre;
 pub mod linear_algebra;
 pub mod logarithm;
+pub mod matrix_functions;
 pub mod minimizer;
 pub mod multifit;
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub mod multilarge;
 pub mod multilinear;
@@ -86,8 +100,10 @@ pub mod pow;
 pub mod power;
 pub mod psi;
 pub mod roots;
+pub mod signal;
 pub mod sort;
 pub mod statistics;
+#[deprecated(note = "use the `statistics` module instead")]
 pub mod stats;
 pub mod synchrotron;
 pub mod transport;
@@ -9,6 +9,10 @@ This chapter describes functions for solving linear systems. The library provide
 gsl_vector and gsl_matrix objects. These routines use the standard algorithms from Golub & Van Loan’s Matrix Computations with Level-1 and
 Level-2 BLAS calls for efficiency.
 
+The functions of this module work on packed outputs the caller has to keep together. The owned decomposition types of the
+[`decomposition`](../types/decomposition/index.html) module (`Lu`, `Qr`, `QrPivoted`, `Cholesky`, `Ldlt`, `Svd`,
+`Bidiag` and `Hessenberg`) do it for you.
+
 ## LU Decomposition
 
 A general square matrix A has an LU decomposition into upper and lower triangular matrices,
@@ -1374,14 +1378,14 @@ pub fn balance_matrix(a: &mut ::MatrixF64, d: &mut ::VectorF64) -> Value {
     Value::from(unsafe { sys::gsl_linalg_balance_matrix(a.unwrap_unique(), d.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_decomp")]
 pub fn pcholesky_decomp(a: &mut ::MatrixF64, p: &mut ::Permutation) -> Value {
     Value::from(unsafe { sys::gsl_linalg_pcholesky_decomp(a.unwrap_unique(), p.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_solve")]
 pub fn pcholesky_solve(
@@ -1400,7 +1404,7 @@ pub fn pcholesky_solve(
     })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_svx")]
 pub fn pcholesky_svx(LDLT: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64) -> Value {
@@ -1409,7 +1413,7 @@ pub fn pcholesky_svx(LDLT: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64)
     })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_decomp2")]
 pub fn pcholesky_decomp2(A: &mut ::MatrixF64, p: &mut ::Permutation, S: &mut ::VectorF64) -> Value {
@@ -1418,7 +1422,7 @@ pub fn pcholesky_decomp2(A: &mut ::MatrixF64, p: &mut ::Permutation, S: &mut ::V
     })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_solve2")]
 pub fn pcholesky_solve2(
@@ -1439,7 +1443,7 @@ pub fn pcholesky_solve2(
     })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_svx2")]
 pub fn pcholesky_svx2(
@@ -1458,7 +1462,7 @@ pub fn pcholesky_svx2(
     })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_invert")]
 pub fn pcholesky_invert(LDLT: &::MatrixF64, p: &::Permutation, Ainv: &mut ::MatrixF64) -> Value {
@@ -1472,7 +1476,7 @@ pub fn pcholesky_invert(LDLT: &::MatrixF64, p: &::Permutation, Ainv: &mut ::Matr
 }
 
 /// Returns `(Value, rcond)`.
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_rcond")]
 pub fn pcholesky_rcond(
@@ -1492,7 +1496,7 @@ pub fn pcholesky_rcond(
     (Value::from(ret), rcond)
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_mcholesky_decomp")]
 pub fn mcholesky_decomp(A: &mut ::MatrixF64, p: &mut ::Permutation, E: &mut ::VectorF64) -> Value {
@@ -1501,7 +1505,7 @@ pub fn mcholesky_decomp(A: &mut ::MatrixF64, p: &mut ::Permutation, E: &mut ::Ve
     })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_mcholesky_solve")]
 pub fn mcholesky_solve(
@@ -1520,7 +1524,7 @@ pub fn mcholesky_solve(
     })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_mcholesky_svx")]
 pub fn mcholesky_svx(LDLT: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64) -> Value {
@@ -1530,7 +1534,7 @@ pub fn mcholesky_svx(LDLT: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64)
 }
 
 /// Returns `(Value, rcond)`.
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_mcholesky_rcond")]
 pub fn mcholesky_rcond(
@@ -1550,7 +1554,7 @@ pub fn mcholesky_rcond(
     (Value::from(ret), rcond)
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_mcholesky_invert")]
 pub fn mcholesky_invert(LDLT: &::MatrixF64, p: &::Permutation, Ainv: &mut ::MatrixF64) -> Value {
@@ -1563,14 +1567,14 @@ pub fn mcholesky_invert(LDLT: &::MatrixF64, p: &::Permutation, Ainv: &mut ::Matr
     })
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_decomp")]
 pub fn cholesky_band_decomp(A: &mut ::MatrixF64) -> Value {
     Value::from(unsafe { sys::gsl_linalg_cholesky_band_decomp(A.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_solve")]
 pub fn cholesky_band_solve(LLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
@@ -1583,7 +1587,7 @@ pub fn cholesky_band_solve(LLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF
     })
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_svx")]
 pub fn cholesky_band_svx(LLT: &::MatrixF64, x: &mut ::VectorF64) -> Value {
@@ -1592,7 +1596,7 @@ pub fn cholesky_band_svx(LLT: &::MatrixF64, x: &mut ::VectorF64) -> Value {
     })
 }
 
-#[cfg(feature = "v2_7")]
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
 #[doc(alias = "gsl_linalg_cholesky_band_solvem")]
 pub fn cholesky_band_solvem(LLT: &::MatrixF64, B: &::MatrixF64, X: &mut ::MatrixF64) -> Value {
@@ -1605,7 +1609,7 @@ pub fn cholesky_band_solvem(LLT: &::MatrixF64, B: &::MatrixF64, X: &mut ::Matrix
     })
 }
 
-#[cfg(feature = "v2_7")]
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
 #[doc(alias = "gsl_linalg_cholesky_band_svxm")]
 pub fn cholesky_band_svxm(LLT: &::MatrixF64, X: &mut ::MatrixF64) -> Value {
@@ -1614,7 +1618,7 @@ pub fn cholesky_band_svxm(LLT: &::MatrixF64, X: &mut ::MatrixF64) -> Value {
     })
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_invert")]
 pub fn cholesky_band_invert(LLT: &::MatrixF64, Ainv: &mut ::MatrixF64) -> Value {
@@ -1623,7 +1627,7 @@ pub fn cholesky_band_invert(LLT: &::MatrixF64, Ainv: &mut ::MatrixF64) -> Value
     })
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_unpack")]
 pub fn cholesky_band_unpack(LLT: &::MatrixF64, L: &mut ::MatrixF64) -> Value {
@@ -1633,7 +1637,7 @@ pub fn cholesky_band_unpack(LLT: &::MatrixF64, L: &mut ::MatrixF64) -> Value {
 }
 
 /// Returns `(Value, rcond)`.
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_rcond")]
 pub fn cholesky_band_rcond(LLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
@@ -1644,14 +1648,14 @@ pub fn cholesky_band_rcond(LLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value,
     (Value::from(ret), rcond)
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_decomp")]
 pub fn ldlt_decomp(A: &mut ::MatrixF64) -> Value {
     Value::from(unsafe { sys::gsl_linalg_ldlt_decomp(A.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_solve")]
 pub fn ldlt_solve(LDLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
@@ -1660,7 +1664,7 @@ pub fn ldlt_solve(LDLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> V
     })
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_svx")]
 pub fn ldlt_svx(LDLT: &::MatrixF64, x: &mut ::VectorF64) -> Value {
@@ -1668,7 +1672,7 @@ pub fn ldlt_svx(LDLT: &::MatrixF64, x: &mut ::VectorF64) -> Value {
 }
 
 /// Returns `(Value, rcond)`.
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_rcond")]
 pub fn ldlt_rcond(LDLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
@@ -1679,14 +1683,14 @@ pub fn ldlt_rcond(LDLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
     (Value::from(ret), rcond)
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_band_decomp")]
 pub fn ldlt_band_decomp(A: &mut ::MatrixF64) -> Value {
     Value::from(unsafe { sys::gsl_linalg_ldlt_band_decomp(A.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_band_solve")]
 pub fn ldlt_band_solve(LDLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
@@ -1695,14 +1699,14 @@ pub fn ldlt_band_solve(LDLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64)
     })
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_band_svx")]
 pub fn ldlt_band_svx(LDLT: &::MatrixF64, x: &mut ::VectorF64) -> Value {
     Value::from(unsafe { sys::gsl_linalg_ldlt_band_svx(LDLT.unwrap_shared(), x.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_band_unpack")]
 pub fn ldlt_band_unpack(LDLT: &::MatrixF64, L: &mut ::MatrixF64, D: &mut ::VectorF64) -> Value {
@@ -1712,7 +1716,7 @@ pub fn ldlt_band_unpack(LDLT: &::MatrixF64, L: &mut ::MatrixF64, D: &mut ::Vecto
 }
 
 /// Returns `(Value, rcond)`.
-#[cfg(feature = "v2_6")]
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_band_rcond")]
 pub fn ldlt_band_rcond(LDLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
@@ -1723,45 +1727,43 @@ pub fn ldlt_band_rcond(LDLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f6
     (Value::from(ret), rcond)
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_upper_invert")]
 pub fn tri_upper_invert(T: &mut ::MatrixF64) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_upper_invert(T.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_lower_invert")]
 pub fn tri_lower_invert(T: &mut ::MatrixF64) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_lower_invert(T.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_upper_unit_invert")]
 pub fn tri_upper_unit_invert(T: &mut ::MatrixF64) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_upper_unit_invert(T.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_lower_unit_invert")]
 pub fn tri_lower_unit_invert(T: &mut ::MatrixF64) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_lower_unit_invert(T.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_2")]
+/// Inverts the triangular matrix `T` in place.
+///
+/// Up to version 4.0.4, this function wrapped `gsl_linalg_complex_tri_invert`: use
+/// [`complex_tri_invert`](fn.complex_tri_invert.html) for complex matrices.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
-#[doc(alias = "gsl_linalg_complex_tri_invert")]
-pub fn tri_invert(
-    Uplo: enums::CblasUplo,
-    Diag: enums::CblasDiag,
-    T: &mut ::MatrixComplexF64,
-) -> Value {
-    Value::from(unsafe {
-        sys::gsl_linalg_complex_tri_invert(Uplo.into(), Diag.into(), T.unwrap_unique())
-    })
+#[doc(alias = "gsl_linalg_tri_invert")]
+pub fn tri_invert(Uplo: enums::CblasUplo, Diag: enums::CblasDiag, T: &mut ::MatrixF64) -> Value {
+    Value::from(unsafe { sys::gsl_linalg_tri_invert(Uplo.into(), Diag.into(), T.unwrap_unique()) })
 }
 
 #[doc(alias = "gsl_linalg_complex_tri_invert")]
@@ -1775,14 +1777,14 @@ pub fn complex_tri_invert(
     })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_LTL")]
 pub fn tri_LTL(L: &mut ::MatrixF64) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_LTL(L.unwrap_unique()) })
 }
 
-#[cfg(feature = "v2_2")]
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_UL")]
 pub fn tri_UL(LU: &mut ::MatrixF64) -> Value {
@@ -1812,3 +1814,1015 @@ pub fn givens(a: f64, b: f64) -> (f64, f64) {
 pub fn givens_gv(v: &mut ::VectorF64, i: usize, j: usize, c: f64, s: f64) {
     unsafe { sys::gsl_linalg_givens_gv(v.unwrap_unique(), i, j, c, s) }
 }
+
+/// This function factors the M-by-N matrix A into the QR decomposition A = Q R using the recursive Level 3 BLAS
+/// algorithm of Elmroth and Gustavson. On output the diagonal and upper triangular part of A contain the matrix R, and
+/// the Householder vectors are stored below the diagonal. The N-by-N upper triangular block reflector T must be
+/// provided; Q = I - V T V^T.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_decomp_r")]
+pub fn QR_decomp_r(A: &mut ::MatrixF64, T: &mut ::MatrixF64) -> Value {
+    Value::from(unsafe { sys::gsl_linalg_QR_decomp_r(A.unwrap_unique(), T.unwrap_unique()) })
+}
+
+/// This function solves the square system A x = b using the QR decomposition (QR, T) computed by QR_decomp_r.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_solve_r")]
+pub fn QR_solve_r(
+    QR: &::MatrixF64,
+    T: &::MatrixF64,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_solve_r(
+            QR.unwrap_shared(),
+            T.unwrap_shared(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+        )
+    })
+}
+
+/// This function finds the least squares solution to the overdetermined system A x = b using the QR decomposition
+/// (QR, T) computed by QR_decomp_r. x must be of length M: on output its first N elements hold the solution and the
+/// last M - N elements the residual vector Q^T b. work is a workspace of length N.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_lssolve_r")]
+pub fn QR_lssolve_r(
+    QR: &::MatrixF64,
+    T: &::MatrixF64,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+    work: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_lssolve_r(
+            QR.unwrap_shared(),
+            T.unwrap_shared(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+            work.unwrap_unique(),
+        )
+    })
+}
+
+/// This function applies Q^T, encoded in the decomposition (QR, T) computed by QR_decomp_r, to the vector b in place.
+/// work is a workspace of length N.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_QTvec_r")]
+pub fn QR_QTvec_r(
+    QR: &::MatrixF64,
+    T: &::MatrixF64,
+    b: &mut ::VectorF64,
+    work: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_QTvec_r(
+            QR.unwrap_shared(),
+            T.unwrap_shared(),
+            b.unwrap_unique(),
+            work.unwrap_unique(),
+        )
+    })
+}
+
+/// This function applies Q^T, encoded in the decomposition (QR, T) computed by QR_decomp_r, to the M-by-K matrix B in
+/// place. work is a N-by-K workspace.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_QTmat_r")]
+pub fn QR_QTmat_r(
+    QR: &::MatrixF64,
+    T: &::MatrixF64,
+    B: &mut ::MatrixF64,
+    work: &mut ::MatrixF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_QTmat_r(
+            QR.unwrap_shared(),
+            T.unwrap_shared(),
+            B.unwrap_unique(),
+            work.unwrap_unique(),
+        )
+    })
+}
+
+/// This function unpacks the decomposition (QR, T) computed by QR_decomp_r into the M-by-M matrix Q and the N-by-N
+/// matrix R.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_unpack_r")]
+pub fn QR_unpack_r(
+    QR: &::MatrixF64,
+    T: &::MatrixF64,
+    Q: &mut ::MatrixF64,
+    R: &mut ::MatrixF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_unpack_r(
+            QR.unwrap_shared(),
+            T.unwrap_shared(),
+            Q.unwrap_unique(),
+            R.unwrap_unique(),
+        )
+    })
+}
+
+/// This function estimates the reciprocal condition number (using the 1-norm) of the R factor of the QR decomposition.
+/// work is a workspace of length 3N.
+///
+/// Returns `(Value, rcond)`.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_rcond")]
+pub fn QR_rcond(QR: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
+    let mut rcond = 0.;
+    let ret =
+        unsafe { sys::gsl_linalg_QR_rcond(QR.unwrap_shared(), &mut rcond, work.unwrap_unique()) };
+    (Value::from(ret), rcond)
+}
+
+/// This function computes the QR decomposition of the stacked matrix [S; A], where S is a N-by-N upper triangular
+/// matrix and A is a M-by-N dense matrix. On output S is replaced by R, A by the Householder vectors Y, and T holds the
+/// N-by-N block reflector.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_UR_decomp")]
+pub fn QR_UR_decomp(S: &mut ::MatrixF64, A: &mut ::MatrixF64, T: &mut ::MatrixF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_UR_decomp(S.unwrap_unique(), A.unwrap_unique(), T.unwrap_unique())
+    })
+}
+
+/// This function finds the least squares solution of [S; A] x = b using the decomposition (R, Y, T) computed by
+/// QR_UR_decomp. b has length N + M; x has the same length, its first N elements holding the solution and the last M
+/// elements the residual. work is a workspace of length N.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_UR_lssolve")]
+pub fn QR_UR_lssolve(
+    R: &::MatrixF64,
+    Y: &::MatrixF64,
+    T: &::MatrixF64,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+    work: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_UR_lssolve(
+            R.unwrap_shared(),
+            Y.unwrap_shared(),
+            T.unwrap_shared(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+            work.unwrap_unique(),
+        )
+    })
+}
+
+/// This function applies Q^T, encoded in the (Y, T) output of QR_UR_decomp, to the vector b of length N + M in place.
+/// work is a workspace of length N.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_UR_QTvec")]
+pub fn QR_UR_QTvec(
+    Y: &::MatrixF64,
+    T: &::MatrixF64,
+    b: &mut ::VectorF64,
+    work: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_UR_QTvec(
+            Y.unwrap_shared(),
+            T.unwrap_shared(),
+            b.unwrap_unique(),
+            work.unwrap_unique(),
+        )
+    })
+}
+
+/// This function computes the QR decomposition of the stacked matrix [U; S], where U and S are N-by-N upper
+/// triangular matrices. On output U is replaced by R, S by the Householder vectors Y, and T holds the N-by-N block
+/// reflector.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_UU_decomp")]
+pub fn QR_UU_decomp(U: &mut ::MatrixF64, S: &mut ::MatrixF64, T: &mut ::MatrixF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_UU_decomp(U.unwrap_unique(), S.unwrap_unique(), T.unwrap_unique())
+    })
+}
+
+/// This function finds the least squares solution of [U; S] x = b using the decomposition (R, Y, T) computed by
+/// QR_UU_decomp. b has length 2N; x has the same length, its first N elements holding the solution and the last N
+/// elements the residual. work is a workspace of length N.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_UU_lssolve")]
+pub fn QR_UU_lssolve(
+    R: &::MatrixF64,
+    Y: &::MatrixF64,
+    T: &::MatrixF64,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+    work: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_UU_lssolve(
+            R.unwrap_shared(),
+            Y.unwrap_shared(),
+            T.unwrap_shared(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+            work.unwrap_unique(),
+        )
+    })
+}
+
+/// This function applies Q^T, encoded in the (Y, T) output of QR_UU_decomp, to the vector b of length 2N in place.
+/// work is a workspace of length N.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_UU_QTvec")]
+pub fn QR_UU_QTvec(
+    Y: &::MatrixF64,
+    T: &::MatrixF64,
+    b: &mut ::VectorF64,
+    work: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_UU_QTvec(
+            Y.unwrap_shared(),
+            T.unwrap_shared(),
+            b.unwrap_unique(),
+            work.unwrap_unique(),
+        )
+    })
+}
+
+/// This function computes the QR decomposition of the stacked matrix [S; A; 0], where S is a N-by-N upper triangular
+/// matrix and A is a M-by-N dense matrix, without referencing the zero block. On output S is replaced by R, A by the
+/// Householder vectors, and T holds the N-by-N block reflector.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_UZ_decomp")]
+pub fn QR_UZ_decomp(S: &mut ::MatrixF64, A: &mut ::MatrixF64, T: &mut ::MatrixF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_UZ_decomp(S.unwrap_unique(), A.unwrap_unique(), T.unwrap_unique())
+    })
+}
+
+/// This function computes the QR decomposition of the stacked matrix [U; D], where U is a N-by-N upper triangular
+/// matrix and D is a N-by-N diagonal matrix given by its diagonal. On output U is replaced by R, Y holds the Householder
+/// vectors, and T the N-by-N block reflector. This is the structure of a Tikhonov regularized least squares problem.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_UD_decomp")]
+pub fn QR_UD_decomp(
+    U: &mut ::MatrixF64,
+    D: &::VectorF64,
+    Y: &mut ::MatrixF64,
+    T: &mut ::MatrixF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_UD_decomp(
+            U.unwrap_unique(),
+            D.unwrap_shared(),
+            Y.unwrap_unique(),
+            T.unwrap_unique(),
+        )
+    })
+}
+
+/// This function finds the least squares solution of [U; D] x = b using the decomposition (R, Y, T) computed by
+/// QR_UD_decomp. b has length 2N; x has the same length, its first N elements holding the solution and the last N
+/// elements the residual. work is a workspace of length N.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QR_UD_lssolve")]
+pub fn QR_UD_lssolve(
+    R: &::MatrixF64,
+    Y: &::MatrixF64,
+    T: &::MatrixF64,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+    work: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QR_UD_lssolve(
+            R.unwrap_shared(),
+            Y.unwrap_shared(),
+            T.unwrap_shared(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+            work.unwrap_unique(),
+        )
+    })
+}
+
+/// This function factorizes the M-by-N matrix A into the LQ decomposition A = L Q. On output the diagonal and lower
+/// triangular part of the input matrix contain the matrix L. The vector tau and the elements above the diagonal encode
+/// the orthogonal matrix Q. The vector tau must be of length k = min(M,N).
+#[doc(alias = "gsl_linalg_LQ_decomp")]
+pub fn LQ_decomp(A: &mut ::MatrixF64, tau: &mut ::VectorF64) -> Value {
+    Value::from(unsafe { sys::gsl_linalg_LQ_decomp(A.unwrap_unique(), tau.unwrap_unique()) })
+}
+
+/// This function finds the minimum norm least squares solution to the underdetermined system A x = b, where the M-by-N
+/// matrix A has M <= N, using its LQ decomposition (LQ, tau). The residual is stored in residual.
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_linalg_LQ_lssolve")]
+pub fn LQ_lssolve(
+    LQ: &::MatrixF64,
+    tau: &::VectorF64,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+    residual: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_lssolve(
+            LQ.unwrap_shared(),
+            tau.unwrap_shared(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+            residual.unwrap_unique(),
+        )
+    })
+}
+
+/// This function applies Q^T, encoded in the LQ decomposition (LQ, tau), to the vector v in place.
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_linalg_LQ_QTvec")]
+pub fn LQ_QTvec(LQ: &::MatrixF64, tau: &::VectorF64, v: &mut ::VectorF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_QTvec(LQ.unwrap_shared(), tau.unwrap_shared(), v.unwrap_unique())
+    })
+}
+
+/// This function solves the system A^T x = b using the LQ decomposition (LQ, tau) of A.
+#[doc(alias = "gsl_linalg_LQ_solve_T")]
+pub fn LQ_solve_T(
+    LQ: &::MatrixF64,
+    tau: &::VectorF64,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_solve_T(
+            LQ.unwrap_shared(),
+            tau.unwrap_shared(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+        )
+    })
+}
+
+/// This function solves the system A^T x = b in place using the LQ decomposition (LQ, tau) of A. On input x should
+/// contain the right-hand side b, which is replaced by the solution on output.
+#[doc(alias = "gsl_linalg_LQ_svx_T")]
+pub fn LQ_svx_T(LQ: &::MatrixF64, tau: &::VectorF64, x: &mut ::VectorF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_svx_T(LQ.unwrap_shared(), tau.unwrap_shared(), x.unwrap_unique())
+    })
+}
+
+/// This function finds the least squares solution of the overdetermined system A^T x = b using the LQ decomposition
+/// (LQ, tau) of A. The residual is stored in residual.
+#[doc(alias = "gsl_linalg_LQ_lssolve_T")]
+pub fn LQ_lssolve_T(
+    LQ: &::MatrixF64,
+    tau: &::VectorF64,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+    residual: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_lssolve_T(
+            LQ.unwrap_shared(),
+            tau.unwrap_shared(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+            residual.unwrap_unique(),
+        )
+    })
+}
+
+/// This function solves the triangular system L^T x = b for x, with L stored in LQ.
+#[doc(alias = "gsl_linalg_LQ_Lsolve_T")]
+pub fn LQ_Lsolve_T(LQ: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_Lsolve_T(LQ.unwrap_shared(), b.unwrap_shared(), x.unwrap_unique())
+    })
+}
+
+/// This function solves the triangular system L^T x = b in place, with L stored in LQ.
+#[doc(alias = "gsl_linalg_LQ_Lsvx_T")]
+pub fn LQ_Lsvx_T(LQ: &::MatrixF64, x: &mut ::VectorF64) -> Value {
+    Value::from(unsafe { sys::gsl_linalg_LQ_Lsvx_T(LQ.unwrap_shared(), x.unwrap_unique()) })
+}
+
+/// This function applies Q, encoded in the LQ decomposition (LQ, tau), to the vector v in place.
+#[doc(alias = "gsl_linalg_LQ_vecQ")]
+pub fn LQ_vecQ(LQ: &::MatrixF64, tau: &::VectorF64, v: &mut ::VectorF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_vecQ(LQ.unwrap_shared(), tau.unwrap_shared(), v.unwrap_unique())
+    })
+}
+
+/// This function applies Q^T, encoded in the LQ decomposition (LQ, tau), to the vector v in place.
+#[doc(alias = "gsl_linalg_LQ_vecQT")]
+pub fn LQ_vecQT(LQ: &::MatrixF64, tau: &::VectorF64, v: &mut ::VectorF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_vecQT(LQ.unwrap_shared(), tau.unwrap_shared(), v.unwrap_unique())
+    })
+}
+
+/// This function unpacks the encoded LQ decomposition (LQ, tau) into the matrices L and Q, where L is M-by-N and Q is
+/// N-by-N.
+#[doc(alias = "gsl_linalg_LQ_unpack")]
+pub fn LQ_unpack(
+    LQ: &::MatrixF64,
+    tau: &::VectorF64,
+    Q: &mut ::MatrixF64,
+    L: &mut ::MatrixF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_unpack(
+            LQ.unwrap_shared(),
+            tau.unwrap_shared(),
+            Q.unwrap_unique(),
+            L.unwrap_unique(),
+        )
+    })
+}
+
+/// This function performs a rank-1 update v w^T of the LQ decomposition (Q, L). Note that w is destroyed by the
+/// update.
+#[doc(alias = "gsl_linalg_LQ_update")]
+pub fn LQ_update(
+    Q: &mut ::MatrixF64,
+    L: &mut ::MatrixF64,
+    v: &::VectorF64,
+    w: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_update(
+            Q.unwrap_unique(),
+            L.unwrap_unique(),
+            v.unwrap_shared(),
+            w.unwrap_unique(),
+        )
+    })
+}
+
+/// This function solves the system L Q x = b for x, using the unpacked LQ decomposition (Q, L).
+#[doc(alias = "gsl_linalg_LQ_LQsolve")]
+pub fn LQ_LQsolve(
+    Q: &mut ::MatrixF64,
+    L: &mut ::MatrixF64,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LQ_LQsolve(
+            Q.unwrap_unique(),
+            L.unwrap_unique(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+        )
+    })
+}
+
+/// This function factorizes the M-by-N matrix A into the QL decomposition A = Q L. On output the lower trapezoidal
+/// part of A contains L, and the vector tau and the remaining elements encode the orthogonal matrix Q. The vector tau
+/// must be of length N.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QL_decomp")]
+pub fn QL_decomp(A: &mut ::MatrixF64, tau: &mut ::VectorF64) -> Value {
+    Value::from(unsafe { sys::gsl_linalg_QL_decomp(A.unwrap_unique(), tau.unwrap_unique()) })
+}
+
+/// This function unpacks the encoded QL decomposition (QL, tau) into the M-by-M matrix Q and the M-by-N matrix L.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_QL_unpack")]
+pub fn QL_unpack(
+    QL: &::MatrixF64,
+    tau: &::VectorF64,
+    Q: &mut ::MatrixF64,
+    L: &mut ::MatrixF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_QL_unpack(
+            QL.unwrap_shared(),
+            tau.unwrap_shared(),
+            Q.unwrap_unique(),
+            L.unwrap_unique(),
+        )
+    })
+}
+
+/// This function factors the M-by-N matrix A into the complete orthogonal decomposition A = Q R Z P^T, where R is the
+/// r-by-r upper triangular block of rank r. On output the packed decomposition is stored in A, the Householder
+/// coefficients of Q and Z in tau_Q (of length min(M,N)) and tau_Z (of length min(M,N)), and the column permutation in
+/// p. work is a workspace of length N.
+///
+/// Returns `(Value, rank)`.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_COD_decomp")]
+pub fn COD_decomp(
+    A: &mut ::MatrixF64,
+    tau_Q: &mut ::VectorF64,
+    tau_Z: &mut ::VectorF64,
+    p: &mut ::Permutation,
+    work: &mut ::VectorF64,
+) -> (Value, usize) {
+    let mut rank = 0;
+    let ret = unsafe {
+        sys::gsl_linalg_COD_decomp(
+            A.unwrap_unique(),
+            tau_Q.unwrap_unique(),
+            tau_Z.unwrap_unique(),
+            p.unwrap_unique(),
+            &mut rank,
+            work.unwrap_unique(),
+        )
+    };
+    (Value::from(ret), rank)
+}
+
+/// Like COD_decomp, but the rank is determined by the diagonal elements of R larger than tol in absolute value.
+///
+/// Returns `(Value, rank)`.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_COD_decomp_e")]
+pub fn COD_decomp_e(
+    A: &mut ::MatrixF64,
+    tau_Q: &mut ::VectorF64,
+    tau_Z: &mut ::VectorF64,
+    p: &mut ::Permutation,
+    tol: f64,
+    work: &mut ::VectorF64,
+) -> (Value, usize) {
+    let mut rank = 0;
+    let ret = unsafe {
+        sys::gsl_linalg_COD_decomp_e(
+            A.unwrap_unique(),
+            tau_Q.unwrap_unique(),
+            tau_Z.unwrap_unique(),
+            p.unwrap_unique(),
+            tol,
+            &mut rank,
+            work.unwrap_unique(),
+        )
+    };
+    (Value::from(ret), rank)
+}
+
+/// This function finds the minimum norm least squares solution to the (possibly rank deficient) system A x = b using
+/// the complete orthogonal decomposition (QRZT, tau_Q, tau_Z, perm, rank) computed by COD_decomp. The residual is
+/// stored in residual.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_COD_lssolve")]
+pub fn COD_lssolve(
+    QRZT: &::MatrixF64,
+    tau_Q: &::VectorF64,
+    tau_Z: &::VectorF64,
+    perm: &::Permutation,
+    rank: usize,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+    residual: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_COD_lssolve(
+            QRZT.unwrap_shared(),
+            tau_Q.unwrap_shared(),
+            tau_Z.unwrap_shared(),
+            perm.unwrap_shared(),
+            rank,
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+            residual.unwrap_unique(),
+        )
+    })
+}
+
+/// This function finds the solution of the regularized system min ||b - A x||^2 + lambda^2 ||x||^2 using the complete
+/// orthogonal decomposition computed by COD_decomp. S is a rank-by-rank workspace and work a workspace of length rank.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_COD_lssolve2")]
+pub fn COD_lssolve2(
+    lambda: f64,
+    QRZT: &::MatrixF64,
+    tau_Q: &::VectorF64,
+    tau_Z: &::VectorF64,
+    perm: &::Permutation,
+    rank: usize,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+    residual: &mut ::VectorF64,
+    S: &mut ::MatrixF64,
+    work: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_COD_lssolve2(
+            lambda,
+            QRZT.unwrap_shared(),
+            tau_Q.unwrap_shared(),
+            tau_Z.unwrap_shared(),
+            perm.unwrap_shared(),
+            rank,
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+            residual.unwrap_unique(),
+            S.unwrap_unique(),
+            work.unwrap_unique(),
+        )
+    })
+}
+
+/// This function unpacks the complete orthogonal decomposition into the M-by-M matrix Q, the M-by-N matrix R and the
+/// N-by-N matrix Z.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_COD_unpack")]
+pub fn COD_unpack(
+    QRZT: &::MatrixF64,
+    tau_Q: &::VectorF64,
+    tau_Z: &::VectorF64,
+    rank: usize,
+    Q: &mut ::MatrixF64,
+    R: &mut ::MatrixF64,
+    Z: &mut ::MatrixF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_COD_unpack(
+            QRZT.unwrap_shared(),
+            tau_Q.unwrap_shared(),
+            tau_Z.unwrap_shared(),
+            rank,
+            Q.unwrap_unique(),
+            R.unwrap_unique(),
+            Z.unwrap_unique(),
+        )
+    })
+}
+
+/// This function multiplies the input matrix A on the right by Z, A' = A Z, using the encoded complete orthogonal
+/// decomposition. work is a workspace of length equal to the number of rows of A.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_COD_matZ")]
+pub fn COD_matZ(
+    QRZT: &::MatrixF64,
+    tau_Z: &::VectorF64,
+    rank: usize,
+    A: &mut ::MatrixF64,
+    work: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_COD_matZ(
+            QRZT.unwrap_shared(),
+            tau_Z.unwrap_shared(),
+            rank,
+            A.unwrap_unique(),
+            work.unwrap_unique(),
+        )
+    })
+}
+
+/// This function computes the LU factorization of the M-by-N banded matrix A, with lower bandwidth lb and upper
+/// bandwidth ub, stored in packed form in the N-by-(2 lb + ub + 1) matrix AB. On output AB contains the factors and
+/// piv, of length min(M,N), the pivot indices.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_LU_band_decomp")]
+pub fn LU_band_decomp(
+    M: usize,
+    lb: usize,
+    ub: usize,
+    AB: &mut ::MatrixF64,
+    piv: &mut ::VectorU32,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LU_band_decomp(M, lb, ub, AB.unwrap_unique(), piv.unwrap_unique())
+    })
+}
+
+/// This function solves the square system A x = b using the banded LU factorization (LUB, piv) computed by
+/// LU_band_decomp.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_LU_band_solve")]
+pub fn LU_band_solve(
+    lb: usize,
+    ub: usize,
+    LUB: &::MatrixF64,
+    piv: &::VectorU32,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LU_band_solve(
+            lb,
+            ub,
+            LUB.unwrap_shared(),
+            piv.unwrap_shared(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+        )
+    })
+}
+
+/// This function solves the square system A x = b in place using the banded LU factorization (LUB, piv) computed by
+/// LU_band_decomp. On input x should contain the right-hand side b, which is replaced by the solution on output.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_LU_band_svx")]
+pub fn LU_band_svx(
+    lb: usize,
+    ub: usize,
+    LUB: &::MatrixF64,
+    piv: &::VectorU32,
+    x: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LU_band_svx(
+            lb,
+            ub,
+            LUB.unwrap_shared(),
+            piv.unwrap_shared(),
+            x.unwrap_unique(),
+        )
+    })
+}
+
+/// This function unpacks the banded LU factorization (LUB, piv) computed by LU_band_decomp into the M-by-min(M,N) unit
+/// lower triangular matrix L and the min(M,N)-by-N upper triangular matrix U.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_LU_band_unpack")]
+pub fn LU_band_unpack(
+    M: usize,
+    lb: usize,
+    ub: usize,
+    LUB: &::MatrixF64,
+    piv: &::VectorU32,
+    L: &mut ::MatrixF64,
+    U: &mut ::MatrixF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_LU_band_unpack(
+            M,
+            lb,
+            ub,
+            LUB.unwrap_shared(),
+            piv.unwrap_shared(),
+            L.unwrap_unique(),
+            U.unwrap_unique(),
+        )
+    })
+}
+
+/// This function estimates the reciprocal condition number (using the 1-norm) of the triangular matrix A, stored in
+/// its upper or lower triangle according to Uplo. work is a workspace of length 3N.
+///
+/// Returns `(Value, rcond)`.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_tri_rcond")]
+pub fn tri_rcond(Uplo: enums::CblasUplo, A: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
+    let mut rcond = 0.;
+    let ret = unsafe {
+        sys::gsl_linalg_tri_rcond(
+            Uplo.into(),
+            A.unwrap_shared(),
+            &mut rcond,
+            work.unwrap_unique(),
+        )
+    };
+    (Value::from(ret), rcond)
+}
+
+/// Returns `(Value, rcond)`.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_tri_upper_rcond")]
+pub fn tri_upper_rcond(A: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
+    let mut rcond = 0.;
+    let ret = unsafe {
+        sys::gsl_linalg_tri_upper_rcond(A.unwrap_shared(), &mut rcond, work.unwrap_unique())
+    };
+    (Value::from(ret), rcond)
+}
+
+/// Returns `(Value, rcond)`.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_tri_lower_rcond")]
+pub fn tri_lower_rcond(A: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
+    let mut rcond = 0.;
+    let ret = unsafe {
+        sys::gsl_linalg_tri_lower_rcond(A.unwrap_shared(), &mut rcond, work.unwrap_unique())
+    };
+    (Value::from(ret), rcond)
+}
+
+/// This function factorizes the symmetric, positive-definite square matrix A into the Cholesky decomposition A = L L^T
+/// using a recursive Level 3 BLAS algorithm. Unlike cholesky_decomp, the upper triangle of A is left untouched.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_cholesky_decomp1")]
+pub fn cholesky_decomp1(A: &mut ::MatrixF64) -> Value {
+    Value::from(unsafe { sys::gsl_linalg_cholesky_decomp1(A.unwrap_unique()) })
+}
+
+/// This function computes the Cholesky decomposition of the scaled matrix S A S, where the diagonal
+/// scaling matrix S, stored in S, is chosen to reduce the condition number of A.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_cholesky_decomp2")]
+pub fn cholesky_decomp2(A: &mut ::MatrixF64, S: &mut ::VectorF64) -> Value {
+    Value::from(unsafe { sys::gsl_linalg_cholesky_decomp2(A.unwrap_unique(), S.unwrap_unique()) })
+}
+
+/// This function solves the system A x = b using the Cholesky decomposition of S A S computed by cholesky_decomp2.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_cholesky_solve2")]
+pub fn cholesky_solve2(
+    LLT: &::MatrixF64,
+    S: &::VectorF64,
+    b: &::VectorF64,
+    x: &mut ::VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_cholesky_solve2(
+            LLT.unwrap_shared(),
+            S.unwrap_shared(),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+        )
+    })
+}
+
+/// This function solves the system A x = b in place using the Cholesky decomposition of S A S computed by
+/// cholesky_decomp2. On input x should contain the right-hand side b, which is replaced by the solution on output.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_cholesky_svx2")]
+pub fn cholesky_svx2(LLT: &::MatrixF64, S: &::VectorF64, x: &mut ::VectorF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_cholesky_svx2(LLT.unwrap_shared(), S.unwrap_shared(), x.unwrap_unique())
+    })
+}
+
+/// This function computes the diagonal scaling S_i = 1 / sqrt(A_ii) of the symmetric, positive-definite matrix A,
+/// which reduces its condition number.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_cholesky_scale")]
+pub fn cholesky_scale(A: &::MatrixF64, S: &mut ::VectorF64) -> Value {
+    Value::from(unsafe { sys::gsl_linalg_cholesky_scale(A.unwrap_shared(), S.unwrap_unique()) })
+}
+
+/// This function applies the scaling computed by cholesky_scale to A, replacing it with S A S.
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[doc(alias = "gsl_linalg_cholesky_scale_apply")]
+pub fn cholesky_scale_apply(A: &mut ::MatrixF64, S: &::VectorF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_cholesky_scale_apply(A.unwrap_unique(), S.unwrap_shared())
+    })
+}
+
+/// This function solves the systems A X = B, for all the columns of B at once, using the Cholesky decomposition of A.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_cholesky_solve_mat")]
+pub fn cholesky_solve_mat(cholesky: &::MatrixF64, B: &::MatrixF64, X: &mut ::MatrixF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_cholesky_solve_mat(
+            cholesky.unwrap_shared(),
+            B.unwrap_shared(),
+            X.unwrap_unique(),
+        )
+    })
+}
+
+/// This function solves the systems A X = B in place using the Cholesky decomposition of A. On input X should contain
+/// the right-hand sides B, which are replaced by the solutions on output.
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+#[doc(alias = "gsl_linalg_cholesky_svx_mat")]
+pub fn cholesky_svx_mat(cholesky: &::MatrixF64, X: &mut ::MatrixF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_linalg_cholesky_svx_mat(cholesky.unwrap_shared(), X.unwrap_unique())
+    })
+}
+
+#[cfg(test)]
+fn matrix_from(n1: usize, n2: usize, data: &[f64]) -> ::MatrixF64 {
+    let mut m = ::MatrixF64::new(n1, n2).unwrap();
+    for (i, x) in data.iter().enumerate() {
+        m.set(i / n2, i % n2, *x);
+    }
+    m
+}
+
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[test]
+fn cod() {
+    // Rank 2, with null space (1, 1, -1): the minimum norm solution of A x = A (1, 1, 1) is (2/3, 2/3, 4/3).
+    let mut a = matrix_from(3, 3, &[1., 2., 3., 2., 4., 6., 1., 0., 1.]);
+    let b = ::VectorF64::from_slice(&[6., 12., 2.]).unwrap();
+    let mut tau_q = ::VectorF64::new(3).unwrap();
+    let mut tau_z = ::VectorF64::new(3).unwrap();
+    let mut p = ::Permutation::new(3).unwrap();
+    let mut work = ::VectorF64::new(3).unwrap();
+    let (ret, rank) = COD_decomp(&mut a, &mut tau_q, &mut tau_z, &mut p, &mut work);
+    assert_eq!(ret, Value::Success);
+    assert_eq!(rank, 2);
+
+    let mut x = ::VectorF64::new(3).unwrap();
+    let mut residual = ::VectorF64::new(3).unwrap();
+    assert_eq!(
+        COD_lssolve(&a, &tau_q, &tau_z, &p, rank, &b, &mut x, &mut residual),
+        Value::Success
+    );
+    assert_eq!(
+        format!("{:.4} {:.4} {:.4}", x.get(0), x.get(1), x.get(2)),
+        "0.6667 0.6667 1.3333"
+    );
+    assert!((0..3).all(|i| residual.get(i).abs() < 1e-10));
+}
+
+#[test]
+fn lq() {
+    let a = matrix_from(2, 3, &[1., 0., 1., 0., 1., 1.]);
+    let mut lq = matrix_from(2, 3, &[1., 0., 1., 0., 1., 1.]);
+    let mut tau = ::VectorF64::new(2).unwrap();
+    assert_eq!(LQ_decomp(&mut lq, &mut tau), Value::Success);
+
+    let mut q = ::MatrixF64::new(3, 3).unwrap();
+    let mut l = ::MatrixF64::new(2, 3).unwrap();
+    assert_eq!(LQ_unpack(&lq, &tau, &mut q, &mut l), Value::Success);
+    for i in 0..2 {
+        assert_eq!(l.get(0, i + 1), 0.);
+        for j in 0..3 {
+            let lq_ij = (0..3).map(|k| l.get(i, k) * q.get(k, j)).sum::<f64>();
+            assert!((lq_ij - a.get(i, j)).abs() < 1e-10);
+        }
+    }
+
+    // The minimum norm solution of the underdetermined system is A^T (A A^T)^-1 b.
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
+    {
+        let b = ::VectorF64::from_slice(&[2., 2.]).unwrap();
+        let mut x = ::VectorF64::new(3).unwrap();
+        let mut residual = ::VectorF64::new(2).unwrap();
+        assert_eq!(
+            LQ_lssolve(&lq, &tau, &b, &mut x, &mut residual),
+            Value::Success
+        );
+        assert_eq!(
+            format!("{:.4} {:.4} {:.4}", x.get(0), x.get(1), x.get(2)),
+            "0.6667 0.6667 1.3333"
+        );
+    }
+}
+
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[test]
+fn lu_band() {
+    // The tridiagonal matrix with 2 on the diagonal and -1 next to it. A(i,j) is stored in AB(j, lb + ub + i - j), the
+    // first lb columns being the workspace of the fill-in.
+    let (n, lb, ub) = (4, 1, 1);
+    let mut ab = ::MatrixF64::new(n, 2 * lb + ub + 1).unwrap();
+    for j in 0..n {
+        for i in j.saturating_sub(ub)..(j + lb + 1).min(n) {
+            ab.set(j, lb + ub + i - j, if i == j { 2. } else { -1. });
+        }
+    }
+    let mut piv = ::VectorU32::new(n).unwrap();
+    assert_eq!(LU_band_decomp(n, lb, ub, &mut ab, &mut piv), Value::Success);
+
+    // A (1, 2, 3, 4) = (0, 0, 0, 5)
+    let b = ::VectorF64::from_slice(&[0., 0., 0., 5.]).unwrap();
+    let mut x = ::VectorF64::new(n).unwrap();
+    assert_eq!(LU_band_solve(lb, ub, &ab, &piv, &b, &mut x), Value::Success);
+    assert!((0..n).all(|i| (x.get(i) - (i + 1) as f64).abs() < 1e-10));
+}
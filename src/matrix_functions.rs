@@ -0,0 +1,330 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Matrix functions
+
+Functions of real square matrices, built on top of the BLAS, LU and eigenvalue routines:
+
+* [`expm`]: the exponential, computed with a degree 13 Padé approximant and scaling and squaring (Higham, 2005).
+* [`sqrtm`]: the principal square root, computed with the Denman–Beavers iteration.
+* [`logm`]: the principal logarithm, computed with inverse scaling and squaring and a Gauss–Legendre quadrature of
+  the integral representation of log(I + X) (which is a Padé approximant).
+* [`powm`]: real powers, by repeated squaring for integer exponents and as exp(p log(A)) otherwise.
+* [`funm_symmetric`]: any function of a symmetric matrix, applied to its eigenvalues.
+
+```
+use rgsl::{matrix_functions, MatrixF64};
+
+// exp([[0, 1], [0, 0]]) = [[1, 1], [0, 1]]
+let mut a = MatrixF64::new(2, 2).unwrap();
+a.set(0, 1, 1.);
+let e = matrix_functions::expm(&a).unwrap();
+assert!((e.get(0, 1) - 1.).abs() < 1e-14);
+assert!((e.get(1, 1) - 1.).abs() < 1e-14);
+```
+
+## References
+
+N. J. Higham, “The Scaling and Squaring Method for the Matrix Exponential Revisited”, SIAM J. Matrix Anal. Appl.,
+Vol 26, No 4, 2005.
+
+N. J. Higham, Functions of Matrices: Theory and Computation, SIAM, 2008.
+!*/
+
+use crate::Value;
+use blas::level3::dgemm;
+use eigen;
+use enums::CblasTranspose;
+use types::decomposition::{check_square, identity, matrix};
+use types::Lu;
+use {EigenSort, GLFixedTable, MatrixF64};
+
+/// Coefficients of the degree 13 Padé approximant of the exponential.
+const PADE13: [f64; 14] = [
+    64764752532480000.,
+    32382376266240000.,
+    7771770303897600.,
+    1187353796428800.,
+    129060195264000.,
+    10559470521600.,
+    670442572800.,
+    33522128640.,
+    1323241920.,
+    40840800.,
+    960960.,
+    16380.,
+    182.,
+    1.,
+];
+
+/// Largest 1-norm for which the degree 13 Padé approximant is accurate to double precision.
+const THETA13: f64 = 5.371920351148152;
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Like `check_square`, rejecting empty matrices as well.
+fn check_order(a: &MatrixF64) -> Result<usize, Value> {
+    match check_square(a)? {
+        0 => Err(Value::BadLength),
+        n => Ok(n),
+    }
+}
+
+fn mul(a: &MatrixF64, b: &MatrixF64) -> Result<MatrixF64, Value> {
+    let mut c = MatrixF64::new(a.size1(), b.size2()).ok_or(Value::NoMemory)?;
+    dgemm(
+        CblasTranspose::NoTranspose,
+        CblasTranspose::NoTranspose,
+        1.,
+        a,
+        b,
+        0.,
+        &mut c,
+    )
+    .to_result()?;
+    Ok(c)
+}
+
+/// Returns the sum of the `terms`, each one scaled by its coefficient.
+fn combine(n: usize, terms: &[(f64, &MatrixF64)]) -> Result<MatrixF64, Value> {
+    let mut c = matrix(n, n)?;
+    for i in 0..n {
+        for j in 0..n {
+            c.set(i, j, terms.iter().map(|&(x, m)| x * m.get(i, j)).sum());
+        }
+    }
+    Ok(c)
+}
+
+/// Returns the 1-norm (maximum absolute column sum) of `a`.
+fn norm1(a: &MatrixF64) -> f64 {
+    (0..a.size2())
+        .map(|j| (0..a.size1()).map(|i| a.get(i, j).abs()).sum::<f64>())
+        .fold(0., f64::max)
+}
+
+fn distance1(a: &MatrixF64, b: &MatrixF64) -> f64 {
+    (0..a.size2())
+        .map(|j| {
+            (0..a.size1())
+                .map(|i| (a.get(i, j) - b.get(i, j)).abs())
+                .sum::<f64>()
+        })
+        .fold(0., f64::max)
+}
+
+fn inverse(a: &MatrixF64) -> Result<MatrixF64, Value> {
+    Lu::new(a)?.inverse()
+}
+
+/// Returns the exponential of the square matrix `a`, or `Value::Domain` if one of its entries is infinite or NaN.
+pub fn expm(a: &MatrixF64) -> Result<MatrixF64, Value> {
+    let n = check_order(a)?;
+    // `norm1` ignores NaN entries and an infinite norm would request 2^31 squarings.
+    if (0..n).any(|i| (0..n).any(|j| !a.get(i, j).is_finite())) {
+        return Err(Value::Domain);
+    }
+    let norm = norm1(a);
+    let s = if norm > THETA13 {
+        (norm / THETA13).log2().ceil() as i32
+    } else {
+        0
+    };
+    let b = &PADE13;
+    let mut a = combine(n, &[(0.5f64.powi(s), a)])?;
+    let i = identity(n)?;
+    let a2 = mul(&a, &a)?;
+    let a4 = mul(&a2, &a2)?;
+    let a6 = mul(&a4, &a2)?;
+
+    let u = mul(
+        &a6,
+        &combine(n, &[(b[13], &a6), (b[11], &a4), (b[9], &a2)])?,
+    )?;
+    let u = combine(
+        n,
+        &[(1., &u), (b[7], &a6), (b[5], &a4), (b[3], &a2), (b[1], &i)],
+    )?;
+    let u = mul(&a, &u)?;
+    let v = mul(
+        &a6,
+        &combine(n, &[(b[12], &a6), (b[10], &a4), (b[8], &a2)])?,
+    )?;
+    let v = combine(
+        n,
+        &[(1., &v), (b[6], &a6), (b[4], &a4), (b[2], &a2), (b[0], &i)],
+    )?;
+
+    // exp(A) ~ (V - U)^-1 (V + U)
+    let p = combine(n, &[(1., &v), (-1., &u)])?;
+    let q = combine(n, &[(1., &v), (1., &u)])?;
+    a = Lu::new(&p)?.solve_matrix(&q)?;
+    for _ in 0..s {
+        a = mul(&a, &a)?;
+    }
+    Ok(a)
+}
+
+/// Returns the principal square root of the square matrix `a`, the one whose eigenvalues have a positive real part.
+///
+/// Returns an error if `a` is singular or if the iteration doesn't converge, which happens when `a` has negative real
+/// eigenvalues: the square root isn't real then.
+pub fn sqrtm(a: &MatrixF64) -> Result<MatrixF64, Value> {
+    let n = check_order(a)?;
+    let mut y = combine(n, &[(1., a)])?;
+    let mut z = identity(n)?;
+    let mut converged = false;
+    for _ in 0..MAX_ITERATIONS {
+        let next = combine(n, &[(0.5, &y), (0.5, &inverse(&z)?)])?;
+        z = combine(n, &[(0.5, &z), (0.5, &inverse(&y)?)])?;
+        let change = distance1(&next, &y);
+        y = next;
+        if converged {
+            return Ok(y);
+        }
+        // The convergence is quadratic, so one more step brings the error down to the rounding level.
+        converged = change <= f64::EPSILON.sqrt() * norm1(&y);
+    }
+    Err(Value::MaxIteration)
+}
+
+/// Returns the principal logarithm of the square matrix `a`, the one whose eigenvalues have an imaginary part in
+/// (-pi, pi).
+///
+/// Returns an error if `a` is singular or has negative real eigenvalues: the logarithm isn't real then.
+pub fn logm(a: &MatrixF64) -> Result<MatrixF64, Value> {
+    let n = check_order(a)?;
+    let i = identity(n)?;
+    let mut x = combine(n, &[(1., a)])?;
+    let mut k = 0;
+    while distance1(&x, &i) > 0.25 {
+        if k == 64 {
+            return Err(Value::MaxIteration);
+        }
+        x = sqrtm(&x)?;
+        k += 1;
+    }
+
+    // log(I + X) = integral over [0, 1] of X (I + t X)^-1 dt
+    let x = combine(n, &[(1., &x), (-1., &i)])?;
+    let table = GLFixedTable::new(8).ok_or(Value::NoMemory)?;
+    let mut log = matrix(n, n)?;
+    for j in 0..8 {
+        let (ret, t, w) = table.point(0., 1., j);
+        ret.to_result()?;
+        let d = combine(n, &[(1., &i), (t, &x)])?;
+        let term = Lu::new(&d)?.solve_matrix(&x)?;
+        log = combine(n, &[(1., &log), (w, &term)])?;
+    }
+    combine(n, &[(2f64.powi(k), &log)])
+}
+
+/// Returns `a` raised to the power `p`. Integer powers are computed by repeated squaring (of the inverse of `a` for
+/// negative ones), other ones as exp(p log(a)).
+pub fn powm(a: &MatrixF64, p: f64) -> Result<MatrixF64, Value> {
+    let n = check_order(a)?;
+    if p.fract() != 0. || p.abs() > i32::MAX as f64 {
+        let log = logm(a)?;
+        return expm(&combine(n, &[(p, &log)])?);
+    }
+    let mut base = if p < 0. {
+        inverse(a)?
+    } else {
+        combine(n, &[(1., a)])?
+    };
+    let mut e = p.abs() as u32;
+    let mut out = identity(n)?;
+    while e > 0 {
+        if e & 1 == 1 {
+            out = mul(&out, &base)?;
+        }
+        e >>= 1;
+        if e > 0 {
+            base = mul(&base, &base)?;
+        }
+    }
+    Ok(out)
+}
+
+/// Returns f(a) for the real symmetric matrix `a`, computed as V f(D) V^T from its eigen decomposition A = V D V^T.
+/// Only the diagonal and lower triangular part of `a` are referenced.
+pub fn funm_symmetric<F: Fn(f64) -> f64>(a: &MatrixF64, f: F) -> Result<MatrixF64, Value> {
+    let n = check_order(a)?;
+    let e = eigen::symmetric(a, EigenSort::ValAsc)?;
+    let mut scaled = combine(n, &[(1., &e.vectors)])?;
+    for j in 0..n {
+        let fj = f(e.values.get(j));
+        for i in 0..n {
+            scaled.set(i, j, scaled.get(i, j) * fj);
+        }
+    }
+    let mut c = matrix(n, n)?;
+    dgemm(
+        CblasTranspose::NoTranspose,
+        CblasTranspose::Transpose,
+        1.,
+        &scaled,
+        &e.vectors,
+        0.,
+        &mut c,
+    )
+    .to_result()?;
+    Ok(c)
+}
+
+#[test]
+fn matrix_functions() {
+    fn from(rows: &[[f64; 3]]) -> MatrixF64 {
+        let mut m = MatrixF64::new(3, 3).unwrap();
+        for (i, row) in rows.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                m.set(i, j, x);
+            }
+        }
+        m
+    }
+    fn close(a: &MatrixF64, b: &MatrixF64, tol: f64) -> bool {
+        distance1(a, b) <= tol * norm1(b).max(1.)
+    }
+
+    let d = from(&[[1., 0., 0.], [0., 2., 0.], [0., 0., -3.]]);
+    let e = expm(&d).unwrap();
+    for (i, &x) in [1f64, 2., -3.].iter().enumerate() {
+        assert!((e.get(i, i) - x.exp()).abs() < 1e-13 * x.exp());
+    }
+
+    // Large norm, to go through the scaling and squaring.
+    let r = from(&[[0., -20., 0.], [20., 0., 0.], [0., 0., 0.]]);
+    let e = expm(&r).unwrap();
+    assert!((e.get(0, 0) - 20f64.cos()).abs() < 1e-12);
+    assert!((e.get(1, 0) - 20f64.sin()).abs() < 1e-12);
+
+    let a = from(&[[4., 1., 0.], [1., 3., 1.], [0., 1., 2.]]);
+    let s = sqrtm(&a).unwrap();
+    assert!(close(&mul(&s, &s).unwrap(), &a, 1e-13));
+    assert!(close(&s, &funm_symmetric(&a, f64::sqrt).unwrap(), 1e-12));
+    assert!(close(&s, &powm(&a, 0.5).unwrap(), 1e-12));
+
+    let l = logm(&a).unwrap();
+    assert!(close(&expm(&l).unwrap(), &a, 1e-12));
+    assert!(close(&l, &funm_symmetric(&a, f64::ln).unwrap(), 1e-12));
+
+    let cube = mul(&mul(&a, &a).unwrap(), &a).unwrap();
+    assert!(close(&powm(&a, 3.).unwrap(), &cube, 1e-14));
+    assert!(close(
+        &mul(&powm(&a, -3.).unwrap(), &cube).unwrap(),
+        &identity(3).unwrap(),
+        1e-12
+    ));
+    assert!(close(&powm(&a, 0.).unwrap(), &identity(3).unwrap(), 0.));
+
+    assert!(expm(&MatrixF64::new(2, 3).unwrap()).is_err());
+    let mut inf = identity(2).unwrap();
+    inf.set(0, 1, f64::INFINITY);
+    assert_eq!(expm(&inf).err(), Some(Value::Domain));
+    inf.set(0, 1, f64::NAN);
+    assert_eq!(expm(&inf).err(), Some(Value::Domain));
+    assert!(sqrtm(&from(&[[-2., 0., 0.], [0., 1., 0.], [0., 0., 1.]])).is_err());
+}
@@ -58,6 +58,23 @@ pub mod diagamma {
 
         (::Value::from(ret), unsafe { result.assume_init() }.into())
     }
+
+    /// This routine computes the digamma function \psi(z) for the complex argument z = x + i y, z \ne 0, -1, -2, ....
+    /// The real and imaginary parts of the result are returned in result_re, result_im.
+    #[doc(alias = "gsl_sf_complex_psi_e")]
+    pub fn complex_psi_e(x: f64, y: f64) -> (Value, ::types::Result, ::types::Result) {
+        let mut result_re = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let mut result_im = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe {
+            sys::gsl_sf_complex_psi_e(x, y, result_re.as_mut_ptr(), result_im.as_mut_ptr())
+        };
+
+        (
+            Value::from(ret),
+            unsafe { result_re.assume_init() }.into(),
+            unsafe { result_im.assume_init() }.into(),
+        )
+    }
 }
 
 pub mod trigamma {
@@ -0,0 +1,679 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+Parameterised distributions built on top of the `gsl_ran_*` and `gsl_cdf_*` routines.
+
+Each distribution is a small `Copy` struct whose parameters are checked when it is built, so that the density, cumulative
+distribution and sampling routines below are never called with arguments that would trigger the GSL error handler. The
+continuous distributions implement [`ContinuousDistribution`](trait.ContinuousDistribution.html) and the discrete ones
+[`DiscreteDistribution`](trait.DiscreteDistribution.html), which makes it possible to write code that is generic over the
+distribution being used:
+
+```
+use rgsl::randist::distributions::{ContinuousDistribution, Gaussian};
+
+let d = Gaussian::new(2.).unwrap();
+assert!((d.quantile(d.cdf(1.5)) - 1.5).abs() < 1e-10);
+assert!(Gaussian::new(-1.).is_err());
+```
+
+The parameterisations are the ones used by GSL, so `Gaussian` for instance has zero mean.
+!*/
+
+use crate::Value;
+
+/// A continuous probability distribution over the real line.
+pub trait ContinuousDistribution {
+    /// This function computes the probability density p(x) at x.
+    fn pdf(&self, x: f64) -> f64;
+
+    /// This function computes the logarithm of the probability density p(x) at x.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+
+    /// This function computes the cumulative distribution function P(x), the probability of a variate being lower than x.
+    fn cdf(&self, x: f64) -> f64;
+
+    /// This function computes the survival function Q(x) = 1 - P(x), the probability of a variate being greater than x.
+    /// It keeps full accuracy in the upper tail.
+    fn sf(&self, x: f64) -> f64;
+
+    /// This function computes the inverse cumulative distribution function P^{-1}(p). It returns `NaN` if p is not in
+    /// [0, 1].
+    fn quantile(&self, p: f64) -> f64;
+
+    /// Returns the mean of the distribution, `NaN` if it is undefined and infinity if it diverges.
+    fn mean(&self) -> f64;
+
+    /// Returns the variance of the distribution, `NaN` if it is undefined and infinity if it diverges.
+    fn variance(&self) -> f64;
+
+    /// This function returns a random variate drawn from the distribution using `rng`.
+    fn sample(&self, rng: &mut ::Rng) -> f64;
+
+    /// This function returns `n` random variates drawn from the distribution using `rng`.
+    fn sample_n(&self, rng: &mut ::Rng, n: usize) -> Vec<f64> {
+        (0..n).map(|_| self.sample(rng)).collect()
+    }
+}
+
+/// A discrete probability distribution over the non-negative integers.
+pub trait DiscreteDistribution {
+    /// This function computes the probability p(k) of obtaining k.
+    fn pdf(&self, k: u32) -> f64;
+
+    /// This function computes the logarithm of the probability p(k) of obtaining k.
+    fn ln_pdf(&self, k: u32) -> f64 {
+        self.pdf(k).ln()
+    }
+
+    /// This function computes the cumulative distribution function P(k), the probability of a variate being lower than or
+    /// equal to k.
+    fn cdf(&self, k: u32) -> f64;
+
+    /// This function computes the survival function Q(k) = 1 - P(k), the probability of a variate being greater than k.
+    fn sf(&self, k: u32) -> f64;
+
+    /// This function returns the smallest k such that P(k) >= p, or `None` if p is not in [0, 1].
+    ///
+    /// GSL doesn't provide inverses for the discrete distributions, so this is found by bisection on
+    /// [`cdf`](#tymethod.cdf).
+    fn quantile(&self, p: f64) -> Option<u32> {
+        if !(0. ..=1.).contains(&p) {
+            return None;
+        }
+        if self.cdf(0) >= p {
+            return Some(0);
+        }
+        // Invariant: cdf(lo) < p <= cdf(hi).
+        let mut lo = 0;
+        let mut hi = 1;
+        while self.cdf(hi) < p {
+            if hi == u32::MAX {
+                return Some(hi);
+            }
+            lo = hi;
+            hi = hi.saturating_mul(2);
+        }
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if self.cdf(mid) < p {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Some(hi)
+    }
+
+    /// Returns the mean of the distribution.
+    fn mean(&self) -> f64;
+
+    /// Returns the variance of the distribution.
+    fn variance(&self) -> f64;
+
+    /// This function returns a random variate drawn from the distribution using `rng`.
+    fn sample(&self, rng: &mut ::Rng) -> u32;
+
+    /// This function returns `n` random variates drawn from the distribution using `rng`.
+    fn sample_n(&self, rng: &mut ::Rng, n: usize) -> Vec<u32> {
+        (0..n).map(|_| self.sample(rng)).collect()
+    }
+}
+
+// The Euler-Mascheroni constant, M_EULER in GSL.
+const EULER: f64 = 0.577_215_664_901_532_9;
+
+fn positive(x: f64) -> bool {
+    x > 0. && x.is_finite()
+}
+
+fn probability(p: f64) -> bool {
+    (0. ..=1.).contains(&p)
+}
+
+fn gamma(x: f64) -> f64 {
+    ::gamma_beta::gamma::gamma(x)
+}
+
+macro_rules! distribution {
+    (
+        $(#[$attr:meta])*
+        $name:ident { $($param:ident: $ty:ty),+ },
+        valid: $valid:expr,
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $name {
+            $($param: $ty,)+
+        }
+
+        impl $name {
+            #[doc = concat!("Creates a new `", stringify!($name), "` distribution, returning `Value::Domain` if the parameters are invalid.")]
+            pub fn new($($param: $ty),+) -> Result<$name, Value> {
+                if $valid {
+                    Ok($name { $($param),+ })
+                } else {
+                    Err(Value::Domain)
+                }
+            }
+
+            $(
+                #[doc = concat!("Returns the `", stringify!($param), "` parameter.")]
+                pub fn $param(&self) -> $ty {
+                    self.$param
+                }
+            )+
+        }
+    };
+}
+
+macro_rules! continuous {
+    (
+        $(#[$attr:meta])*
+        $name:ident { $($param:ident),+ },
+        valid: $valid:expr,
+        pdf: $pdf:ident,
+        cdf: $P:ident,
+        sf: $Q:ident,
+        quantile: $Pinv:ident,
+        sample: $sample:ident,
+        mean: $mean:expr,
+        variance: $variance:expr,
+    ) => {
+        distribution! {
+            $(#[$attr])*
+            $name { $($param: f64),+ },
+            valid: $valid,
+        }
+
+        impl ContinuousDistribution for $name {
+            fn pdf(&self, x: f64) -> f64 {
+                unsafe { sys::$pdf(x, $(self.$param),+) }
+            }
+
+            fn cdf(&self, x: f64) -> f64 {
+                unsafe { sys::$P(x, $(self.$param),+) }
+            }
+
+            fn sf(&self, x: f64) -> f64 {
+                unsafe { sys::$Q(x, $(self.$param),+) }
+            }
+
+            fn quantile(&self, p: f64) -> f64 {
+                if !probability(p) {
+                    return f64::NAN;
+                }
+                unsafe { sys::$Pinv(p, $(self.$param),+) }
+            }
+
+            fn mean(&self) -> f64 {
+                #[allow(unused_variables)]
+                let $name { $($param),+ } = *self;
+                $mean
+            }
+
+            fn variance(&self) -> f64 {
+                #[allow(unused_variables)]
+                let $name { $($param),+ } = *self;
+                $variance
+            }
+
+            fn sample(&self, rng: &mut ::Rng) -> f64 {
+                rng.$sample($(self.$param),+)
+            }
+        }
+    };
+}
+
+macro_rules! discrete {
+    (
+        $(#[$attr:meta])*
+        $name:ident { $($param:ident: $ty:ty),+ },
+        valid: $valid:expr,
+        pdf: $pdf:ident,
+        cdf: $P:ident,
+        sf: $Q:ident,
+        sample: $sample:ident,
+        mean: $mean:expr,
+        variance: $variance:expr,
+    ) => {
+        distribution! {
+            $(#[$attr])*
+            $name { $($param: $ty),+ },
+            valid: $valid,
+        }
+
+        impl DiscreteDistribution for $name {
+            fn pdf(&self, k: u32) -> f64 {
+                unsafe { sys::$pdf(k, $(self.$param),+) }
+            }
+
+            fn cdf(&self, k: u32) -> f64 {
+                unsafe { sys::$P(k, $(self.$param),+) }
+            }
+
+            fn sf(&self, k: u32) -> f64 {
+                unsafe { sys::$Q(k, $(self.$param),+) }
+            }
+
+            fn mean(&self) -> f64 {
+                let $name { $($param),+ } = *self;
+                $mean
+            }
+
+            fn variance(&self) -> f64 {
+                let $name { $($param),+ } = *self;
+                $variance
+            }
+
+            fn sample(&self, rng: &mut ::Rng) -> u32 {
+                rng.$sample($(self.$param),+)
+            }
+        }
+    };
+}
+
+continuous! {
+    /// The zero-mean Gaussian distribution with standard deviation sigma.
+    Gaussian { sigma },
+    valid: positive(sigma),
+    pdf: gsl_ran_gaussian_pdf,
+    cdf: gsl_cdf_gaussian_P,
+    sf: gsl_cdf_gaussian_Q,
+    quantile: gsl_cdf_gaussian_Pinv,
+    sample: gaussian,
+    mean: 0.,
+    variance: sigma * sigma,
+}
+
+continuous! {
+    /// The exponential distribution with mean mu.
+    Exponential { mu },
+    valid: positive(mu),
+    pdf: gsl_ran_exponential_pdf,
+    cdf: gsl_cdf_exponential_P,
+    sf: gsl_cdf_exponential_Q,
+    quantile: gsl_cdf_exponential_Pinv,
+    sample: exponential,
+    mean: mu,
+    variance: mu * mu,
+}
+
+continuous! {
+    /// The Laplace distribution with width a.
+    Laplace { a },
+    valid: positive(a),
+    pdf: gsl_ran_laplace_pdf,
+    cdf: gsl_cdf_laplace_P,
+    sf: gsl_cdf_laplace_Q,
+    quantile: gsl_cdf_laplace_Pinv,
+    sample: laplace,
+    mean: 0.,
+    variance: 2. * a * a,
+}
+
+continuous! {
+    /// The Cauchy distribution with scale parameter a. Its mean and variance are undefined.
+    Cauchy { a },
+    valid: positive(a),
+    pdf: gsl_ran_cauchy_pdf,
+    cdf: gsl_cdf_cauchy_P,
+    sf: gsl_cdf_cauchy_Q,
+    quantile: gsl_cdf_cauchy_Pinv,
+    sample: cauchy,
+    mean: f64::NAN,
+    variance: f64::NAN,
+}
+
+continuous! {
+    /// The Rayleigh distribution with scale parameter sigma.
+    Rayleigh { sigma },
+    valid: positive(sigma),
+    pdf: gsl_ran_rayleigh_pdf,
+    cdf: gsl_cdf_rayleigh_P,
+    sf: gsl_cdf_rayleigh_Q,
+    quantile: gsl_cdf_rayleigh_Pinv,
+    sample: rayleigh,
+    mean: sigma * (::std::f64::consts::PI / 2.).sqrt(),
+    variance: (4. - ::std::f64::consts::PI) / 2. * sigma * sigma,
+}
+
+continuous! {
+    /// The gamma distribution with shape a and scale b.
+    Gamma { a, b },
+    valid: positive(a) && positive(b),
+    pdf: gsl_ran_gamma_pdf,
+    cdf: gsl_cdf_gamma_P,
+    sf: gsl_cdf_gamma_Q,
+    quantile: gsl_cdf_gamma_Pinv,
+    sample: gamma,
+    mean: a * b,
+    variance: a * b * b,
+}
+
+continuous! {
+    /// The uniform distribution over [a, b).
+    Flat { a, b },
+    valid: a.is_finite() && b.is_finite() && a < b,
+    pdf: gsl_ran_flat_pdf,
+    cdf: gsl_cdf_flat_P,
+    sf: gsl_cdf_flat_Q,
+    quantile: gsl_cdf_flat_Pinv,
+    sample: flat,
+    mean: (a + b) / 2.,
+    variance: (b - a) * (b - a) / 12.,
+}
+
+continuous! {
+    /// The lognormal distribution with location zeta and scale sigma.
+    Lognormal { zeta, sigma },
+    valid: zeta.is_finite() && positive(sigma),
+    pdf: gsl_ran_lognormal_pdf,
+    cdf: gsl_cdf_lognormal_P,
+    sf: gsl_cdf_lognormal_Q,
+    quantile: gsl_cdf_lognormal_Pinv,
+    sample: lognormal,
+    mean: (zeta + sigma * sigma / 2.).exp(),
+    variance: ((sigma * sigma).exp() - 1.) * (2. * zeta + sigma * sigma).exp(),
+}
+
+continuous! {
+    /// The chi-squared distribution with nu degrees of freedom.
+    ChiSquared { nu },
+    valid: positive(nu),
+    pdf: gsl_ran_chisq_pdf,
+    cdf: gsl_cdf_chisq_P,
+    sf: gsl_cdf_chisq_Q,
+    quantile: gsl_cdf_chisq_Pinv,
+    sample: chisq,
+    mean: nu,
+    variance: 2. * nu,
+}
+
+continuous! {
+    /// The F-distribution with nu1 and nu2 degrees of freedom.
+    FDist { nu1, nu2 },
+    valid: positive(nu1) && positive(nu2),
+    pdf: gsl_ran_fdist_pdf,
+    cdf: gsl_cdf_fdist_P,
+    sf: gsl_cdf_fdist_Q,
+    quantile: gsl_cdf_fdist_Pinv,
+    sample: fdist,
+    mean: if nu2 > 2. { nu2 / (nu2 - 2.) } else { f64::INFINITY },
+    variance: if nu2 > 4. {
+        2. * nu2 * nu2 * (nu1 + nu2 - 2.) / (nu1 * (nu2 - 2.) * (nu2 - 2.) * (nu2 - 4.))
+    } else if nu2 > 2. {
+        f64::INFINITY
+    } else {
+        f64::NAN
+    },
+}
+
+continuous! {
+    /// Student's t-distribution with nu degrees of freedom.
+    TDist { nu },
+    valid: positive(nu),
+    pdf: gsl_ran_tdist_pdf,
+    cdf: gsl_cdf_tdist_P,
+    sf: gsl_cdf_tdist_Q,
+    quantile: gsl_cdf_tdist_Pinv,
+    sample: tdist,
+    mean: if nu > 1. { 0. } else { f64::NAN },
+    variance: if nu > 2. {
+        nu / (nu - 2.)
+    } else if nu > 1. {
+        f64::INFINITY
+    } else {
+        f64::NAN
+    },
+}
+
+continuous! {
+    /// The beta distribution with parameters a and b, over [0, 1].
+    Beta { a, b },
+    valid: positive(a) && positive(b),
+    pdf: gsl_ran_beta_pdf,
+    cdf: gsl_cdf_beta_P,
+    sf: gsl_cdf_beta_Q,
+    quantile: gsl_cdf_beta_Pinv,
+    sample: beta,
+    mean: a / (a + b),
+    variance: a * b / ((a + b) * (a + b) * (a + b + 1.)),
+}
+
+continuous! {
+    /// The logistic distribution with scale parameter a.
+    Logistic { a },
+    valid: positive(a),
+    pdf: gsl_ran_logistic_pdf,
+    cdf: gsl_cdf_logistic_P,
+    sf: gsl_cdf_logistic_Q,
+    quantile: gsl_cdf_logistic_Pinv,
+    sample: logistic,
+    mean: 0.,
+    variance: a * a * ::std::f64::consts::PI * ::std::f64::consts::PI / 3.,
+}
+
+continuous! {
+    /// The Pareto distribution of order a and scale b, over [b, \infty).
+    Pareto { a, b },
+    valid: positive(a) && positive(b),
+    pdf: gsl_ran_pareto_pdf,
+    cdf: gsl_cdf_pareto_P,
+    sf: gsl_cdf_pareto_Q,
+    quantile: gsl_cdf_pareto_Pinv,
+    sample: pareto,
+    mean: if a > 1. { a * b / (a - 1.) } else { f64::INFINITY },
+    variance: if a > 2. {
+        b * b * a / ((a - 1.) * (a - 1.) * (a - 2.))
+    } else {
+        f64::INFINITY
+    },
+}
+
+continuous! {
+    /// The Weibull distribution with scale a and exponent b.
+    Weibull { a, b },
+    valid: positive(a) && positive(b),
+    pdf: gsl_ran_weibull_pdf,
+    cdf: gsl_cdf_weibull_P,
+    sf: gsl_cdf_weibull_Q,
+    quantile: gsl_cdf_weibull_Pinv,
+    sample: weibull,
+    mean: a * gamma(1. + 1. / b),
+    variance: a * a * (gamma(1. + 2. / b) - gamma(1. + 1. / b).powi(2)),
+}
+
+continuous! {
+    /// The Type-1 Gumbel distribution with parameters a and b.
+    Gumbel1 { a, b },
+    valid: positive(a) && positive(b),
+    pdf: gsl_ran_gumbel1_pdf,
+    cdf: gsl_cdf_gumbel1_P,
+    sf: gsl_cdf_gumbel1_Q,
+    quantile: gsl_cdf_gumbel1_Pinv,
+    sample: gumbel1,
+    mean: (b.ln() + EULER) / a,
+    variance: ::std::f64::consts::PI * ::std::f64::consts::PI / (6. * a * a),
+}
+
+continuous! {
+    /// The Type-2 Gumbel distribution with parameters a and b.
+    Gumbel2 { a, b },
+    valid: positive(a) && positive(b),
+    pdf: gsl_ran_gumbel2_pdf,
+    cdf: gsl_cdf_gumbel2_P,
+    sf: gsl_cdf_gumbel2_Q,
+    quantile: gsl_cdf_gumbel2_Pinv,
+    sample: gumbel2,
+    mean: if a > 1. {
+        b.powf(1. / a) * gamma(1. - 1. / a)
+    } else {
+        f64::INFINITY
+    },
+    variance: if a > 2. {
+        b.powf(2. / a) * (gamma(1. - 2. / a) - gamma(1. - 1. / a).powi(2))
+    } else {
+        f64::INFINITY
+    },
+}
+
+discrete! {
+    /// The Poisson distribution with mean mu.
+    Poisson { mu: f64 },
+    valid: positive(mu),
+    pdf: gsl_ran_poisson_pdf,
+    cdf: gsl_cdf_poisson_P,
+    sf: gsl_cdf_poisson_Q,
+    sample: poisson,
+    mean: mu,
+    variance: mu,
+}
+
+discrete! {
+    /// The binomial distribution, the number of successes in n independent trials with probability p.
+    Binomial { p: f64, n: u32 },
+    valid: probability(p),
+    pdf: gsl_ran_binomial_pdf,
+    cdf: gsl_cdf_binomial_P,
+    sf: gsl_cdf_binomial_Q,
+    sample: binomial,
+    mean: n as f64 * p,
+    variance: n as f64 * p * (1. - p),
+}
+
+discrete! {
+    /// The negative binomial distribution, the number of failures occurring before n successes in independent trials with
+    /// probability p of success. n need not be an integer.
+    NegativeBinomial { p: f64, n: f64 },
+    valid: p > 0. && p <= 1. && positive(n),
+    pdf: gsl_ran_negative_binomial_pdf,
+    cdf: gsl_cdf_negative_binomial_P,
+    sf: gsl_cdf_negative_binomial_Q,
+    sample: negative_binomial,
+    mean: n * (1. - p) / p,
+    variance: n * (1. - p) / (p * p),
+}
+
+discrete! {
+    /// The Pascal distribution, the negative binomial distribution with an integer value of n.
+    Pascal { p: f64, n: u32 },
+    valid: p > 0. && p <= 1. && n > 0,
+    pdf: gsl_ran_pascal_pdf,
+    cdf: gsl_cdf_pascal_P,
+    sf: gsl_cdf_pascal_Q,
+    sample: pascal,
+    mean: n as f64 * (1. - p) / p,
+    variance: n as f64 * (1. - p) / (p * p),
+}
+
+discrete! {
+    /// The geometric distribution, the number of independent trials with probability p until the first success, for k >= 1.
+    Geometric { p: f64 },
+    valid: p > 0. && p <= 1.,
+    pdf: gsl_ran_geometric_pdf,
+    cdf: gsl_cdf_geometric_P,
+    sf: gsl_cdf_geometric_Q,
+    sample: geometric,
+    mean: 1. / p,
+    variance: (1. - p) / (p * p),
+}
+
+discrete! {
+    /// The hypergeometric distribution, the number of elements of type 1 obtained when drawing t elements without replacement
+    /// from a population of n1 elements of type 1 and n2 elements of type 2.
+    Hypergeometric { n1: u32, n2: u32, t: u32 },
+    valid: n1.checked_add(n2).map_or(false, |n| t <= n),
+    pdf: gsl_ran_hypergeometric_pdf,
+    cdf: gsl_cdf_hypergeometric_P,
+    sf: gsl_cdf_hypergeometric_Q,
+    sample: hypergeometric,
+    mean: {
+        let n = n1 as f64 + n2 as f64;
+        if n == 0. { 0. } else { t as f64 * n1 as f64 / n }
+    },
+    variance: {
+        let n = n1 as f64 + n2 as f64;
+        if n <= 1. {
+            0.
+        } else {
+            t as f64 * (n1 as f64 / n) * (n2 as f64 / n) * (n - t as f64) / (n - 1.)
+        }
+    },
+}
+
+distribution! {
+    /// The Bernoulli distribution, which is 1 with probability p and 0 otherwise.
+    Bernoulli { p: f64 },
+    valid: probability(p),
+}
+
+impl DiscreteDistribution for Bernoulli {
+    fn pdf(&self, k: u32) -> f64 {
+        unsafe { sys::gsl_ran_bernoulli_pdf(k, self.p) }
+    }
+
+    fn cdf(&self, k: u32) -> f64 {
+        if k == 0 {
+            1. - self.p
+        } else {
+            1.
+        }
+    }
+
+    fn sf(&self, k: u32) -> f64 {
+        if k == 0 {
+            self.p
+        } else {
+            0.
+        }
+    }
+
+    fn mean(&self) -> f64 {
+        self.p
+    }
+
+    fn variance(&self) -> f64 {
+        self.p * (1. - self.p)
+    }
+
+    fn sample(&self, rng: &mut ::Rng) -> u32 {
+        rng.bernoulli(self.p)
+    }
+}
+
+#[test]
+fn distributions() {
+    let g = Gaussian::new(1.).unwrap();
+    assert_eq!(format!("{:.4}", g.cdf(1.96)), "0.9750");
+    assert_eq!(format!("{:.4}", g.quantile(0.975)), "1.9600");
+    assert_eq!(format!("{:.4}", g.pdf(0.)), "0.3989");
+    assert!(g.quantile(1.5).is_nan());
+    assert_eq!(Gaussian::new(0.), Err(Value::Domain));
+    assert_eq!(Gamma::new(1., f64::NAN), Err(Value::Domain));
+
+    let c = ChiSquared::new(2.).unwrap();
+    assert_eq!(format!("{:.4}", c.sf(5.991)), "0.0500");
+    assert_eq!(format!("{:.4}", c.mean()), "2.0000");
+
+    let p = Poisson::new(2.).unwrap();
+    assert_eq!(format!("{:.4}", p.pdf(0)), "0.1353");
+    assert_eq!(p.quantile(0.5), Some(2));
+    assert_eq!(p.quantile(0.), Some(0));
+    assert_eq!(p.quantile(-0.1), None);
+
+    let b = Binomial::new(0.5, 10).unwrap();
+    assert_eq!(format!("{:.4}", b.pdf(5)), "0.2461");
+    assert_eq!(b.quantile(1.), Some(10));
+    assert_eq!(Binomial::new(1.5, 10), Err(Value::Domain));
+    assert_eq!(Hypergeometric::new(2, 3, 6), Err(Value::Domain));
+
+    let mut rng = ::Rng::new(::RngType::default()).unwrap();
+    let samples = Exponential::new(3.).unwrap().sample_n(&mut rng, 10);
+    assert_eq!(samples.len(), 10);
+    assert!(samples.iter().all(|&x| x >= 0.));
+}
@@ -60,6 +60,7 @@ pub mod bivariate_gaussian;
 pub mod cauchy;
 pub mod chi_squared;
 pub mod dirichlet;
+pub mod distributions;
 pub mod exponential;
 pub mod exponential_power;
 pub mod f_distribution;
@@ -76,6 +77,9 @@ pub mod logarithmic;
 pub mod logistic;
 pub mod lognormal;
 pub mod multinomial;
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+pub mod multivariate_gaussian;
 pub mod negative_binomial;
 pub mod pareto;
 pub mod pascal;
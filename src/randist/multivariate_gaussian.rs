@@ -0,0 +1,191 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+The multivariate Gaussian distribution with mean vector \mu and covariance matrix \Sigma = L L^T has the probability density
+
+p(x_1,...,x_k) dx_1...dx_k = 1 / \sqrt{(2 \pi)^k |\Sigma|} \exp(-1/2 (x - \mu)^T \Sigma^{-1} (x - \mu)) dx_1...dx_k
+
+All the routines of this module take the covariance matrix in its Cholesky factored form L, which can be obtained from \Sigma with
+[`cholesky_factor`](fn.cholesky_factor.html). Only the lower triangle of L is referenced.
+!*/
+
+use crate::Value;
+use ffi::FFI;
+
+/// This function returns the lower triangular Cholesky factor L of the symmetric, positive-definite covariance matrix `covariance`,
+/// such that \Sigma = L L^T. The strict upper triangle of the returned matrix is set to zero.
+///
+/// Returns `Value::BadLength` if `covariance` is not square and `Value::Domain` if it is not positive-definite. GSL reports
+/// the latter through its error handler, which aborts by default: turn it off with
+/// [`set_error_handler_off`](../../error/fn.set_error_handler_off.html) first to get the error back.
+pub fn cholesky_factor(covariance: &::MatrixF64) -> Result<::MatrixF64, Value> {
+    let n = covariance.size1();
+    if n == 0 || n != covariance.size2() {
+        return Err(Value::BadLength);
+    }
+    let mut l = covariance.clone().ok_or(Value::NoMemory)?;
+    match ::linear_algebra::cholesky_decomp(&mut l) {
+        Value::Success => {}
+        e => return Err(e),
+    }
+    for i in 0..n {
+        for j in i + 1..n {
+            l.set(i, j, 0.);
+        }
+    }
+    Ok(l)
+}
+
+/// This function computes the probability density p(x_1,...,x_k) at x for a multivariate Gaussian distribution with mean mu and
+/// covariance matrix \Sigma = L L^T. `work` is a workspace of length k.
+#[doc(alias = "gsl_ran_multivariate_gaussian_pdf")]
+pub fn multivariate_gaussian_pdf(
+    x: &::VectorF64,
+    mu: &::VectorF64,
+    L: &::MatrixF64,
+    work: &mut ::VectorF64,
+) -> (Value, f64) {
+    let mut result = 0.;
+    let ret = unsafe {
+        sys::gsl_ran_multivariate_gaussian_pdf(
+            x.unwrap_shared(),
+            mu.unwrap_shared(),
+            L.unwrap_shared(),
+            &mut result,
+            work.unwrap_unique(),
+        )
+    };
+    (Value::from(ret), result)
+}
+
+/// This function computes the logarithm of the probability density p(x_1,...,x_k) at x for a multivariate Gaussian distribution with
+/// mean mu and covariance matrix \Sigma = L L^T. `work` is a workspace of length k.
+#[doc(alias = "gsl_ran_multivariate_gaussian_log_pdf")]
+pub fn multivariate_gaussian_log_pdf(
+    x: &::VectorF64,
+    mu: &::VectorF64,
+    L: &::MatrixF64,
+    work: &mut ::VectorF64,
+) -> (Value, f64) {
+    let mut result = 0.;
+    let ret = unsafe {
+        sys::gsl_ran_multivariate_gaussian_log_pdf(
+            x.unwrap_shared(),
+            mu.unwrap_shared(),
+            L.unwrap_shared(),
+            &mut result,
+            work.unwrap_unique(),
+        )
+    };
+    (Value::from(ret), result)
+}
+
+/// Given a set of n samples X_j from a k-dimensional multivariate Gaussian distribution, stored in the rows of the n-by-k matrix X,
+/// this function computes the maximum likelihood estimate of the mean of the distribution, storing it in mu_hat (of length k).
+#[doc(alias = "gsl_ran_multivariate_gaussian_mean")]
+pub fn multivariate_gaussian_mean(X: &::MatrixF64, mu_hat: &mut ::VectorF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_ran_multivariate_gaussian_mean(X.unwrap_shared(), mu_hat.unwrap_unique())
+    })
+}
+
+/// Given a set of n samples X_j from a k-dimensional multivariate Gaussian distribution, stored in the rows of the n-by-k matrix X,
+/// this function computes the maximum likelihood estimate of the variance-covariance matrix of the distribution, storing it in the
+/// k-by-k matrix sigma_hat.
+#[doc(alias = "gsl_ran_multivariate_gaussian_vcov")]
+pub fn multivariate_gaussian_vcov(X: &::MatrixF64, sigma_hat: &mut ::MatrixF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_ran_multivariate_gaussian_vcov(X.unwrap_shared(), sigma_hat.unwrap_unique())
+    })
+}
+
+/// This function computes the probability density at the p-by-p matrix X for a Wishart distribution with n = df degrees of freedom
+/// and scale matrix V = L L^T. `L_X` is the Cholesky factor of X and `work` is a p-by-p workspace.
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_ran_wishart_pdf")]
+pub fn wishart_pdf(
+    X: &::MatrixF64,
+    L_X: &::MatrixF64,
+    df: f64,
+    L: &::MatrixF64,
+    work: &mut ::MatrixF64,
+) -> (Value, f64) {
+    let mut result = 0.;
+    let ret = unsafe {
+        sys::gsl_ran_wishart_pdf(
+            X.unwrap_shared(),
+            L_X.unwrap_shared(),
+            df,
+            L.unwrap_shared(),
+            &mut result,
+            work.unwrap_unique(),
+        )
+    };
+    (Value::from(ret), result)
+}
+
+/// This function computes the logarithm of the probability density at the p-by-p matrix X for a Wishart distribution with n = df
+/// degrees of freedom and scale matrix V = L L^T. `L_X` is the Cholesky factor of X and `work` is a p-by-p workspace.
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_ran_wishart_log_pdf")]
+pub fn wishart_log_pdf(
+    X: &::MatrixF64,
+    L_X: &::MatrixF64,
+    df: f64,
+    L: &::MatrixF64,
+    work: &mut ::MatrixF64,
+) -> (Value, f64) {
+    let mut result = 0.;
+    let ret = unsafe {
+        sys::gsl_ran_wishart_log_pdf(
+            X.unwrap_shared(),
+            L_X.unwrap_shared(),
+            df,
+            L.unwrap_shared(),
+            &mut result,
+            work.unwrap_unique(),
+        )
+    };
+    (Value::from(ret), result)
+}
+
+#[test]
+fn multivariate_gaussian() {
+    let mut cov = ::MatrixF64::new(2, 2).unwrap();
+    cov.set(0, 0, 4.);
+    cov.set(0, 1, 2.);
+    cov.set(1, 0, 2.);
+    cov.set(1, 1, 3.);
+    let l = cholesky_factor(&cov).unwrap();
+    assert_eq!(
+        format!(
+            "{:.4} {:.4} {:.4} {:.4}",
+            l.get(0, 0),
+            l.get(0, 1),
+            l.get(1, 0),
+            l.get(1, 1)
+        ),
+        "2.0000 0.0000 1.0000 1.4142"
+    );
+
+    // standard bivariate normal at its mean: 1 / (2 pi)
+    let mut id = ::MatrixF64::new(2, 2).unwrap();
+    id.set_identity();
+    let x = ::VectorF64::new(2).unwrap();
+    let mu = ::VectorF64::new(2).unwrap();
+    let mut work = ::VectorF64::new(2).unwrap();
+    let (ret, p) = multivariate_gaussian_pdf(&x, &mu, &id, &mut work);
+    assert_eq!(ret, Value::Success);
+    assert_eq!(format!("{:.6}", p), "0.159155");
+    let (_, lp) = multivariate_gaussian_log_pdf(&x, &mu, &id, &mut work);
+    assert_eq!(format!("{:.6}", lp), "-1.837877");
+
+    assert_eq!(
+        cholesky_factor(&::MatrixF64::new(2, 3).unwrap()).err(),
+        Some(Value::BadLength)
+    );
+}
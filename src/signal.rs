@@ -0,0 +1,312 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Signal processing
+
+This module provides FFT-based convolution and cross-correlation of real sequences, together with
+the usual window functions and power spectral density estimates (periodogram and Welch's method).
+All the transforms are computed with the mixed-radix real FFT routines of the [`fft`](crate::fft)
+module, so sequences of any length are supported.
+
+## Convolution and correlation
+
+The (linear) convolution of two sequences a and v of lengths N and M is defined as,
+
+(a * v)_k = \sum_n a_n v_{k-n}
+
+for k = 0 .. N+M-2, and the cross-correlation as,
+
+c_k = \sum_n a_{n+k} v_n.
+
+The [`ConvolveMode`] selects which part of the full result is returned, following the usual
+`full`/`same`/`valid` conventions.
+
+## Spectral estimation
+
+The power spectral densities are one-sided: for a signal sampled at frequency f_s and of length N,
+the returned frequencies are k f_s / N for k = 0 .. N/2, and the density at every frequency other
+than zero and the Nyquist frequency is doubled to account for the negative frequencies. With a
+window w, the periodogram is,
+
+P_k = c |\sum_n w_n x_n \exp(-2\pi i n k / N)|^2 / (f_s \sum_n w_n^2)
+
+with c = 2 for the doubled frequencies and c = 1 otherwise, so that the integral of the density
+over the frequencies equals the mean power of the (windowed) signal. Welch's method averages the
+periodograms of overlapping segments of the signal to reduce the variance of the estimate.
+!*/
+
+use crate::{ComplexF64, FftRealPlan, Value};
+use std::f64::consts::PI;
+
+/// Selects which part of the full convolution (or correlation) is returned.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ConvolveMode {
+    /// The full result, of length N + M - 1.
+    Full,
+    /// The central part of the full result, of length max(N, M).
+    Same,
+    /// Only the elements which do not depend on zero-padding, of length max(N, M) - min(N, M) + 1.
+    Valid,
+}
+
+/// Window functions used to taper a signal before computing its spectrum.
+///
+/// All the windows are given in their symmetric form by [`Window::symmetric`], with w_0 and
+/// w_{N-1} as the two end points, as found in filter design textbooks. The periodic (DFT-even)
+/// form used for spectral estimation is returned by [`Window::periodic`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Window {
+    /// w_n = 1
+    Rectangular,
+    /// w_n = 0.5 - 0.5 \cos(2\pi n / (N-1))
+    Hann,
+    /// w_n = 0.54 - 0.46 \cos(2\pi n / (N-1))
+    Hamming,
+    /// w_n = 0.42 - 0.5 \cos(2\pi n / (N-1)) + 0.08 \cos(4\pi n / (N-1))
+    Blackman,
+    /// w_n = I_0(\beta \sqrt{1 - (2n/(N-1) - 1)^2}) / I_0(\beta), where I_0 is the modified
+    /// Bessel function of zeroth order and `beta` the shape parameter.
+    Kaiser(f64),
+}
+
+impl Window {
+    /// Returns the `n` coefficients of the symmetric window.
+    pub fn symmetric(&self, n: usize) -> Vec<f64> {
+        if n == 1 {
+            return vec![1.];
+        }
+        let m = n as f64 - 1.;
+        (0..n)
+            .map(|k| {
+                let x = k as f64 / m;
+                match *self {
+                    Window::Rectangular => 1.,
+                    Window::Hann => 0.5 - 0.5 * (2. * PI * x).cos(),
+                    Window::Hamming => 0.54 - 0.46 * (2. * PI * x).cos(),
+                    Window::Blackman => {
+                        0.42 - 0.5 * (2. * PI * x).cos() + 0.08 * (4. * PI * x).cos()
+                    }
+                    Window::Kaiser(beta) => {
+                        let r = 2. * x - 1.;
+                        ::bessel::I0(beta * (1. - r * r).max(0.).sqrt()) / ::bessel::I0(beta)
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the `n` coefficients of the periodic window, which are the first `n` coefficients
+    /// of the symmetric window of length `n + 1`.
+    pub fn periodic(&self, n: usize) -> Vec<f64> {
+        let mut w = self.symmetric(n + 1);
+        w.truncate(n);
+        w
+    }
+}
+
+/// A one-sided power spectral density estimate.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Spectrum {
+    /// The frequencies, in the same unit as the sampling frequency.
+    pub frequencies: Vec<f64>,
+    /// The power spectral density at each frequency.
+    pub power: Vec<f64>,
+}
+
+/// Returns the convolution of `a` and `v`, computed with FFTs.
+///
+/// Returns `Value::BadLength` if one of the sequences is empty.
+pub fn convolve(a: &[f64], v: &[f64], mode: ConvolveMode) -> Result<Vec<f64>, Value> {
+    if a.is_empty() || v.is_empty() {
+        return Err(Value::BadLength);
+    }
+    let n = a.len() + v.len() - 1;
+    let mut plan = FftRealPlan::new(n).ok_or(Value::NoMemory)?;
+    let fa = transform_padded(&mut plan, a)?;
+    let fv = transform_padded(&mut plan, v)?;
+    let product: Vec<ComplexF64> = fa
+        .iter()
+        .zip(fv.iter())
+        .map(|(x, y)| ComplexF64 {
+            dat: [
+                x.dat[0] * y.dat[0] - x.dat[1] * y.dat[1],
+                x.dat[0] * y.dat[1] + x.dat[1] * y.dat[0],
+            ],
+        })
+        .collect();
+    let mut full = vec![0.; n];
+    plan.inverse(&product, &mut full)?;
+
+    let (long, short) = if a.len() >= v.len() {
+        (a.len(), v.len())
+    } else {
+        (v.len(), a.len())
+    };
+    Ok(match mode {
+        ConvolveMode::Full => full,
+        ConvolveMode::Same => {
+            let start = (short - 1) / 2;
+            full[start..start + long].to_vec()
+        }
+        ConvolveMode::Valid => full[short - 1..long].to_vec(),
+    })
+}
+
+/// Returns the cross-correlation c_k = \sum_n a_{n+k} v_n of `a` and `v`. In `Full` mode, the
+/// element at index `v.len() - 1` corresponds to a zero lag.
+///
+/// Returns `Value::BadLength` if one of the sequences is empty.
+pub fn correlate(a: &[f64], v: &[f64], mode: ConvolveMode) -> Result<Vec<f64>, Value> {
+    let reversed: Vec<f64> = v.iter().rev().cloned().collect();
+    convolve(a, &reversed, mode)
+}
+
+/// Returns the periodogram of `x`, sampled at frequency `fs`, tapered by `window`.
+///
+/// Returns `Value::BadLength` if `x` is empty and `Value::Domain` if `fs` isn't strictly
+/// positive.
+pub fn periodogram(x: &[f64], fs: f64, window: Window) -> Result<Spectrum, Value> {
+    if x.is_empty() {
+        return Err(Value::BadLength);
+    }
+    if fs <= 0. {
+        return Err(Value::Domain);
+    }
+    let mut plan = FftRealPlan::new(x.len()).ok_or(Value::NoMemory)?;
+    let w = window.periodic(x.len());
+    let mut power = vec![0.; plan.complex_len()];
+    accumulate_psd(&mut plan, x, &w, fs, &mut power)?;
+    Ok(Spectrum {
+        frequencies: frequencies(x.len(), fs),
+        power,
+    })
+}
+
+/// Returns the Welch estimate of the power spectral density of `x`, sampled at frequency `fs`.
+///
+/// The signal is split into segments of `segment_len` samples, consecutive segments sharing
+/// `overlap` samples. Each segment is tapered by `window` and the periodograms of all the
+/// segments are averaged. Samples at the end of `x` which don't fill a whole segment are ignored.
+///
+/// Returns `Value::BadLength` if `segment_len` is zero or larger than `x`, `Value::Invalid` if
+/// `overlap` isn't smaller than `segment_len` and `Value::Domain` if `fs` isn't strictly positive.
+pub fn welch(
+    x: &[f64],
+    fs: f64,
+    window: Window,
+    segment_len: usize,
+    overlap: usize,
+) -> Result<Spectrum, Value> {
+    if segment_len == 0 || segment_len > x.len() {
+        return Err(Value::BadLength);
+    }
+    if overlap >= segment_len {
+        return Err(Value::Invalid);
+    }
+    if fs <= 0. {
+        return Err(Value::Domain);
+    }
+    let mut plan = FftRealPlan::new(segment_len).ok_or(Value::NoMemory)?;
+    let w = window.periodic(segment_len);
+    let mut power = vec![0.; plan.complex_len()];
+    let step = segment_len - overlap;
+    let mut count = 0;
+    let mut start = 0;
+    while start + segment_len <= x.len() {
+        accumulate_psd(
+            &mut plan,
+            &x[start..start + segment_len],
+            &w,
+            fs,
+            &mut power,
+        )?;
+        count += 1;
+        start += step;
+    }
+    for p in power.iter_mut() {
+        *p /= count as f64;
+    }
+    Ok(Spectrum {
+        frequencies: frequencies(segment_len, fs),
+        power,
+    })
+}
+
+fn transform_padded(plan: &mut FftRealPlan, x: &[f64]) -> Result<Vec<ComplexF64>, Value> {
+    let mut padded = vec![0.; plan.len()];
+    padded[..x.len()].copy_from_slice(x);
+    let mut out = vec![ComplexF64::default(); plan.complex_len()];
+    plan.forward(&padded, &mut out)?;
+    Ok(out)
+}
+
+/// Adds the one-sided periodogram of `x` tapered by `w` to `power`.
+fn accumulate_psd(
+    plan: &mut FftRealPlan,
+    x: &[f64],
+    w: &[f64],
+    fs: f64,
+    power: &mut [f64],
+) -> Result<(), Value> {
+    let n = x.len();
+    let tapered: Vec<f64> = x.iter().zip(w.iter()).map(|(a, b)| a * b).collect();
+    let mut coeffs = vec![ComplexF64::default(); plan.complex_len()];
+    plan.forward(&tapered, &mut coeffs)?;
+
+    let scale = fs * w.iter().map(|a| a * a).sum::<f64>();
+    for (k, (p, z)) in power.iter_mut().zip(coeffs.iter()).enumerate() {
+        let doubled = k != 0 && 2 * k != n;
+        let c = if doubled { 2. } else { 1. };
+        *p += c * (z.dat[0] * z.dat[0] + z.dat[1] * z.dat[1]) / scale;
+    }
+    Ok(())
+}
+
+fn frequencies(n: usize, fs: f64) -> Vec<f64> {
+    (0..n / 2 + 1).map(|k| k as f64 * fs / n as f64).collect()
+}
+
+#[test]
+fn signal() {
+    let a = [1., 2., 3.];
+    let v = [0., 1., 0.5];
+    let fmt = |x: &[f64]| {
+        x.iter()
+            .map(|x| format!("{:.4}", x))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let full = convolve(&a, &v, ConvolveMode::Full).unwrap();
+    assert_eq!(fmt(&full), "0.0000 1.0000 2.5000 4.0000 1.5000");
+    let same = convolve(&a, &v, ConvolveMode::Same).unwrap();
+    assert_eq!(fmt(&same), "1.0000 2.5000 4.0000");
+    let valid = convolve(&a, &v, ConvolveMode::Valid).unwrap();
+    assert_eq!(fmt(&valid), "2.5000");
+    let corr = correlate(&a, &v, ConvolveMode::Full).unwrap();
+    assert_eq!(fmt(&corr), "0.5000 2.0000 3.5000 3.0000 0.0000");
+
+    assert_eq!(
+        fmt(&Window::Hann.symmetric(5)),
+        "0.0000 0.5000 1.0000 0.5000 0.0000"
+    );
+    assert_eq!(fmt(&Window::Hamming.symmetric(3)), "0.0800 1.0000 0.0800");
+    assert_eq!(
+        fmt(&Window::Kaiser(0.).symmetric(3)),
+        "1.0000 1.0000 1.0000"
+    );
+
+    // Parseval: the density integrates to the mean power of the signal.
+    let x: Vec<f64> = (0..64).map(|i| (i as f64 * 0.3).sin() + 0.25).collect();
+    let p = periodogram(&x, 2., Window::Rectangular).unwrap();
+    let df = p.frequencies[1] - p.frequencies[0];
+    let total: f64 = p.power.iter().sum::<f64>() * df;
+    let mean_power: f64 = x.iter().map(|v| v * v).sum::<f64>() / x.len() as f64;
+    assert_eq!(format!("{:.6}", total), format!("{:.6}", mean_power));
+
+    let w = welch(&x, 2., Window::Hann, 16, 8).unwrap();
+    assert_eq!(w.frequencies.len(), 9);
+    assert_eq!(welch(&x, 2., Window::Hann, 16, 16), Err(Value::Invalid));
+}
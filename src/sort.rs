@@ -21,6 +21,8 @@ The Heapsort algorithm is described in the following book,
 Robert Sedgewick, Algorithms in C, Addison-Wesley, ISBN 0201514257.
 !*/
 
+use crate::paste::paste;
+
 /// The following functions will sort the elements of an array or vector, either directly or indirectly. They are defined for all real and
 /// integer types using the normal suffix rules. For example, the float versions of the array functions are gsl_sort_float and gsl_sort_float_index.
 /// The corresponding vector functions are gsl_sort_vector_float and gsl_sort_vector_float_index. The prototypes are available in the header files
@@ -30,21 +32,46 @@ Robert Sedgewick, Algorithms in C, Addison-Wesley, ISBN 0201514257.
 /// vector by magnitude compute a real vector containing the magnitudes of the complex elements, and sort this vector indirectly. The resulting index
 /// gives the appropriate ordering of the original complex vector.
 pub mod vectors {
+    use super::SortElement;
+    use crate::paste::paste;
     use crate::Value;
     use ffi::FFI;
     use types::{Permutation, VectorF64};
 
+    /// Panics if the stride is zero or if n elements with this stride don't fit in `len` elements.
+    fn check(len: usize, stride: usize, n: usize) {
+        assert!(stride > 0, "stride must be positive");
+        assert!(
+            n == 0 || (n - 1).checked_mul(stride).map_or(false, |last| last < len),
+            "n elements with this stride overrun the data"
+        );
+    }
+
     /// This function sorts the n elements of the array data with stride stride into ascending numerical order.
+    ///
+    /// It is available for every [`SortElement`](../trait.SortElement.html): `gsl_sort_float` is used for `f32` data,
+    /// `gsl_sort_int` for `i32` data, and so on.
     #[doc(alias = "gsl_sort")]
-    pub fn sort(data: &mut [f64], stride: usize, n: usize) {
-        unsafe { sys::gsl_sort(data.as_mut_ptr(), stride, n) }
+    #[doc(alias = "gsl_sort_float")]
+    pub fn sort<T: SortElement>(data: &mut [T], stride: usize, n: usize) {
+        check(data.len(), stride, n);
+        unsafe { T::sort(data.as_mut_ptr(), stride, n) }
     }
 
     /// This function sorts the n elements of the array data1 with stride stride1 into ascending numerical order, while making the same rearrangement
     /// of the array data2 with stride stride2, also of size n.
     #[doc(alias = "gsl_sort2")]
-    pub fn sort2(data1: &mut [f64], stride1: usize, data2: &mut [f64], stride2: usize, n: usize) {
-        unsafe { sys::gsl_sort2(data1.as_mut_ptr(), stride1, data2.as_mut_ptr(), stride2, n) }
+    #[doc(alias = "gsl_sort2_float")]
+    pub fn sort2<T: SortElement>(
+        data1: &mut [T],
+        stride1: usize,
+        data2: &mut [T],
+        stride2: usize,
+        n: usize,
+    ) {
+        check(data1.len(), stride1, n);
+        check(data2.len(), stride2, n);
+        unsafe { T::sort2(data1.as_mut_ptr(), stride1, data2.as_mut_ptr(), stride2, n) }
     }
 
     /// This function sorts the elements of the vector v into ascending numerical order.
@@ -63,8 +90,11 @@ pub mod vectors {
     /// permutation in p. The array p must be allocated with a sufficient length to store the n elements of the permutation. The elements of p
     /// give the index of the array element which would have been stored in that position if the array had been sorted in place. The array data is not changed.
     #[doc(alias = "gsl_sort_index")]
-    pub fn sort_index(p: &mut [usize], data: &[f64], stride: usize, n: usize) {
-        unsafe { sys::gsl_sort_index(p.as_mut_ptr(), data.as_ptr(), stride, n) }
+    #[doc(alias = "gsl_sort_float_index")]
+    pub fn sort_index<T: SortElement>(p: &mut [usize], data: &[T], stride: usize, n: usize) {
+        assert!(p.len() >= n);
+        check(data.len(), stride, n);
+        unsafe { T::sort_index(p.as_mut_ptr(), data.as_ptr(), stride, n) }
     }
 
     /// This function indirectly sorts the elements of the vector v into ascending order, storing the resulting permutation in p. The elements of p give the
@@ -74,6 +104,45 @@ pub mod vectors {
     pub fn sort_vector_index(p: &mut Permutation, v: &VectorF64) -> Value {
         Value::from(unsafe { sys::gsl_sort_vector_index(p.unwrap_unique(), v.unwrap_shared()) })
     }
+
+    macro_rules! sort_vector {
+        ($suffix:ident, $vector:ident) => {
+            paste! {
+                #[doc = "Same as [`sort_vector`](fn.sort_vector.html), for [`" $vector "`](../../struct." $vector ".html)."]
+                #[doc(alias = "gsl_sort_vector_" $suffix)]
+                pub fn [<sort_vector_ $suffix>](v: &mut ::$vector) {
+                    unsafe { sys::[<gsl_sort_vector_ $suffix>](v.unwrap_unique()) }
+                }
+
+                #[doc = "Same as [`sort_vector2`](fn.sort_vector2.html), for [`" $vector "`](../../struct." $vector ".html)."]
+                #[doc(alias = "gsl_sort_vector2_" $suffix)]
+                pub fn [<sort_vector2_ $suffix>](v1: &mut ::$vector, v2: &mut ::$vector) {
+                    unsafe { sys::[<gsl_sort_vector2_ $suffix>](v1.unwrap_unique(), v2.unwrap_unique()) }
+                }
+
+                #[doc = "Same as [`sort_vector_index`](fn.sort_vector_index.html), for [`" $vector "`](../../struct." $vector ".html)."]
+                #[doc(alias = "gsl_sort_vector_" $suffix "_index")]
+                pub fn [<sort_vector_ $suffix _index>](p: &mut Permutation, v: &::$vector) -> Value {
+                    Value::from(unsafe {
+                        sys::[<gsl_sort_vector_ $suffix _index>](p.unwrap_unique(), v.unwrap_shared())
+                    })
+                }
+            }
+        };
+    }
+
+    sort_vector!(float, VectorF32);
+    sort_vector!(int, VectorI32);
+    sort_vector!(uint, VectorU32);
+    #[cfg(gsl_long_64)]
+    sort_vector!(long, VectorI64);
+    #[cfg(gsl_long_64)]
+    sort_vector!(ulong, VectorU64);
+    sort_vector!(short, VectorI16);
+    sort_vector!(ushort, VectorU16);
+    #[cfg(gsl_char_signed)]
+    sort_vector!(char, VectorI8);
+    sort_vector!(uchar, VectorU8);
 }
 
 /// The functions described in this section select the k smallest or largest elements of a data set of size N. The routines use an O(kN) direct insertion
@@ -81,31 +150,51 @@ pub mod vectors {
 /// 10 largest values from one million data points, but not for selecting the largest 100,000 values. If the subset is a significant part of the total dataset
 /// it may be faster to sort all the elements of the dataset directly with an O(N \log N) algorithm and obtain the smallest or largest values that way.
 pub mod select {
+    use super::SortElement;
     use crate::Value;
     use ffi::FFI;
     use types::VectorF64;
 
+    /// Returns the number of elements of `src` with the given stride.
+    fn strided_len<T>(src: &[T], stride: usize) -> usize {
+        assert!(stride > 0);
+        (src.len() + stride - 1) / stride
+    }
+
     /// This function copies the k smallest elements of the array src, of size n and stride stride, in ascending numerical order into the array dest. The size
     /// k of the subset must be less than or equal to n. The data src is not modified by this operation.
     #[doc(alias = "gsl_sort_smallest")]
-    pub fn sort_smallest(dest: &mut [f64], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_smallest(dest.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    #[doc(alias = "gsl_sort_float_smallest")]
+    pub fn sort_smallest<T: SortElement>(
+        dest: &mut [T],
+        k: usize,
+        src: &[T],
+        stride: usize,
+    ) -> Value {
+        assert!(k <= dest.len());
+        let n = strided_len(src, stride);
+        Value::from(unsafe { T::smallest(dest.as_mut_ptr(), k, src.as_ptr(), stride, n) })
     }
 
     /// This function copies the k largest elements of the array src, of size n and stride stride, in descending numerical order into the array dest. k must
     /// be less than or equal to n. The data src is not modified by this operation.
     #[doc(alias = "gsl_sort_largest")]
-    pub fn sort_largest(dest: &mut [f64], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_largest(dest.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    #[doc(alias = "gsl_sort_float_largest")]
+    pub fn sort_largest<T: SortElement>(
+        dest: &mut [T],
+        k: usize,
+        src: &[T],
+        stride: usize,
+    ) -> Value {
+        assert!(k <= dest.len());
+        let n = strided_len(src, stride);
+        Value::from(unsafe { T::largest(dest.as_mut_ptr(), k, src.as_ptr(), stride, n) })
     }
 
     /// This function copies the k smallest or largest elements of the vector v into the array dest. k must be less than or equal to the length of the vector v.
     #[doc(alias = "gsl_sort_vector_smallest")]
     pub fn sort_vector_smallest(dest: &mut [f64], k: usize, v: &VectorF64) -> Value {
+        assert!(k <= dest.len());
         Value::from(unsafe {
             sys::gsl_sort_vector_smallest(dest.as_mut_ptr(), k, v.unwrap_shared())
         })
@@ -114,6 +203,7 @@ pub mod select {
     /// This function copies the k smallest or largest elements of the vector v into the array dest. k must be less than or equal to the length of the vector v.
     #[doc(alias = "gsl_sort_vector_largest")]
     pub fn sort_vector_largest(dest: &mut [f64], k: usize, v: &VectorF64) -> Value {
+        assert!(k <= dest.len());
         Value::from(unsafe {
             sys::gsl_sort_vector_largest(dest.as_mut_ptr(), k, v.unwrap_shared())
         })
@@ -122,25 +212,38 @@ pub mod select {
     /// This function stores the indices of the k smallest elements of the array src, of size n and stride stride, in the array p. The indices are chosen so that
     /// the corresponding data is in ascending numerical order. k must be less than or equal to n. The data src is not modified by this operation.
     #[doc(alias = "gsl_sort_smallest_index")]
-    pub fn sort_smallest_index(p: &mut [usize], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_smallest_index(p.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    #[doc(alias = "gsl_sort_float_smallest_index")]
+    pub fn sort_smallest_index<T: SortElement>(
+        p: &mut [usize],
+        k: usize,
+        src: &[T],
+        stride: usize,
+    ) -> Value {
+        assert!(k <= p.len());
+        let n = strided_len(src, stride);
+        Value::from(unsafe { T::smallest_index(p.as_mut_ptr(), k, src.as_ptr(), stride, n) })
     }
 
     /// This function stores the indices of the k largest elements of the array src, of size n and stride stride, in the array p. The indices are chosen so that
     /// the corresponding data is in descending numerical order. k must be less than or equal to n. The data src is not modified by this operation.
     #[doc(alias = "gsl_sort_largest_index")]
-    pub fn sort_largest_index(p: &mut [usize], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_largest_index(p.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    #[doc(alias = "gsl_sort_float_largest_index")]
+    pub fn sort_largest_index<T: SortElement>(
+        p: &mut [usize],
+        k: usize,
+        src: &[T],
+        stride: usize,
+    ) -> Value {
+        assert!(k <= p.len());
+        let n = strided_len(src, stride);
+        Value::from(unsafe { T::largest_index(p.as_mut_ptr(), k, src.as_ptr(), stride, n) })
     }
 
     /// This function stores the indices of the k smallest or largest elements of the vector v in the array p. k must be less than or equal to the length of
     /// the vector v.
     #[doc(alias = "gsl_sort_vector_smallest_index")]
     pub fn sort_vector_smallest_index(p: &mut [usize], k: usize, v: &VectorF64) -> Value {
+        assert!(k <= p.len());
         Value::from(unsafe {
             sys::gsl_sort_vector_smallest_index(p.as_mut_ptr(), k, v.unwrap_shared())
         })
@@ -150,8 +253,168 @@ pub mod select {
     /// the vector v.
     #[doc(alias = "gsl_sort_vector_largest_index")]
     pub fn sort_vector_largest_index(p: &mut [usize], k: usize, v: &VectorF64) -> Value {
+        assert!(k <= p.len());
         Value::from(unsafe {
             sys::gsl_sort_vector_largest_index(p.as_mut_ptr(), k, v.unwrap_shared())
         })
     }
 }
+
+/// Implemented by the types the array functions of [`vectors`](vectors/index.html) and [`select`](select/index.html)
+/// can sort: `f64` dispatches to `gsl_sort`, `f32` to `gsl_sort_float`, `u8` to `gsl_sort_uchar`, and so on. The
+/// integer types follow the vector types of this crate, so `i8` and `i64`/`u64` are missing on the targets where C
+/// `char` is unsigned or C `long` has 32 bits.
+///
+/// # Safety
+///
+/// GSL sorts the data in place through the pointers it is given, so each implementation must use the `gsl_sort`
+/// family whose C element type has the layout of `Self`.
+pub unsafe trait SortElement: Copy + PartialOrd {
+    #[doc(hidden)]
+    unsafe fn sort(data: *mut Self, stride: usize, n: usize);
+    #[doc(hidden)]
+    unsafe fn sort2(data1: *mut Self, stride1: usize, data2: *mut Self, stride2: usize, n: usize);
+    #[doc(hidden)]
+    unsafe fn sort_index(p: *mut usize, data: *const Self, stride: usize, n: usize);
+    #[doc(hidden)]
+    unsafe fn smallest(dest: *mut Self, k: usize, src: *const Self, stride: usize, n: usize)
+        -> i32;
+    #[doc(hidden)]
+    unsafe fn largest(dest: *mut Self, k: usize, src: *const Self, stride: usize, n: usize) -> i32;
+    #[doc(hidden)]
+    unsafe fn smallest_index(
+        p: *mut usize,
+        k: usize,
+        src: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> i32;
+    #[doc(hidden)]
+    unsafe fn largest_index(
+        p: *mut usize,
+        k: usize,
+        src: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> i32;
+}
+
+macro_rules! sort_element {
+    ($ty:ty, $sort:ident, $sort2:ident) => {
+        paste! {
+            unsafe impl SortElement for $ty {
+                unsafe fn sort(data: *mut Self, stride: usize, n: usize) {
+                    sys::$sort(data as _, stride, n)
+                }
+
+                unsafe fn sort2(
+                    data1: *mut Self,
+                    stride1: usize,
+                    data2: *mut Self,
+                    stride2: usize,
+                    n: usize,
+                ) {
+                    sys::$sort2(data1 as _, stride1, data2 as _, stride2, n)
+                }
+
+                unsafe fn sort_index(p: *mut usize, data: *const Self, stride: usize, n: usize) {
+                    sys::[<$sort _index>](p, data as _, stride, n)
+                }
+
+                unsafe fn smallest(
+                    dest: *mut Self,
+                    k: usize,
+                    src: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> i32 {
+                    sys::[<$sort _smallest>](dest as _, k, src as _, stride, n)
+                }
+
+                unsafe fn largest(
+                    dest: *mut Self,
+                    k: usize,
+                    src: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> i32 {
+                    sys::[<$sort _largest>](dest as _, k, src as _, stride, n)
+                }
+
+                unsafe fn smallest_index(
+                    p: *mut usize,
+                    k: usize,
+                    src: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> i32 {
+                    sys::[<$sort _smallest_index>](p, k, src as _, stride, n)
+                }
+
+                unsafe fn largest_index(
+                    p: *mut usize,
+                    k: usize,
+                    src: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> i32 {
+                    sys::[<$sort _largest_index>](p, k, src as _, stride, n)
+                }
+            }
+        }
+    };
+}
+
+sort_element!(f64, gsl_sort, gsl_sort2);
+sort_element!(f32, gsl_sort_float, gsl_sort2_float);
+sort_element!(i32, gsl_sort_int, gsl_sort2_int);
+sort_element!(u32, gsl_sort_uint, gsl_sort2_uint);
+sort_element!(i16, gsl_sort_short, gsl_sort2_short);
+sort_element!(u16, gsl_sort_ushort, gsl_sort2_ushort);
+#[cfg(gsl_char_signed)]
+sort_element!(i8, gsl_sort_char, gsl_sort2_char);
+sort_element!(u8, gsl_sort_uchar, gsl_sort2_uchar);
+#[cfg(gsl_long_64)]
+sort_element!(i64, gsl_sort_long, gsl_sort2_long);
+#[cfg(gsl_long_64)]
+sort_element!(u64, gsl_sort_ulong, gsl_sort2_ulong);
+
+#[test]
+fn sort_float() {
+    let mut data = [3.5f32, -1., 2., 0.25];
+    let mut other = [0f32, 1., 2., 3.];
+    vectors::sort2(&mut data, 1, &mut other, 1, 4);
+    assert_eq!(data, [-1., 0.25, 2., 3.5]);
+    assert_eq!(other, [1., 3., 2., 0.]);
+
+    let mut p = [0; 4];
+    vectors::sort_index(&mut p, &[5u8, 1, 3, 2], 1, 4);
+    assert_eq!(p, [1, 3, 2, 0]);
+
+    let mut largest = [0f32; 2];
+    assert_eq!(
+        select::sort_largest(&mut largest, 2, &[1f32, 7., 3., 5.], 1),
+        ::Value::Success
+    );
+    assert_eq!(largest, [7., 5.]);
+
+    let mut smallest = [0.; 2];
+    assert_eq!(
+        select::sort_smallest(&mut smallest, 2, &[4., 0., 1., 0., 3.], 2),
+        ::Value::Success
+    );
+    assert_eq!(smallest, [1., 3.]);
+}
+
+#[test]
+#[should_panic]
+fn sort_overflowing_stride() {
+    // An unchecked (n - 1) * stride would wrap around to 0, which is in bounds.
+    vectors::sort(&mut [1., 0.], 1 << (usize::BITS - 1), 3);
+}
+
+#[test]
+#[should_panic]
+fn sort_index_zero_stride() {
+    vectors::sort_index(&mut [0; 2], &[1., 0.], 0, 2);
+}
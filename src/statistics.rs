@@ -27,6 +27,13 @@ returned, since the maximum or minimum value is undefined. For functions which r
 The median and percentile functions described in this section operate on sorted data. For convenience we use quantiles, measured on a
 scale of 0 to 1, instead of percentiles (which use a scale of 0 to 100).
 
+## Bounds-checked API
+
+The free functions of this module follow the C interface and take the stride and number of elements explicitly. They panic
+if these would read past the end of the data. The [`Statistics`](trait.Statistics.html) trait provides the same functions as
+methods on slices, [`Strided`](struct.Strided.html) views and GSL vectors, for all the element types GSL supports, and
+[`Summary`](struct.Summary.html) computes the usual descriptive statistics in one go.
+
 ## References and Further Reading
 
 The standard reference for almost any topic in statistics is the multi-volume Advanced Theory of Statistics by Kendall and Stuart.
@@ -44,6 +51,10 @@ Review of Particle Properties R.M. Barnett et al., Physical Review D54, 1 (1996)
 The Review of Particle Physics is available online at the website http://pdg.lbl.gov/.
 !*/
 
+use crate::paste::paste;
+use crate::Value;
+use types::GslVector;
+
 /// This function returns the arithmetic mean of data, a dataset of length n with stride stride. The
 /// arithmetic mean, or sample mean, is denoted by \Hat\mu and defined as,
 ///
@@ -53,6 +64,7 @@ The Review of Particle Physics is available online at the website http://pdg.lbl
 /// the variance of \Hat\mu is \sigma^2 / N.
 #[doc(alias = "gsl_stats_mean")]
 pub fn mean(data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_mean(data.as_ptr(), stride, n) }
 }
 
@@ -70,6 +82,7 @@ pub fn mean(data: &[f64], stride: usize, n: usize) -> f64 {
 /// mean then you can pass it directly to gsl_stats_variance_m.
 #[doc(alias = "gsl_stats_variance")]
 pub fn variance(data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_variance(data.as_ptr(), stride, n) }
 }
 
@@ -79,6 +92,7 @@ pub fn variance(data: &[f64], stride: usize, n: usize) -> f64 {
 /// \Hat\sigma^2 = (1/(N-1)) \sum (x_i - mean)^2
 #[doc(alias = "gsl_stats_variance_m")]
 pub fn variance_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_variance_m(data.as_ptr(), stride, n, mean) }
 }
 
@@ -86,6 +100,7 @@ pub fn variance_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
 /// square root of the corresponding variance functions above.
 #[doc(alias = "gsl_stats_sd")]
 pub fn sd(data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_sd(data.as_ptr(), stride, n) }
 }
 
@@ -93,6 +108,7 @@ pub fn sd(data: &[f64], stride: usize, n: usize) -> f64 {
 /// square root of the corresponding variance functions above.
 #[doc(alias = "gsl_stats_sd_m")]
 pub fn sd_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_sd_m(data.as_ptr(), stride, n, mean) }
 }
 
@@ -103,6 +119,7 @@ pub fn sd_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
 /// TSS =  \sum (x_i - mean)^2
 #[doc(alias = "gsl_stats_tss")]
 pub fn tss(data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_tss(data.as_ptr(), stride, n) }
 }
 
@@ -113,6 +130,7 @@ pub fn tss(data: &[f64], stride: usize, n: usize) -> f64 {
 /// TSS =  \sum (x_i - mean)^2
 #[doc(alias = "gsl_stats_tss_m")]
 pub fn tss_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_tss_m(data.as_ptr(), stride, n, mean) }
 }
 
@@ -124,6 +142,7 @@ pub fn tss_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
 /// \Hat\sigma^2 = (1/N) \sum (x_i - \mu)^2
 #[doc(alias = "gsl_stats_variance_with_fixed_mean")]
 pub fn variance_with_fixed_mean(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_variance_with_fixed_mean(data.as_ptr(), stride, n, mean) }
 }
 
@@ -131,6 +150,7 @@ pub fn variance_with_fixed_mean(data: &[f64], stride: usize, n: usize, mean: f64
 /// result is the square root of the corresponding variance function.
 #[doc(alias = "gsl_stats_sd_with_fixed_mean")]
 pub fn sd_with_fixed_mean(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_sd_with_fixed_mean(data.as_ptr(), stride, n, mean) }
 }
 
@@ -144,6 +164,7 @@ pub fn sd_with_fixed_mean(data: &[f64], stride: usize, n: usize, mean: f64) -> f
 /// mean of data via a call to gsl_stats_mean.
 #[doc(alias = "gsl_stats_absdev")]
 pub fn absdev(data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_absdev(data.as_ptr(), stride, n) }
 }
 
@@ -157,6 +178,7 @@ pub fn absdev(data: &[f64], stride: usize, n: usize) -> f64 {
 /// zero, or the median).
 #[doc(alias = "gsl_stats_absdev_m")]
 pub fn absdev_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_absdev_m(data.as_ptr(), stride, n, mean) }
 }
 
@@ -172,6 +194,7 @@ pub fn absdev_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
 /// and [`sd`].
 #[doc(alias = "gsl_stats_skew")]
 pub fn skew(data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_skew(data.as_ptr(), stride, n) }
 }
 
@@ -184,6 +207,7 @@ pub fn skew(data: &[f64], stride: usize, n: usize) -> f64 {
 /// and want to avoid recomputing them.
 #[doc(alias = "gsl_stats_skew_m_sd")]
 pub fn skew_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_skew_m_sd(data.as_ptr(), stride, n, mean, sd) }
 }
 
@@ -196,6 +220,7 @@ pub fn skew_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64) -> f
 /// is normalized to zero for a Gaussian distribution.
 #[doc(alias = "gsl_stats_kurtosis")]
 pub fn kurtosis(data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_kurtosis(data.as_ptr(), stride, n) }
 }
 
@@ -208,6 +233,7 @@ pub fn kurtosis(data: &[f64], stride: usize, n: usize) -> f64 {
 /// want to avoid recomputing them.
 #[doc(alias = "gsl_stats_kurtosis_m_sd")]
 pub fn kurtosis_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_kurtosis_m_sd(data.as_ptr(), stride, n, mean, sd) }
 }
 
@@ -218,6 +244,7 @@ pub fn kurtosis_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64)
 ///        \sum_{i = 1}^{n} (x_{i} - \Hat\mu) (x_{i} - \Hat\mu)}
 #[doc(alias = "gsl_stats_lag1_autocorrelation")]
 pub fn lag1_autocorrelation(data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_lag1_autocorrelation(data.as_ptr(), stride, n) }
 }
 
@@ -225,6 +252,7 @@ pub fn lag1_autocorrelation(data: &[f64], stride: usize, n: usize) -> f64 {
 /// the mean mean.
 #[doc(alias = "gsl_stats_lag1_autocorrelation_m")]
 pub fn lag1_autocorrelation_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_lag1_autocorrelation_m(data.as_ptr(), stride, n, mean) }
 }
 
@@ -234,6 +262,8 @@ pub fn lag1_autocorrelation_m(data: &[f64], stride: usize, n: usize, mean: f64)
 /// covar = (1/(n - 1)) \sum_{i = 1}^{n} (x_i - \Hat x) (y_i - \Hat y)
 #[doc(alias = "gsl_stats_covariance")]
 pub fn covariance(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize, n: usize) -> f64 {
+    check(data1.len(), stride1, n);
+    check(data2.len(), stride2, n);
     unsafe { sys::gsl_stats_covariance(data1.as_ptr(), stride1, data2.as_ptr(), stride2, n) }
 }
 
@@ -250,6 +280,8 @@ pub fn covariance_m(
     mean1: f64,
     mean2: f64,
 ) -> f64 {
+    check(data1.len(), stride1, n);
+    check(data2.len(), stride2, n);
     unsafe {
         sys::gsl_stats_covariance_m(
             data1.as_ptr(),
@@ -273,6 +305,8 @@ pub fn covariance_m(
 ///     }
 #[doc(alias = "gsl_stats_correlation")]
 pub fn correlation(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize, n: usize) -> f64 {
+    check(data1.len(), stride1, n);
+    check(data2.len(), stride2, n);
     unsafe { sys::gsl_stats_correlation(data1.as_ptr(), stride1, data2.as_ptr(), stride2, n) }
 }
 
@@ -290,6 +324,12 @@ pub fn spearman(
     n: usize,
     work: &mut [f64],
 ) -> f64 {
+    check(data1.len(), stride1, n);
+    check(data2.len(), stride2, n);
+    assert!(
+        work.len() >= 2 * n,
+        "work must hold at least 2 * n elements"
+    );
     unsafe {
         sys::gsl_stats_spearman(
             data1.as_ptr(),
@@ -308,6 +348,8 @@ pub fn spearman(
 /// \Hat\mu = (\sum w_i x_i) / (\sum w_i)
 #[doc(alias = "gsl_stats_wmean")]
 pub fn wmean(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wmean(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
@@ -322,6 +364,8 @@ pub fn wmean(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -
 /// when there are N equal non-zero weights.
 #[doc(alias = "gsl_stats_wvariance")]
 pub fn wvariance(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wvariance(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
@@ -336,6 +380,8 @@ pub fn wvariance_m(
     n: usize,
     wmean: f64,
 ) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wvariance_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
 }
 
@@ -343,6 +389,8 @@ pub fn wvariance_m(
 /// square root of the corresponding variance function [`wvariance`] above.
 #[doc(alias = "gsl_stats_wsd")]
 pub fn wsd(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wsd(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
@@ -350,6 +398,8 @@ pub fn wsd(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) ->
 /// [`wvariance_m`] above.
 #[doc(alias = "gsl_stats_wsd_m")]
 pub fn wsd_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize, wmean: f64) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wsd_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
 }
 
@@ -367,6 +417,8 @@ pub fn wvariance_with_fixed_mean(
     n: usize,
     mean: f64,
 ) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe {
         sys::gsl_stats_wvariance_with_fixed_mean(
             w.as_ptr(),
@@ -390,6 +442,8 @@ pub fn wsd_with_fixed_mean(
     n: usize,
     mean: f64,
 ) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe {
         sys::gsl_stats_wsd_with_fixed_mean(w.as_ptr(), wstride, data.as_ptr(), stride, n, mean)
     }
@@ -402,6 +456,8 @@ pub fn wsd_with_fixed_mean(
 /// TSS =  \sum w_i (x_i - wmean)^2
 #[doc(alias = "gsl_stats_wtss")]
 pub fn wtss(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wtss(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
@@ -412,6 +468,8 @@ pub fn wtss(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) ->
 /// TSS =  \sum w_i (x_i - wmean)^2
 #[doc(alias = "gsl_stats_wtss_m")]
 pub fn wtss_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize, wmean: f64) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wtss_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
 }
 
@@ -421,6 +479,8 @@ pub fn wtss_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize,
 /// absdev = (\sum w_i |x_i - \Hat\mu|) / (\sum w_i)
 #[doc(alias = "gsl_stats_wabsdev")]
 pub fn wabsdev(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wabsdev(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
@@ -434,6 +494,8 @@ pub fn wabsdev_m(
     n: usize,
     wmean: f64,
 ) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wabsdev_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
 }
 
@@ -442,6 +504,8 @@ pub fn wabsdev_m(
 /// skew = (\sum w_i ((x_i - \Hat x)/\Hat \sigma)^3) / (\sum w_i)
 #[doc(alias = "gsl_stats_wskew")]
 pub fn wskew(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wskew(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
@@ -457,6 +521,8 @@ pub fn wskew_m_sd(
     wmean: f64,
     wsd: f64,
 ) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wskew_m_sd(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean, wsd) }
 }
 
@@ -465,6 +531,8 @@ pub fn wskew_m_sd(
 /// kurtosis = ((\sum w_i ((x_i - \Hat x)/\Hat \sigma)^4) / (\sum w_i)) - 3
 #[doc(alias = "gsl_stats_wkurtosis")]
 pub fn wkurtosis(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe { sys::gsl_stats_wkurtosis(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
@@ -480,6 +548,8 @@ pub fn wkurtosis_m_sd(
     wmean: f64,
     wsd: f64,
 ) -> f64 {
+    check(data.len(), stride, n);
+    check(w.len(), wstride, n);
     unsafe {
         sys::gsl_stats_wkurtosis_m_sd(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean, wsd)
     }
@@ -492,6 +562,7 @@ pub fn wkurtosis_m_sd(
 /// apply fabs or abs to your data before calling this function.
 #[doc(alias = "gsl_stats_max")]
 pub fn max(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_nonempty(data.len(), stride, n);
     unsafe { sys::gsl_stats_max(data.as_ptr(), stride, n) }
 }
 
@@ -502,6 +573,7 @@ pub fn max(data: &[f64], stride: usize, n: usize) -> f64 {
 /// apply fabs or abs to your data before calling this function.
 #[doc(alias = "gsl_stats_min")]
 pub fn min(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_nonempty(data.len(), stride, n);
     unsafe { sys::gsl_stats_min(data.as_ptr(), stride, n) }
 }
 
@@ -510,6 +582,7 @@ pub fn min(data: &[f64], stride: usize, n: usize) -> f64 {
 /// Returns `(min, max)`.
 #[doc(alias = "gsl_stats_minmax")]
 pub fn minmax(data: &[f64], stride: usize, n: usize) -> (f64, f64) {
+    check_nonempty(data.len(), stride, n);
     let mut min = 0.;
     let mut max = 0.;
     unsafe { sys::gsl_stats_minmax(&mut min, &mut max, data.as_ptr(), stride, n) };
@@ -521,6 +594,7 @@ pub fn minmax(data: &[f64], stride: usize, n: usize) -> (f64, f64) {
 /// for all j. When there are several equal maximum elements then the first one is chosen.
 #[doc(alias = "gsl_stats_max_index")]
 pub fn max_index(data: &[f64], stride: usize, n: usize) -> usize {
+    check_nonempty(data.len(), stride, n);
     unsafe { sys::gsl_stats_max_index(data.as_ptr(), stride, n) }
 }
 
@@ -529,6 +603,7 @@ pub fn max_index(data: &[f64], stride: usize, n: usize) -> usize {
 /// for all j. When there are several equal minimum elements then the first one is chosen.
 #[doc(alias = "gsl_stats_min_index")]
 pub fn min_index(data: &[f64], stride: usize, n: usize) -> usize {
+    check_nonempty(data.len(), stride, n);
     unsafe { sys::gsl_stats_min_index(data.as_ptr(), stride, n) }
 }
 
@@ -538,6 +613,7 @@ pub fn min_index(data: &[f64], stride: usize, n: usize) -> usize {
 /// Returns `(min_index, max_index)`.
 #[doc(alias = "gsl_stats_minmax_index")]
 pub fn minmax_index(data: &[f64], stride: usize, n: usize) -> (usize, usize) {
+    check_nonempty(data.len(), stride, n);
     let mut min_index = 0;
     let mut max_index = 0;
     unsafe {
@@ -556,6 +632,7 @@ pub fn minmax_index(data: &[f64], stride: usize, n: usize) -> (usize, usize) {
 /// interpolation this function always returns a floating-point number, even for integer data types.
 #[doc(alias = "gsl_stats_median_from_sorted_data")]
 pub fn median_from_sorted_data(data: &[f64], stride: usize, n: usize) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_median_from_sorted_data(data.as_ptr(), stride, n) }
 }
 
@@ -579,5 +656,906 @@ pub fn median_from_sorted_data(data: &[f64], stride: usize, n: usize) -> f64 {
 /// returns a floating-point number, even for integer data types.
 #[doc(alias = "gsl_stats_quantile_from_sorted_data")]
 pub fn quantile_from_sorted_data(data: &[f64], stride: usize, n: usize, f: f64) -> f64 {
+    check(data.len(), stride, n);
     unsafe { sys::gsl_stats_quantile_from_sorted_data(data.as_ptr(), stride, n, f) }
 }
+
+/// This function computes the median absolute deviation (MAD) of data, a dataset of length n with
+/// stride stride, scaled by 1.4826 so that it is a consistent estimator of the standard deviation
+/// for Gaussian data:
+///
+/// MAD = 1.4826 \times median { | x_i - median(x) | }
+///
+/// Additional workspace of size n is required in work.
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad")]
+pub fn mad(data: &[f64], stride: usize, n: usize, work: &mut [f64]) -> f64 {
+    check(data.len(), stride, n);
+    assert!(work.len() >= n, "work must hold at least n elements");
+    unsafe { sys::gsl_stats_mad(data.as_ptr(), stride, n, work.as_mut_ptr()) }
+}
+
+/// This function computes the median absolute deviation of data without the 1.4826 scale factor.
+/// Additional workspace of size n is required in work.
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad0")]
+pub fn mad0(data: &[f64], stride: usize, n: usize, work: &mut [f64]) -> f64 {
+    check(data.len(), stride, n);
+    assert!(work.len() >= n, "work must hold at least n elements");
+    unsafe { sys::gsl_stats_mad0(data.as_ptr(), stride, n, work.as_mut_ptr()) }
+}
+
+/// This function computes the S_n statistic of Croux and Rousseeuw for sorted_data, a dataset of
+/// length n with stride stride:
+///
+/// S_n = c \times med_i { med_j | x_i - x_j | }
+///
+/// where c is a correction factor making it a consistent estimator of the standard deviation for
+/// Gaussian data. Like the MAD it has a 50% breakdown point, but it is more efficient and does not
+/// assume the data to be symmetric. The elements of the array must be in ascending numerical order.
+/// Additional workspace of size n is required in work.
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Sn_from_sorted_data")]
+pub fn Sn_from_sorted_data(sorted_data: &[f64], stride: usize, n: usize, work: &mut [f64]) -> f64 {
+    check(sorted_data.len(), stride, n);
+    assert!(work.len() >= n, "work must hold at least n elements");
+    unsafe {
+        sys::gsl_stats_Sn_from_sorted_data(sorted_data.as_ptr(), stride, n, work.as_mut_ptr())
+    }
+}
+
+/// This function computes the Q_n statistic of Croux and Rousseeuw for sorted_data, a dataset of
+/// length n with stride stride:
+///
+/// Q_n = d \times { | x_i - x_j |, i < j }_{(k)}
+///
+/// the k-th order statistic of the pairwise distances, where k is roughly a quarter of the number
+/// of pairs and d is a correction factor for consistency at the Gaussian. The elements of the array
+/// must be in ascending numerical order. Additional workspace of size 3n is required in work and of
+/// size 5n in work_int.
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Qn_from_sorted_data")]
+pub fn Qn_from_sorted_data(
+    sorted_data: &[f64],
+    stride: usize,
+    n: usize,
+    work: &mut [f64],
+    work_int: &mut [i32],
+) -> f64 {
+    check(sorted_data.len(), stride, n);
+    assert!(
+        work.len() >= 3 * n,
+        "work must hold at least 3 * n elements"
+    );
+    assert!(
+        work_int.len() >= 5 * n,
+        "work_int must hold at least 5 * n elements"
+    );
+    unsafe {
+        sys::gsl_stats_Qn_from_sorted_data(
+            sorted_data.as_ptr(),
+            stride,
+            n,
+            work.as_mut_ptr(),
+            work_int.as_mut_ptr(),
+        )
+    }
+}
+
+/// This function returns the trimmed mean of sorted_data, a dataset of length n with stride stride:
+/// the mean of the data once the smallest and largest trim fraction of the elements (trim being
+/// between 0 and 0.5) have been discarded. The elements of the array must be in ascending numerical
+/// order.
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_trmean_from_sorted_data")]
+pub fn trmean_from_sorted_data(trim: f64, sorted_data: &[f64], stride: usize, n: usize) -> f64 {
+    check(sorted_data.len(), stride, n);
+    unsafe { sys::gsl_stats_trmean_from_sorted_data(trim, sorted_data.as_ptr(), stride, n) }
+}
+
+/// This function returns the Gastwirth location estimator of sorted_data, a dataset of length n
+/// with stride stride:
+///
+/// \hat\mu = 0.3 \times Q_{1/3} + 0.4 \times Q_{1/2} + 0.3 \times Q_{2/3}
+///
+/// where Q_p is the p-quantile of the data. The elements of the array must be in ascending
+/// numerical order.
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_gastwirth_from_sorted_data")]
+pub fn gastwirth_from_sorted_data(sorted_data: &[f64], stride: usize, n: usize) -> f64 {
+    check(sorted_data.len(), stride, n);
+    unsafe { sys::gsl_stats_gastwirth_from_sorted_data(sorted_data.as_ptr(), stride, n) }
+}
+
+/// This function returns the weighted quantile f (between 0 and 1) of data with the non-negative
+/// weights w, which don't need to be sorted. It is the smallest element x_k, in ascending order,
+/// whose cumulative weight reaches f times the total weight. When the cumulative weight is exactly
+/// equal to it, the average of x_k and the next element is returned, so that with equal weights
+/// the weighted median is the usual median.
+///
+/// Returns `Value::BadLength` if data is empty or data and w don't have the same length, and
+/// `Value::Domain` if f is out of range, a weight is negative or all of them are zero.
+pub fn weighted_quantile(data: &[f64], w: &[f64], f: f64) -> Result<f64, Value> {
+    let n = data.len();
+    if n == 0 || w.len() != n {
+        return Err(Value::BadLength);
+    }
+    if !(0. ..=1.).contains(&f) || w.iter().any(|&wi| wi.is_nan() || wi < 0.) {
+        return Err(Value::Domain);
+    }
+    let total: f64 = w.iter().sum();
+    if total <= 0. {
+        return Err(Value::Domain);
+    }
+    let mut p = vec![0; n];
+    ::sort::vectors::sort_index(&mut p, data, 1, n);
+
+    let target = f * total;
+    let mut cumulative = 0.;
+    for (k, &i) in p.iter().enumerate() {
+        if w[i] == 0. {
+            continue;
+        }
+        cumulative += w[i];
+        if cumulative >= target {
+            if cumulative == target {
+                if let Some(&next) = p[k + 1..].iter().find(|&&j| w[j] > 0.) {
+                    return Ok((data[i] + data[next]) / 2.);
+                }
+            }
+            return Ok(data[i]);
+        }
+    }
+    Ok(data[p[n - 1]])
+}
+
+/// This function returns the weighted median of data with the non-negative weights w. It is the
+/// weighted quantile 0.5, see [`weighted_quantile`](fn.weighted_quantile.html).
+pub fn weighted_median(data: &[f64], w: &[f64]) -> Result<f64, Value> {
+    weighted_quantile(data, w, 0.5)
+}
+
+fn check(len: usize, stride: usize, n: usize) {
+    assert!(stride > 0, "stride must be positive");
+    assert!(
+        n == 0 || (n - 1).checked_mul(stride).map_or(false, |last| last < len),
+        "n elements with this stride overrun the data"
+    );
+}
+
+fn check_nonempty(len: usize, stride: usize, n: usize) {
+    assert!(n > 0, "n must be positive");
+    check(len, stride, n);
+}
+
+/// A bounds-checked strided view of a slice: the elements `data[0]`, `data[stride]`, `data[2 * stride]`, ...
+///
+/// ```
+/// use rgsl::statistics::{Statistics, Strided};
+///
+/// // the even and odd elements of an interleaved buffer
+/// let data = [1., 10., 2., 20., 3., 30.];
+/// assert_eq!(Strided::new(&data, 2).unwrap().mean(), 2.);
+/// assert_eq!(Strided::new(&data[1..], 2).unwrap().mean(), 20.);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Strided<'a, T> {
+    data: &'a [T],
+    stride: usize,
+    n: usize,
+}
+
+impl<'a, T> Strided<'a, T> {
+    /// Creates a view of every `stride`-th element of `data`, starting with the first one.
+    ///
+    /// Returns `Value::Invalid` if `stride` is zero.
+    pub fn new(data: &'a [T], stride: usize) -> Result<Strided<'a, T>, Value> {
+        if stride == 0 {
+            return Err(Value::Invalid);
+        }
+        Ok(Strided {
+            data,
+            stride,
+            n: (data.len() + stride - 1) / stride,
+        })
+    }
+
+    /// Creates a view of the `n` elements `data[0]`, `data[stride]`, ..., `data[(n - 1) * stride]`.
+    ///
+    /// Returns `Value::Invalid` if `stride` is zero and `Value::BadLength` if the last element is past the end of `data`.
+    pub fn with_len(data: &'a [T], stride: usize, n: usize) -> Result<Strided<'a, T>, Value> {
+        let view = Strided::new(data, stride)?;
+        if n > view.n {
+            return Err(Value::BadLength);
+        }
+        Ok(Strided { n, ..view })
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+}
+
+/// The element types supported by the [`Statistics`](trait.Statistics.html) methods. Each of them maps to one family of
+/// `gsl_stats_*` functions (`gsl_stats_float_*` for `f32`, `gsl_stats_int_*` for `i32`, `gsl_stats_uchar_*` for `u8`, ...).
+///
+/// `i8` maps to the `gsl_stats_char_*` functions. It is only available on the targets where C `char` is known to be
+/// signed (x86, Apple and Windows): elsewhere, such as on ARM Linux, GSL would read negative values as large positive
+/// ones. `i64` and `u64` map to the `gsl_stats_long_*` and `gsl_stats_ulong_*` functions and are only available where
+/// C `long` has 64 bits.
+///
+/// # Safety
+///
+/// The hidden methods forward raw pointers to GSL; implementations must call the functions matching the element type.
+pub unsafe trait StatsElement: Copy + PartialOrd {
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+    #[doc(hidden)]
+    unsafe fn mean(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    unsafe fn variance_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn tss_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn variance_with_fixed_mean(
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        mean: f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn absdev_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn skew_m_sd(data: *const Self, stride: usize, n: usize, mean: f64, sd: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn kurtosis_m_sd(data: *const Self, stride: usize, n: usize, mean: f64, sd: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn lag1_autocorrelation_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn covariance(
+        data1: *const Self,
+        stride1: usize,
+        data2: *const Self,
+        stride2: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn correlation(
+        data1: *const Self,
+        stride1: usize,
+        data2: *const Self,
+        stride2: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn spearman(
+        data1: *const Self,
+        stride1: usize,
+        data2: *const Self,
+        stride2: usize,
+        n: usize,
+        work: *mut f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn minmax_index(data: *const Self, stride: usize, n: usize) -> (usize, usize);
+    #[doc(hidden)]
+    unsafe fn quantile_from_sorted_data(data: *const Self, stride: usize, n: usize, f: f64) -> f64;
+}
+
+macro_rules! stats_element {
+    ($ty:ty, $name:ident) => {
+        paste! {
+            unsafe impl StatsElement for $ty {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                unsafe fn mean(data: *const Self, stride: usize, n: usize) -> f64 {
+                    sys::[<$name _mean>](data as _, stride, n)
+                }
+
+                unsafe fn variance_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64 {
+                    sys::[<$name _variance_m>](data as _, stride, n, mean)
+                }
+
+                unsafe fn tss_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64 {
+                    sys::[<$name _tss_m>](data as _, stride, n, mean)
+                }
+
+                unsafe fn variance_with_fixed_mean(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                ) -> f64 {
+                    sys::[<$name _variance_with_fixed_mean>](data as _, stride, n, mean)
+                }
+
+                unsafe fn absdev_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64 {
+                    sys::[<$name _absdev_m>](data as _, stride, n, mean)
+                }
+
+                unsafe fn skew_m_sd(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                    sd: f64,
+                ) -> f64 {
+                    sys::[<$name _skew_m_sd>](data as _, stride, n, mean, sd)
+                }
+
+                unsafe fn kurtosis_m_sd(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                    sd: f64,
+                ) -> f64 {
+                    sys::[<$name _kurtosis_m_sd>](data as _, stride, n, mean, sd)
+                }
+
+                unsafe fn lag1_autocorrelation_m(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                ) -> f64 {
+                    sys::[<$name _lag1_autocorrelation_m>](data as _, stride, n, mean)
+                }
+
+                unsafe fn covariance(
+                    data1: *const Self,
+                    stride1: usize,
+                    data2: *const Self,
+                    stride2: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$name _covariance>](data1 as _, stride1, data2 as _, stride2, n)
+                }
+
+                unsafe fn correlation(
+                    data1: *const Self,
+                    stride1: usize,
+                    data2: *const Self,
+                    stride2: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$name _correlation>](data1 as _, stride1, data2 as _, stride2, n)
+                }
+
+                unsafe fn spearman(
+                    data1: *const Self,
+                    stride1: usize,
+                    data2: *const Self,
+                    stride2: usize,
+                    n: usize,
+                    work: *mut f64,
+                ) -> f64 {
+                    sys::[<$name _spearman>](data1 as _, stride1, data2 as _, stride2, n, work)
+                }
+
+                unsafe fn minmax_index(data: *const Self, stride: usize, n: usize) -> (usize, usize) {
+                    let mut min_index = 0;
+                    let mut max_index = 0;
+                    sys::[<$name _minmax_index>](&mut min_index, &mut max_index, data as _, stride, n);
+                    (min_index, max_index)
+                }
+
+                unsafe fn quantile_from_sorted_data(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    f: f64,
+                ) -> f64 {
+                    sys::[<$name _quantile_from_sorted_data>](data as _, stride, n, f)
+                }
+            }
+        }
+    };
+}
+
+stats_element!(f64, gsl_stats);
+stats_element!(f32, gsl_stats_float);
+stats_element!(i32, gsl_stats_int);
+stats_element!(u32, gsl_stats_uint);
+stats_element!(i16, gsl_stats_short);
+stats_element!(u16, gsl_stats_ushort);
+// C `char` is signed on these targets.
+#[cfg(gsl_char_signed)]
+stats_element!(i8, gsl_stats_char);
+stats_element!(u8, gsl_stats_uchar);
+#[cfg(gsl_long_64)]
+stats_element!(i64, gsl_stats_long);
+#[cfg(gsl_long_64)]
+stats_element!(u64, gsl_stats_ulong);
+
+/// A dataset the [`Statistics`](trait.Statistics.html) methods can be computed on: a slice, a `Vec`, a
+/// [`Strided`](struct.Strided.html) view or a GSL vector (or vector view).
+///
+/// The implementations guarantee that the pointer, stride and length they return describe valid elements, which is what
+/// makes the `Statistics` methods safe.
+///
+/// # Safety
+///
+/// `stats_parts` must return a pointer to at least `(n - 1) * stride + 1` valid elements (or `n == 0`), which stay alive as
+/// long as `self` is borrowed.
+pub unsafe trait StatsData {
+    type Elem: StatsElement;
+
+    #[doc(hidden)]
+    fn stats_parts(&self) -> (*const Self::Elem, usize, usize);
+}
+
+unsafe impl<T: StatsElement> StatsData for [T] {
+    type Elem = T;
+
+    fn stats_parts(&self) -> (*const T, usize, usize) {
+        (self.as_ptr(), 1, self.len())
+    }
+}
+
+unsafe impl<T: StatsElement> StatsData for Vec<T> {
+    type Elem = T;
+
+    fn stats_parts(&self) -> (*const T, usize, usize) {
+        (self.as_ptr(), 1, self.len())
+    }
+}
+
+unsafe impl<'a, T: StatsElement> StatsData for Strided<'a, T> {
+    type Elem = T;
+
+    fn stats_parts(&self) -> (*const T, usize, usize) {
+        (self.data.as_ptr(), self.stride, self.n)
+    }
+}
+
+macro_rules! stats_vector {
+    ($vector:ident, $view:ident, $ty:ty) => {
+        unsafe impl StatsData for ::$vector {
+            type Elem = $ty;
+
+            fn stats_parts(&self) -> (*const $ty, usize, usize) {
+                let ptr = GslVector::as_ptr(self);
+                if ptr.is_null() {
+                    (ptr, 1, 0)
+                } else {
+                    (ptr, GslVector::stride(self), GslVector::len(self))
+                }
+            }
+        }
+
+        unsafe impl<'a> StatsData for ::$view<'a> {
+            type Elem = $ty;
+
+            fn stats_parts(&self) -> (*const $ty, usize, usize) {
+                let mut parts = (::std::ptr::null(), 1, 0);
+                self.vector(|v| {
+                    if let Some(v) = v {
+                        parts = v.stats_parts();
+                    }
+                });
+                parts
+            }
+        }
+    };
+}
+
+stats_vector!(VectorF64, VectorF64View, f64);
+stats_vector!(VectorF32, VectorF32View, f32);
+stats_vector!(VectorI32, VectorI32View, i32);
+stats_vector!(VectorU32, VectorU32View, u32);
+#[cfg(gsl_long_64)]
+stats_vector!(VectorI64, VectorI64View, i64);
+#[cfg(gsl_long_64)]
+stats_vector!(VectorU64, VectorU64View, u64);
+stats_vector!(VectorI16, VectorI16View, i16);
+stats_vector!(VectorU16, VectorU16View, u16);
+#[cfg(gsl_char_signed)]
+stats_vector!(VectorI8, VectorI8View, i8);
+stats_vector!(VectorU8, VectorU8View, u8);
+
+/// Returns a sorted copy of the data, or `None` if it contains NaN, which has no place in the order.
+fn sorted_copy<D: StatsData + ?Sized>(data: &D) -> Option<Vec<D::Elem>> {
+    let (ptr, stride, n) = data.stats_parts();
+    let mut v = (0..n)
+        .map(|i| unsafe { *ptr.add(i * stride) })
+        .collect::<Vec<_>>();
+    if v.iter().any(|x| x.partial_cmp(x).is_none()) {
+        return None;
+    }
+    v.sort_by(|a, b| a.partial_cmp(b).expect("NaN was filtered out"));
+    Some(v)
+}
+
+fn quantile_of_sorted<T: StatsElement>(sorted: &[T], f: f64) -> f64 {
+    if sorted.is_empty() || !(0. ..=1.).contains(&f) {
+        return f64::NAN;
+    }
+    unsafe { T::quantile_from_sorted_data(sorted.as_ptr(), 1, sorted.len(), f) }
+}
+
+/// Bounds-checked statistics over any [`StatsData`](trait.StatsData.html), whatever its element type.
+///
+/// Unlike the free functions of this module, the number of elements and the stride are taken from the data itself, so
+/// they can't overrun it:
+///
+/// ```
+/// use rgsl::statistics::Statistics;
+///
+/// let data = [17.2, 18.1, 16.5, 18.3, 12.6];
+/// assert_eq!(format!("{:.4}", data.mean()), "16.5400");
+/// assert_eq!(format!("{:.4}", data.sd()), "2.3338");
+/// assert_eq!(data.median(), 17.2);
+///
+/// let counts: Vec<u32> = vec![3, 1, 4, 1, 5];
+/// assert_eq!(counts.maximum(), Some(5));
+/// ```
+pub trait Statistics: StatsData {
+    /// Returns the arithmetic mean of the data.
+    #[doc(alias = "gsl_stats_mean")]
+    fn mean(&self) -> f64 {
+        let (ptr, stride, n) = self.stats_parts();
+        unsafe { Self::Elem::mean(ptr, stride, n) }
+    }
+
+    /// Returns the sample variance of the data, normalized by 1/(N-1).
+    #[doc(alias = "gsl_stats_variance")]
+    fn variance(&self) -> f64 {
+        self.variance_m(self.mean())
+    }
+
+    /// Returns the sample variance of the data relative to the given value of mean.
+    #[doc(alias = "gsl_stats_variance_m")]
+    fn variance_m(&self, mean: f64) -> f64 {
+        let (ptr, stride, n) = self.stats_parts();
+        unsafe { Self::Elem::variance_m(ptr, stride, n, mean) }
+    }
+
+    /// Returns the sample standard deviation of the data.
+    #[doc(alias = "gsl_stats_sd")]
+    fn sd(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Returns the sample standard deviation of the data relative to the given value of mean.
+    #[doc(alias = "gsl_stats_sd_m")]
+    fn sd_m(&self, mean: f64) -> f64 {
+        self.variance_m(mean).sqrt()
+    }
+
+    /// Returns the total sum of squares of the data about its mean.
+    #[doc(alias = "gsl_stats_tss")]
+    fn tss(&self) -> f64 {
+        self.tss_m(self.mean())
+    }
+
+    /// Returns the total sum of squares of the data about the given value of mean.
+    #[doc(alias = "gsl_stats_tss_m")]
+    fn tss_m(&self, mean: f64) -> f64 {
+        let (ptr, stride, n) = self.stats_parts();
+        unsafe { Self::Elem::tss_m(ptr, stride, n, mean) }
+    }
+
+    /// Returns the variance of the data for a known population mean, normalized by 1/N.
+    #[doc(alias = "gsl_stats_variance_with_fixed_mean")]
+    fn variance_with_fixed_mean(&self, mean: f64) -> f64 {
+        let (ptr, stride, n) = self.stats_parts();
+        unsafe { Self::Elem::variance_with_fixed_mean(ptr, stride, n, mean) }
+    }
+
+    /// Returns the standard deviation of the data for a known population mean.
+    #[doc(alias = "gsl_stats_sd_with_fixed_mean")]
+    fn sd_with_fixed_mean(&self, mean: f64) -> f64 {
+        self.variance_with_fixed_mean(mean).sqrt()
+    }
+
+    /// Returns the absolute deviation of the data from its mean.
+    #[doc(alias = "gsl_stats_absdev")]
+    fn absdev(&self) -> f64 {
+        self.absdev_m(self.mean())
+    }
+
+    /// Returns the absolute deviation of the data from the given value of mean.
+    #[doc(alias = "gsl_stats_absdev_m")]
+    fn absdev_m(&self, mean: f64) -> f64 {
+        let (ptr, stride, n) = self.stats_parts();
+        unsafe { Self::Elem::absdev_m(ptr, stride, n, mean) }
+    }
+
+    /// Returns the skewness of the data.
+    #[doc(alias = "gsl_stats_skew")]
+    fn skew(&self) -> f64 {
+        let mean = self.mean();
+        self.skew_m_sd(mean, self.sd_m(mean))
+    }
+
+    /// Returns the skewness of the data using the given values of the mean and standard deviation.
+    #[doc(alias = "gsl_stats_skew_m_sd")]
+    fn skew_m_sd(&self, mean: f64, sd: f64) -> f64 {
+        let (ptr, stride, n) = self.stats_parts();
+        unsafe { Self::Elem::skew_m_sd(ptr, stride, n, mean, sd) }
+    }
+
+    /// Returns the excess kurtosis of the data.
+    #[doc(alias = "gsl_stats_kurtosis")]
+    fn kurtosis(&self) -> f64 {
+        let mean = self.mean();
+        self.kurtosis_m_sd(mean, self.sd_m(mean))
+    }
+
+    /// Returns the excess kurtosis of the data using the given values of the mean and standard deviation.
+    #[doc(alias = "gsl_stats_kurtosis_m_sd")]
+    fn kurtosis_m_sd(&self, mean: f64, sd: f64) -> f64 {
+        let (ptr, stride, n) = self.stats_parts();
+        unsafe { Self::Elem::kurtosis_m_sd(ptr, stride, n, mean, sd) }
+    }
+
+    /// Returns the lag-1 autocorrelation of the data.
+    #[doc(alias = "gsl_stats_lag1_autocorrelation")]
+    fn lag1_autocorrelation(&self) -> f64 {
+        let (ptr, stride, n) = self.stats_parts();
+        if n == 0 {
+            return f64::NAN;
+        }
+        unsafe { Self::Elem::lag1_autocorrelation_m(ptr, stride, n, self.mean()) }
+    }
+
+    /// Returns the covariance of the data with `other`. Returns `Value::BadLength` if they don't have the same length.
+    #[doc(alias = "gsl_stats_covariance")]
+    fn covariance<O: StatsData<Elem = Self::Elem> + ?Sized>(
+        &self,
+        other: &O,
+    ) -> Result<f64, Value> {
+        let (ptr1, stride1, n) = self.stats_parts();
+        let (ptr2, stride2, n2) = other.stats_parts();
+        if n != n2 {
+            return Err(Value::BadLength);
+        }
+        Ok(unsafe { Self::Elem::covariance(ptr1, stride1, ptr2, stride2, n) })
+    }
+
+    /// Returns the Pearson correlation coefficient of the data with `other`. Returns `Value::BadLength` if they don't
+    /// have the same length.
+    #[doc(alias = "gsl_stats_correlation")]
+    fn correlation<O: StatsData<Elem = Self::Elem> + ?Sized>(
+        &self,
+        other: &O,
+    ) -> Result<f64, Value> {
+        let (ptr1, stride1, n) = self.stats_parts();
+        let (ptr2, stride2, n2) = other.stats_parts();
+        if n != n2 {
+            return Err(Value::BadLength);
+        }
+        Ok(unsafe { Self::Elem::correlation(ptr1, stride1, ptr2, stride2, n) })
+    }
+
+    /// Returns the Spearman rank correlation coefficient of the data with `other`. Returns `Value::BadLength` if they
+    /// don't have the same length.
+    #[doc(alias = "gsl_stats_spearman")]
+    fn spearman<O: StatsData<Elem = Self::Elem> + ?Sized>(&self, other: &O) -> Result<f64, Value> {
+        let (ptr1, stride1, n) = self.stats_parts();
+        let (ptr2, stride2, n2) = other.stats_parts();
+        if n != n2 {
+            return Err(Value::BadLength);
+        }
+        let mut work = vec![0.; 2 * n];
+        Ok(unsafe { Self::Elem::spearman(ptr1, stride1, ptr2, stride2, n, work.as_mut_ptr()) })
+    }
+
+    /// Returns the maximum value of the data, or `None` if it is empty.
+    #[doc(alias = "gsl_stats_max")]
+    fn maximum(&self) -> Option<Self::Elem> {
+        self.minmax().map(|(_, max)| max)
+    }
+
+    /// Returns the minimum value of the data, or `None` if it is empty.
+    #[doc(alias = "gsl_stats_min")]
+    fn minimum(&self) -> Option<Self::Elem> {
+        self.minmax().map(|(min, _)| min)
+    }
+
+    /// Returns the minimum and maximum values of the data, or `None` if it is empty.
+    #[doc(alias = "gsl_stats_minmax")]
+    fn minmax(&self) -> Option<(Self::Elem, Self::Elem)> {
+        let (ptr, stride, _) = self.stats_parts();
+        self.minmax_index()
+            .map(|(min, max)| unsafe { (*ptr.add(min * stride), *ptr.add(max * stride)) })
+    }
+
+    /// Returns the index of the maximum value of the data, or `None` if it is empty.
+    #[doc(alias = "gsl_stats_max_index")]
+    fn max_index(&self) -> Option<usize> {
+        self.minmax_index().map(|(_, max)| max)
+    }
+
+    /// Returns the index of the minimum value of the data, or `None` if it is empty.
+    #[doc(alias = "gsl_stats_min_index")]
+    fn min_index(&self) -> Option<usize> {
+        self.minmax_index().map(|(min, _)| min)
+    }
+
+    /// Returns the indices of the minimum and maximum values of the data, or `None` if it is empty.
+    #[doc(alias = "gsl_stats_minmax_index")]
+    fn minmax_index(&self) -> Option<(usize, usize)> {
+        let (ptr, stride, n) = self.stats_parts();
+        if n == 0 {
+            return None;
+        }
+        Some(unsafe { Self::Elem::minmax_index(ptr, stride, n) })
+    }
+
+    /// Returns the median of the data, which doesn't need to be sorted (a sorted copy is made), or `NaN` if it is empty
+    /// or contains NaN.
+    #[doc(alias = "gsl_stats_median")]
+    fn median(&self) -> f64 {
+        self.quantile(0.5)
+    }
+
+    /// Returns the quantile f (between 0 and 1) of the data, which doesn't need to be sorted (a sorted copy is made), or
+    /// `NaN` if it is empty, contains NaN or f is out of range.
+    #[doc(alias = "gsl_stats_quantile_from_sorted_data")]
+    fn quantile(&self, f: f64) -> f64 {
+        match sorted_copy(self) {
+            Some(sorted) => quantile_of_sorted(&sorted, f),
+            None => f64::NAN,
+        }
+    }
+
+    /// Returns a [`Summary`](struct.Summary.html) of the data.
+    fn summary(&self) -> Result<Summary, Value> {
+        Summary::new(self)
+    }
+}
+
+impl<D: StatsData + ?Sized> Statistics for D {}
+
+/// The usual descriptive statistics of a dataset, computed together by [`Summary::new`](#method.new).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Summary {
+    /// The number of elements.
+    pub count: usize,
+    /// The arithmetic mean.
+    pub mean: f64,
+    /// The sample variance, normalized by 1/(N-1).
+    pub variance: f64,
+    /// The sample standard deviation.
+    pub sd: f64,
+    /// The skewness, as computed by `gsl_stats_skew`.
+    pub skew: f64,
+    /// The excess kurtosis, as computed by `gsl_stats_kurtosis`.
+    pub kurtosis: f64,
+    /// The smallest value.
+    pub min: f64,
+    /// The first quartile (quantile 0.25).
+    pub lower_quartile: f64,
+    /// The median.
+    pub median: f64,
+    /// The third quartile (quantile 0.75).
+    pub upper_quartile: f64,
+    /// The largest value.
+    pub max: f64,
+}
+
+impl Summary {
+    /// Computes the summary of `data`. The moments are accumulated in a single pass over the data, and the quantiles
+    /// come from one sorted copy of it.
+    ///
+    /// Returns `Value::BadLength` if `data` is empty and `Value::Domain` if it contains NaN.
+    pub fn new<D: StatsData + ?Sized>(data: &D) -> Result<Summary, Value> {
+        let sorted = sorted_copy(data).ok_or(Value::Domain)?;
+        let count = sorted.len();
+        if count == 0 {
+            return Err(Value::BadLength);
+        }
+        // Terriberry's extension of Welford's algorithm to the third and fourth central moments.
+        let (mut mean, mut m2, mut m3, mut m4) = (0f64, 0f64, 0f64, 0f64);
+        for (i, x) in sorted.iter().enumerate() {
+            let n = (i + 1) as f64;
+            let delta = x.to_f64() - mean;
+            let delta_n = delta / n;
+            let term = delta * delta_n * (n - 1.);
+            mean += delta_n;
+            m4 += term * delta_n * delta_n * (n * n - 3. * n + 3.) + 6. * delta_n * delta_n * m2
+                - 4. * delta_n * m3;
+            m3 += term * delta_n * (n - 2.) - 3. * delta_n * m2;
+            m2 += term;
+        }
+        let n = count as f64;
+        let variance = m2 / (n - 1.);
+        let sd = variance.sqrt();
+        Ok(Summary {
+            count,
+            mean,
+            variance,
+            sd,
+            skew: m3 / n / (sd * sd * sd),
+            kurtosis: m4 / n / (variance * variance) - 3.,
+            min: sorted[0].to_f64(),
+            lower_quartile: quantile_of_sorted(&sorted, 0.25),
+            median: quantile_of_sorted(&sorted, 0.5),
+            upper_quartile: quantile_of_sorted(&sorted, 0.75),
+            max: sorted[count - 1].to_f64(),
+        })
+    }
+}
+
+#[test]
+fn statistics_summary() {
+    let data = [17.2, 18.1, 16.5, 18.3, 12.6];
+    let s = data.summary().unwrap();
+    assert_eq!(s.count, 5);
+    assert_eq!(format!("{:.4}", s.mean), format!("{:.4}", data.mean()));
+    assert_eq!(
+        format!("{:.4}", s.variance),
+        format!("{:.4}", data.variance())
+    );
+    assert_eq!(format!("{:.4}", s.skew), format!("{:.4}", data.skew()));
+    assert_eq!(
+        format!("{:.4}", s.kurtosis),
+        format!("{:.4}", data.kurtosis())
+    );
+    assert_eq!((s.min, s.median, s.max), (12.6, 17.2, 18.3));
+    assert_eq!((s.lower_quartile, s.upper_quartile), (16.5, 18.1));
+
+    let ints = [1i32, 2, 3, 4];
+    assert_eq!(ints.mean(), 2.5);
+    assert_eq!(ints.minmax(), Some((1, 4)));
+    assert_eq!(Strided::new(&ints, 2).unwrap().mean(), 2.);
+    assert_eq!(Strided::with_len(&ints, 2, 3).err(), Some(Value::BadLength));
+
+    let labels = ::VectorU8::from_slice(&[3, 1, 2]).unwrap();
+    assert_eq!(labels.mean(), 2.);
+    assert_eq!(labels.maximum(), Some(3));
+
+    let empty: [f64; 0] = [];
+    assert_eq!(empty.maximum(), None);
+    assert_eq!(empty.summary().err(), Some(Value::BadLength));
+
+    // Wherever the NaN sits, the order statistics are NaN rather than depending on its position.
+    for nan_data in &[
+        [f64::NAN, 1., 2., 3.],
+        [1., 2., f64::NAN, 3.],
+        [3., 2., 1., f64::NAN],
+    ] {
+        assert!(nan_data.median().is_nan());
+        assert!(nan_data.quantile(0.25).is_nan());
+        assert_eq!(nan_data.summary().err(), Some(Value::Domain));
+    }
+
+    let x = [1., 2., 3., 4.];
+    assert_eq!(weighted_median(&x, &[1., 1., 1., 1.]), Ok(2.5));
+    assert_eq!(weighted_median(&x, &[1., 1., 1., 5.]), Ok(4.));
+    assert_eq!(weighted_quantile(&x, &[1., 1., 0., 1.], 1.), Ok(4.));
+    assert_eq!(weighted_median(&x, &[1.]), Err(Value::BadLength));
+    assert_eq!(weighted_median(&x, &[0.; 4]), Err(Value::Domain));
+}
+
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
+#[test]
+fn robust_statistics() {
+    let sorted = [1., 2., 3., 4., 100.];
+    let mut work = [0.; 15];
+    let mut work_int = [0; 25];
+    assert_eq!(format!("{:.4}", mad0(&sorted, 1, 5, &mut work)), "1.0000");
+    assert_eq!(format!("{:.4}", mad(&sorted, 1, 5, &mut work)), "1.4826");
+    assert_eq!(
+        format!("{:.4}", trmean_from_sorted_data(0.2, &sorted, 1, 5)),
+        "3.0000"
+    );
+    assert!(Sn_from_sorted_data(&sorted, 1, 5, &mut work) < 5.);
+    assert!(Qn_from_sorted_data(&sorted, 1, 5, &mut work, &mut work_int) < 5.);
+    assert!(gastwirth_from_sorted_data(&sorted, 1, 5) < 5.);
+}
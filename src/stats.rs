@@ -2,9 +2,32 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+//! Deprecated in favour of [`statistics`](../statistics/index.html), which takes the number of elements explicitly and
+//! provides the bounds-checked [`Statistics`](../statistics/trait.Statistics.html) trait.
+
+// The number of elements a strided slice holds.
+fn count(len: usize, stride: usize) -> usize {
+    assert!(stride > 0, "stride must be positive");
+    (len + stride - 1) / stride
+}
+
+fn weighted_count(wlen: usize, wstride: usize, len: usize, stride: usize) -> usize {
+    let n = count(len, stride);
+    assert!(count(wlen, wstride) >= n, "not enough weights for the data");
+    n
+}
+
 #[doc(alias = "gsl_stats_wtss")]
 pub fn wtss(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_wtss(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+    unsafe {
+        sys::gsl_stats_wtss(
+            w.as_ptr(),
+            wstride,
+            data.as_ptr(),
+            stride,
+            weighted_count(w.len(), wstride, data.len(), stride),
+        )
+    }
 }
 
 #[doc(alias = "gsl_stats_wtss_m")]
@@ -15,7 +38,7 @@ pub fn wtss_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, wmean: f64
             wstride,
             data.as_ptr(),
             stride,
-            data.len() as _,
+            weighted_count(w.len(), wstride, data.len(), stride),
             wmean,
         )
     }
@@ -23,17 +46,41 @@ pub fn wtss_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, wmean: f64
 
 #[doc(alias = "gsl_stats_wabsdev")]
 pub fn wabsdev(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_wabsdev(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+    unsafe {
+        sys::gsl_stats_wabsdev(
+            w.as_ptr(),
+            wstride,
+            data.as_ptr(),
+            stride,
+            weighted_count(w.len(), wstride, data.len(), stride),
+        )
+    }
 }
 
 #[doc(alias = "gsl_stats_wskew")]
 pub fn wskew(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_wskew(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+    unsafe {
+        sys::gsl_stats_wskew(
+            w.as_ptr(),
+            wstride,
+            data.as_ptr(),
+            stride,
+            weighted_count(w.len(), wstride, data.len(), stride),
+        )
+    }
 }
 
 #[doc(alias = "gsl_stats_wkurtosis")]
 pub fn wkurtosis(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_wkurtosis(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+    unsafe {
+        sys::gsl_stats_wkurtosis(
+            w.as_ptr(),
+            wstride,
+            data.as_ptr(),
+            stride,
+            weighted_count(w.len(), wstride, data.len(), stride),
+        )
+    }
 }
 
 #[doc(alias = "gsl_stats_wvariance_m")]
@@ -44,7 +91,7 @@ pub fn wvariance_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, wmean
             wstride,
             data.as_ptr(),
             stride,
-            data.len() as _,
+            weighted_count(w.len(), wstride, data.len(), stride),
             wmean,
         )
     }
@@ -58,7 +105,7 @@ pub fn wabsdev_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, wmean:
             wstride,
             data.as_ptr(),
             stride,
-            data.len() as _,
+            weighted_count(w.len(), wstride, data.len(), stride),
             wmean,
         )
     }
@@ -79,7 +126,7 @@ pub fn wskew_m_sd(
             wstride,
             data.as_ptr(),
             stride,
-            data.len() as _,
+            weighted_count(w.len(), wstride, data.len(), stride),
             wmean,
             wsd,
         )
@@ -101,7 +148,7 @@ pub fn wkurtosis_m_sd(
             wstride,
             data.as_ptr(),
             stride,
-            data.len() as _,
+            weighted_count(w.len(), wstride, data.len(), stride),
             wmean,
             wsd,
         )
@@ -114,10 +161,10 @@ pub fn pvariance(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize) -
         sys::gsl_stats_pvariance(
             data1.as_ptr(),
             stride1,
-            data1.len() as _,
+            count(data1.len(), stride1),
             data2.as_ptr(),
             stride2,
-            data2.len() as _,
+            count(data2.len(), stride2),
         )
     }
 }
@@ -128,22 +175,22 @@ pub fn ttest(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize) -> f6
         sys::gsl_stats_ttest(
             data1.as_ptr(),
             stride1,
-            data1.len() as _,
+            count(data1.len(), stride1),
             data2.as_ptr(),
             stride2,
-            data2.len() as _,
+            count(data2.len(), stride2),
         )
     }
 }
 
 #[doc(alias = "gsl_stats_max")]
 pub fn max(data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_max(data.as_ptr(), stride, data.len() as _) }
+    unsafe { sys::gsl_stats_max(data.as_ptr(), stride, count(data.len(), stride)) }
 }
 
 #[doc(alias = "gsl_stats_min")]
 pub fn min(data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_min(data.as_ptr(), stride, data.len() as _) }
+    unsafe { sys::gsl_stats_min(data.as_ptr(), stride, count(data.len(), stride)) }
 }
 
 /// Returns `(min, max)`.
@@ -152,18 +199,26 @@ pub fn stats_minmax(data: &[f64], stride: usize) -> (f64, f64) {
     let mut min = 0.;
     let mut max = 0.;
 
-    unsafe { sys::gsl_stats_minmax(&mut min, &mut max, data.as_ptr(), stride, data.len() as _) }
+    unsafe {
+        sys::gsl_stats_minmax(
+            &mut min,
+            &mut max,
+            data.as_ptr(),
+            stride,
+            count(data.len(), stride),
+        )
+    }
     (min, max)
 }
 
 #[doc(alias = "gsl_stats_max_index")]
 pub fn max_index(data: &[f64], stride: usize) -> usize {
-    unsafe { sys::gsl_stats_max_index(data.as_ptr(), stride, data.len() as _) }
+    unsafe { sys::gsl_stats_max_index(data.as_ptr(), stride, count(data.len(), stride)) }
 }
 
 #[doc(alias = "gsl_stats_min_index")]
 pub fn min_index(data: &[f64], stride: usize) -> usize {
-    unsafe { sys::gsl_stats_min_index(data.as_ptr(), stride, data.len() as _) }
+    unsafe { sys::gsl_stats_min_index(data.as_ptr(), stride, count(data.len(), stride)) }
 }
 
 /// Returns `(min, max)`.
@@ -173,21 +228,27 @@ pub fn stats_minmax_index(data: &[f64], stride: usize) -> (usize, usize) {
     let mut max = 0;
 
     unsafe {
-        sys::gsl_stats_minmax_index(&mut min, &mut max, data.as_ptr(), stride, data.len() as _)
+        sys::gsl_stats_minmax_index(
+            &mut min,
+            &mut max,
+            data.as_ptr(),
+            stride,
+            count(data.len(), stride),
+        )
     }
     (min, max)
 }
 
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 #[doc(alias = "gsl_stats_select")]
 pub fn select(data: &mut [f64], stride: usize, k: usize) -> f64 {
-    unsafe { sys::gsl_stats_select(data.as_mut_ptr(), stride, data.len() as _, k) }
+    unsafe { sys::gsl_stats_select(data.as_mut_ptr(), stride, count(data.len(), stride), k) }
 }
 
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 #[doc(alias = "gsl_stats_median")]
 pub fn median(data: &mut [f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_median(data.as_mut_ptr(), stride, data.len() as _) }
+    unsafe { sys::gsl_stats_median(data.as_mut_ptr(), stride, count(data.len(), stride)) }
 }
@@ -15,6 +15,22 @@ pub trait Trigonometric {
     fn cos(&self) -> Self;
     /// This routine computes the cosine function \sin(x).
     fn cos_e(&self) -> (Value, types::Result);
+    /// This routine computes \sin(\pi x), which is accurate for large x where \sin(M_PI * x) is not.
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    fn sin_pi(&self) -> Self;
+    /// This routine computes \sin(\pi x), which is accurate for large x where \sin(M_PI * x) is not.
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    fn sin_pi_e(&self) -> (Value, types::Result);
+    /// This routine computes \cos(\pi x), which is accurate for large x where \cos(M_PI * x) is not.
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    fn cos_pi(&self) -> Self;
+    /// This routine computes \cos(\pi x), which is accurate for large x where \cos(M_PI * x) is not.
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    fn cos_pi_e(&self) -> (Value, types::Result);
     /// This routine computes the hypotenuse function \sqrt{x^2 + y^2} avoiding overflow and underflow.
     fn sf_hypot(&self, y: f64) -> Self;
     /// This routine computes the hypotenuse function \sqrt{x^2 + y^2} avoiding overflow and underflow.
@@ -50,6 +66,9 @@ pub trait Trigonometric {
     ///
     /// Note that the mathematical value of \pi is slightly greater than M_PI, so the machine numbers M_PI and -M_PI are included in the range.
     fn angle_restrict_symm_e(&mut self) -> Value;
+    /// This routine forces the angle theta to lie in the range (-\pi,\pi], returning the reduced angle
+    /// together with an estimate of the error introduced by the reduction.
+    fn angle_restrict_symm_err_e(&self) -> (Value, types::Result);
     /// This routine forces the angle theta to lie in the range [0, 2\pi).
     ///
     /// Note that the mathematical value of 2\pi is slightly greater than 2*M_PI, so the machine number 2*M_PI is included in the range.
@@ -58,6 +77,9 @@ pub trait Trigonometric {
     ///
     /// Note that the mathematical value of 2\pi is slightly greater than 2*M_PI, so the machine number 2*M_PI is included in the range.
     fn angle_restrict_pos_e(&mut self) -> Value;
+    /// This routine forces the angle theta to lie in the range [0, 2\pi), returning the reduced angle
+    /// together with an estimate of the error introduced by the reduction.
+    fn angle_restrict_pos_err_e(&self) -> (Value, types::Result);
     /// This routine computes the sine of an angle x with an associated absolute error dx, \sin(x \pm dx).
     ///
     /// Note that this function is provided in the error-handling form only since its purpose is to compute the propagated error.
@@ -95,6 +117,36 @@ impl Trigonometric for f64 {
         (::Value::from(ret), unsafe { result.assume_init() }.into())
     }
 
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[doc(alias = "gsl_sf_sin_pi")]
+    fn sin_pi(&self) -> f64 {
+        unsafe { ::sys::gsl_sf_sin_pi(*self) }
+    }
+
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[doc(alias = "gsl_sf_sin_pi_e")]
+    fn sin_pi_e(&self) -> (Value, types::Result) {
+        let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe { ::sys::gsl_sf_sin_pi_e(*self, result.as_mut_ptr()) };
+
+        (::Value::from(ret), unsafe { result.assume_init() }.into())
+    }
+
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[doc(alias = "gsl_sf_cos_pi")]
+    fn cos_pi(&self) -> f64 {
+        unsafe { ::sys::gsl_sf_cos_pi(*self) }
+    }
+
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    #[doc(alias = "gsl_sf_cos_pi_e")]
+    fn cos_pi_e(&self) -> (Value, types::Result) {
+        let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe { ::sys::gsl_sf_cos_pi_e(*self, result.as_mut_ptr()) };
+
+        (::Value::from(ret), unsafe { result.assume_init() }.into())
+    }
+
     #[doc(alias = "gsl_sf_hypot")]
     fn sf_hypot(&self, y: f64) -> f64 {
         unsafe { ::sys::gsl_sf_hypot(*self, y) }
@@ -232,6 +284,14 @@ impl Trigonometric for f64 {
         Value::from(unsafe { ::sys::gsl_sf_angle_restrict_symm_e(self) })
     }
 
+    #[doc(alias = "gsl_sf_angle_restrict_symm_err_e")]
+    fn angle_restrict_symm_err_e(&self) -> (Value, types::Result) {
+        let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe { ::sys::gsl_sf_angle_restrict_symm_err_e(*self, result.as_mut_ptr()) };
+
+        (::Value::from(ret), unsafe { result.assume_init() }.into())
+    }
+
     #[doc(alias = "gsl_sf_angle_restrict_pos")]
     fn angle_restrict_pos(&self) -> f64 {
         unsafe { ::sys::gsl_sf_angle_restrict_pos(*self) }
@@ -242,6 +302,14 @@ impl Trigonometric for f64 {
         Value::from(unsafe { ::sys::gsl_sf_angle_restrict_pos_e(self) })
     }
 
+    #[doc(alias = "gsl_sf_angle_restrict_pos_err_e")]
+    fn angle_restrict_pos_err_e(&self) -> (Value, types::Result) {
+        let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe { ::sys::gsl_sf_angle_restrict_pos_err_e(*self, result.as_mut_ptr()) };
+
+        (::Value::from(ret), unsafe { result.assume_init() }.into())
+    }
+
     #[doc(alias = "gsl_sf_sin_err_e")]
     fn sin_err_e(&self, dx: f64) -> (Value, types::Result) {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
@@ -258,3 +326,21 @@ impl Trigonometric for f64 {
         (::Value::from(ret), unsafe { result.assume_init() }.into())
     }
 }
+
+#[test]
+fn angle_restrict_err() {
+    let (ret, res) = (3. * ::std::f64::consts::FRAC_PI_2).angle_restrict_symm_err_e();
+    assert_eq!(ret, Value::Success);
+    assert_eq!(format!("{:.6}", res.val), "-1.570796");
+
+    let (_, res) = (-::std::f64::consts::FRAC_PI_2).angle_restrict_pos_err_e();
+    assert_eq!(format!("{:.6}", res.val), "4.712389");
+
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
+    {
+        assert_eq!(format!("{:.6}", 0.5f64.sin_pi()), "1.000000");
+        assert_eq!(format!("{:.6}", (1f64 / 3.).cos_pi()), "0.500000");
+        // exact at integers even for large arguments
+        assert_eq!(1e15f64.sin_pi(), 0.);
+    }
+}
@@ -0,0 +1,497 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# BLAS methods
+
+Methods on the vector and matrix types which check the dimensions of their operands before calling the
+[`blas`](../../blas/index.html) functions, and allocate their result when no output is given:
+
+```
+use rgsl::{MatrixF64, VectorF64};
+
+let mut a = MatrixF64::new(2, 3).unwrap();
+a.set_all(1.);
+let x = VectorF64::from_slice(&[1., 2., 3.]).unwrap();
+
+let y = a.matvec(&x).unwrap();
+assert_eq!(y.get(1), 6.);
+// A^T A, without copying A.
+let ata = a.t().matmul(&a).unwrap();
+assert_eq!((ata.size1(), ata.size2()), (3, 3));
+assert!(a.matmul(&a).is_err());
+```
+
+A mismatch of the dimensions is reported as `Value::BadLength`, a non-square triangular matrix as `Value::NotSquare`.
+!*/
+
+use crate::paste::paste;
+use crate::Value;
+use blas;
+use enums::{CblasDiag, CblasSide, CblasTranspose, CblasUplo};
+use {ComplexF32, ComplexF64};
+use {MatrixComplexF32, MatrixComplexF64, MatrixF32, MatrixF64};
+use {VectorComplexF32, VectorComplexF64, VectorF32, VectorF64};
+
+fn check(ok: bool) -> Result<(), Value> {
+    if ok {
+        Ok(())
+    } else {
+        Err(Value::BadLength)
+    }
+}
+
+/// A borrowed matrix, possibly transposed, used as an operand of the BLAS methods. The transposition is only
+/// recorded and passed to BLAS: the elements aren't moved.
+pub struct MatrixOp<'a, M: 'a> {
+    matrix: &'a M,
+    trans: CblasTranspose,
+}
+
+impl<'a, M> Clone for MatrixOp<'a, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, M> Copy for MatrixOp<'a, M> {}
+
+impl<'a, M> From<&'a M> for MatrixOp<'a, M> {
+    fn from(matrix: &'a M) -> Self {
+        MatrixOp {
+            matrix,
+            trans: CblasTranspose::NoTranspose,
+        }
+    }
+}
+
+impl<'a, M> MatrixOp<'a, M> {
+    /// Returns the underlying matrix.
+    pub fn matrix(&self) -> &'a M {
+        self.matrix
+    }
+
+    /// Returns the operation applied to the matrix.
+    pub fn transpose(&self) -> CblasTranspose {
+        self.trans
+    }
+
+    /// Returns the transpose of this operand: a transposed matrix goes back to the untransposed one.
+    ///
+    /// The transpose of a conjugate transposed matrix is its conjugate, which BLAS can't express: it gives
+    /// `Value::Invalid`.
+    pub fn t(self) -> Result<Self, Value> {
+        let trans = match self.trans {
+            CblasTranspose::NoTranspose => CblasTranspose::Transpose,
+            CblasTranspose::Transpose => CblasTranspose::NoTranspose,
+            CblasTranspose::ConjugateTranspose => return Err(Value::Invalid),
+        };
+        Ok(MatrixOp {
+            matrix: self.matrix,
+            trans,
+        })
+    }
+}
+
+macro_rules! blas_ops {
+    (
+        $matrix:ident, $vector:ident, $elem:ty, $real:ty, $zero:expr, $one:expr,
+        $gemm:ident, $gemv:ident, $syrk:ident, $trsm:ident, $dot:ident, $nrm2:ident, [$($r:tt)?]
+    ) => {
+        paste! {
+            impl<'a> MatrixOp<'a, $matrix> {
+                /// Returns the number of rows of the operand.
+                pub fn nrows(&self) -> usize {
+                    match self.trans {
+                        CblasTranspose::NoTranspose => self.matrix.size1(),
+                        _ => self.matrix.size2(),
+                    }
+                }
+
+                /// Returns the number of columns of the operand.
+                pub fn ncols(&self) -> usize {
+                    match self.trans {
+                        CblasTranspose::NoTranspose => self.matrix.size2(),
+                        _ => self.matrix.size1(),
+                    }
+                }
+
+                /// Returns the matrix product of this operand and `b`.
+                pub fn matmul<'b, B: Into<MatrixOp<'b, $matrix>>>(
+                    &self,
+                    b: B,
+                ) -> Result<$matrix, Value> {
+                    let b = b.into();
+                    check(self.ncols() == b.nrows())?;
+                    let mut c = $matrix::new(self.nrows(), b.ncols()).ok_or(Value::NoMemory)?;
+                    self.gemm_into($one, b, $zero, &mut c)?;
+                    Ok(c)
+                }
+
+                /// Computes C = alpha op(A) op(B) + beta C.
+                #[doc(alias = gsl_blas_ $gemm)]
+                pub fn gemm_into<'b, B: Into<MatrixOp<'b, $matrix>>>(
+                    &self,
+                    alpha: $elem,
+                    b: B,
+                    beta: $elem,
+                    c: &mut $matrix,
+                ) -> Result<(), Value> {
+                    let b = b.into();
+                    check(self.ncols() == b.nrows())?;
+                    check(c.size1() == self.nrows() && c.size2() == b.ncols())?;
+                    blas::level3::$gemm(
+                        self.trans,
+                        b.trans,
+                        $($r)? alpha,
+                        self.matrix,
+                        b.matrix,
+                        $($r)? beta,
+                        c,
+                    )
+                    .to_result()
+                }
+
+                /// Returns the product of this operand and the vector `x`.
+                pub fn matvec(&self, x: &$vector) -> Result<$vector, Value> {
+                    let mut y = $vector::new(self.nrows()).ok_or(Value::NoMemory)?;
+                    self.gemv_into($one, x, $zero, &mut y)?;
+                    Ok(y)
+                }
+
+                /// Computes y = alpha op(A) x + beta y.
+                #[doc(alias = gsl_blas_ $gemv)]
+                pub fn gemv_into(
+                    &self,
+                    alpha: $elem,
+                    x: &$vector,
+                    beta: $elem,
+                    y: &mut $vector,
+                ) -> Result<(), Value> {
+                    check(x.len() == self.ncols() && y.len() == self.nrows())?;
+                    blas::level2::$gemv(
+                        self.trans,
+                        $($r)? alpha,
+                        self.matrix,
+                        x,
+                        $($r)? beta,
+                        y,
+                    )
+                    .to_result()
+                }
+
+                /// Returns the symmetric product op(A) op(A)^T. Both triangles of the result are filled.
+                ///
+                /// Conjugate transposed operands aren't supported by BLAS and give `Value::Invalid`.
+                #[doc(alias = gsl_blas_ $syrk)]
+                pub fn syrk(&self) -> Result<$matrix, Value> {
+                    if self.trans == CblasTranspose::ConjugateTranspose {
+                        return Err(Value::Invalid);
+                    }
+                    let n = self.nrows();
+                    let mut c = $matrix::new(n, n).ok_or(Value::NoMemory)?;
+                    blas::level3::$syrk(
+                        CblasUplo::Lower,
+                        self.trans,
+                        $($r)? $one,
+                        self.matrix,
+                        $($r)? $zero,
+                        &mut c,
+                    )
+                    .to_result()?;
+                    for i in 0..n {
+                        for j in i + 1..n {
+                            let x = c.get(j, i);
+                            c.set(i, j, $($r)? x);
+                        }
+                    }
+                    Ok(c)
+                }
+
+                /// Returns the solution X of op(A) X = B (`side` is `Left`) or X op(A) = B (`side` is `Right`), where
+                /// A is triangular. `uplo` tells which triangle of A is used, `diag` if its diagonal is taken as unit.
+                #[doc(alias = gsl_blas_ $trsm)]
+                pub fn trsm(
+                    &self,
+                    side: CblasSide,
+                    uplo: CblasUplo,
+                    diag: CblasDiag,
+                    b: &$matrix,
+                ) -> Result<$matrix, Value> {
+                    let mut x = b.clone().ok_or(Value::NoMemory)?;
+                    self.trsm_into(side, uplo, diag, $one, &mut x)?;
+                    Ok(x)
+                }
+
+                /// Like `trsm`, but solves for alpha B in place: `b` holds B on input and the solution on output.
+                #[doc(alias = gsl_blas_ $trsm)]
+                pub fn trsm_into(
+                    &self,
+                    side: CblasSide,
+                    uplo: CblasUplo,
+                    diag: CblasDiag,
+                    alpha: $elem,
+                    b: &mut $matrix,
+                ) -> Result<(), Value> {
+                    let n = self.nrows();
+                    if n != self.ncols() {
+                        return Err(Value::NotSquare);
+                    }
+                    check(match side {
+                        CblasSide::Left => b.size1() == n,
+                        CblasSide::Right => b.size2() == n,
+                    })?;
+                    blas::level3::$trsm(
+                        side,
+                        uplo,
+                        self.trans,
+                        diag,
+                        $($r)? alpha,
+                        self.matrix,
+                        b,
+                    )
+                    .to_result()
+                }
+            }
+
+            impl $matrix {
+                /// Returns the transpose of the matrix, as a BLAS operand.
+                pub fn t(&self) -> MatrixOp<'_, $matrix> {
+                    MatrixOp {
+                        matrix: self,
+                        trans: CblasTranspose::Transpose,
+                    }
+                }
+
+                /// Returns the matrix product of the matrix and `b`.
+                pub fn matmul<'b, B: Into<MatrixOp<'b, $matrix>>>(
+                    &self,
+                    b: B,
+                ) -> Result<$matrix, Value> {
+                    MatrixOp::from(self).matmul(b)
+                }
+
+                /// Computes C = alpha A op(B) + beta C.
+                #[doc(alias = gsl_blas_ $gemm)]
+                pub fn gemm_into<'b, B: Into<MatrixOp<'b, $matrix>>>(
+                    &self,
+                    alpha: $elem,
+                    b: B,
+                    beta: $elem,
+                    c: &mut $matrix,
+                ) -> Result<(), Value> {
+                    MatrixOp::from(self).gemm_into(alpha, b, beta, c)
+                }
+
+                /// Returns the product of the matrix and the vector `x`.
+                pub fn matvec(&self, x: &$vector) -> Result<$vector, Value> {
+                    MatrixOp::from(self).matvec(x)
+                }
+
+                /// Computes y = alpha A x + beta y.
+                #[doc(alias = gsl_blas_ $gemv)]
+                pub fn gemv_into(
+                    &self,
+                    alpha: $elem,
+                    x: &$vector,
+                    beta: $elem,
+                    y: &mut $vector,
+                ) -> Result<(), Value> {
+                    MatrixOp::from(self).gemv_into(alpha, x, beta, y)
+                }
+
+                /// Returns the symmetric product A A^T. Use `a.t().syrk()` for A^T A.
+                #[doc(alias = gsl_blas_ $syrk)]
+                pub fn syrk(&self) -> Result<$matrix, Value> {
+                    MatrixOp::from(self).syrk()
+                }
+
+                /// Returns the solution X of A X = B (`side` is `Left`) or X A = B (`side` is `Right`), where the matrix
+                /// A is triangular.
+                #[doc(alias = gsl_blas_ $trsm)]
+                pub fn trsm(
+                    &self,
+                    side: CblasSide,
+                    uplo: CblasUplo,
+                    diag: CblasDiag,
+                    b: &$matrix,
+                ) -> Result<$matrix, Value> {
+                    MatrixOp::from(self).trsm(side, uplo, diag, b)
+                }
+
+                /// Like `trsm`, but solves for alpha B in place.
+                #[doc(alias = gsl_blas_ $trsm)]
+                pub fn trsm_into(
+                    &self,
+                    side: CblasSide,
+                    uplo: CblasUplo,
+                    diag: CblasDiag,
+                    alpha: $elem,
+                    b: &mut $matrix,
+                ) -> Result<(), Value> {
+                    MatrixOp::from(self).trsm_into(side, uplo, diag, alpha, b)
+                }
+            }
+
+            impl $vector {
+                /// Returns the scalar product x^T y.
+                #[doc(alias = gsl_blas_ $dot)]
+                pub fn dot(&self, y: &$vector) -> Result<$elem, Value> {
+                    check(self.len() == y.len())?;
+                    let (ret, x) = blas::level1::$dot(self, y);
+                    ret.to_result()?;
+                    Ok(x)
+                }
+
+                /// Returns the Euclidean norm of the vector.
+                #[doc(alias = gsl_blas_ $nrm2)]
+                pub fn norm2(&self) -> $real {
+                    blas::level1::$nrm2(self)
+                }
+            }
+        }
+    };
+}
+
+blas_ops!(
+    MatrixF64,
+    VectorF64,
+    f64,
+    f64,
+    0.,
+    1.,
+    dgemm,
+    dgemv,
+    dsyrk,
+    dtrsm,
+    ddot,
+    dnrm2,
+    []
+);
+blas_ops!(
+    MatrixF32,
+    VectorF32,
+    f32,
+    f32,
+    0.,
+    1.,
+    sgemm,
+    sgemv,
+    ssyrk,
+    strsm,
+    sdot,
+    snrm2,
+    []
+);
+blas_ops!(
+    MatrixComplexF64, VectorComplexF64, ComplexF64, f64,
+    ComplexF64 { dat: [0., 0.] }, ComplexF64 { dat: [1., 0.] },
+    zgemm, zgemv, zsyrk, ztrsm, zdotu, dznrm2, [&]
+);
+blas_ops!(
+    MatrixComplexF32, VectorComplexF32, ComplexF32, f32,
+    ComplexF32 { dat: [0., 0.] }, ComplexF32 { dat: [1., 0.] },
+    cgemm, cgemv, csyrk, ctrsm, cdotu, scnrm2, [&]
+);
+
+macro_rules! complex_ops {
+    ($matrix:ident, $vector:ident, $elem:ty, $dotc:ident) => {
+        paste! {
+            impl $matrix {
+                /// Returns the conjugate transpose of the matrix, as a BLAS operand.
+                pub fn h(&self) -> MatrixOp<'_, $matrix> {
+                    MatrixOp {
+                        matrix: self,
+                        trans: CblasTranspose::ConjugateTranspose,
+                    }
+                }
+            }
+
+            impl $vector {
+                /// Returns the conjugate scalar product x^H y.
+                #[doc(alias = gsl_blas_ $dotc)]
+                pub fn dotc(&self, y: &$vector) -> Result<$elem, Value> {
+                    check(self.len() == y.len())?;
+                    let (ret, x) = blas::level1::$dotc(self, y);
+                    ret.to_result()?;
+                    Ok(x)
+                }
+            }
+        }
+    };
+}
+
+complex_ops!(MatrixComplexF64, VectorComplexF64, ComplexF64, zdotc);
+complex_ops!(MatrixComplexF32, VectorComplexF32, ComplexF32, cdotc);
+
+#[test]
+fn blas_ops() {
+    let mut a = MatrixF64::new(2, 3).unwrap();
+    for i in 0..2 {
+        for j in 0..3 {
+            a.set(i, j, (i * 3 + j) as f64);
+        }
+    }
+    let x = VectorF64::from_slice(&[1., 1., 1.]).unwrap();
+    let y = a.matvec(&x).unwrap();
+    assert_eq!((y.get(0), y.get(1)), (3., 12.));
+    assert_eq!(a.t().matvec(&y).unwrap().get(2), 6. + 5. * 12.);
+    assert_eq!(a.matvec(&y).unwrap_err(), Value::BadLength);
+
+    let aat = a.matmul(a.t()).unwrap();
+    assert_eq!((aat.size1(), aat.size2()), (2, 2));
+    assert_eq!(aat.get(0, 1), 14.);
+    let syrk = a.syrk().unwrap();
+    assert_eq!(syrk.get(0, 1), aat.get(1, 0));
+    let ata = a.t().syrk().unwrap();
+    assert_eq!(ata.get(2, 0), a.t().matmul(&a).unwrap().get(0, 2));
+    assert!(a.matmul(&a).is_err());
+    assert_eq!(a.t().t().unwrap().nrows(), 2);
+
+    let mut c = MatrixF64::new(2, 2).unwrap();
+    c.set_identity();
+    a.gemm_into(2., a.t(), 1., &mut c).unwrap();
+    assert_eq!(c.get(0, 0), 2. * aat.get(0, 0) + 1.);
+
+    // Upper triangular [[2, 1], [0, 4]]
+    let mut u = MatrixF64::new(2, 2).unwrap();
+    u.set(0, 0, 2.);
+    u.set(0, 1, 1.);
+    u.set(1, 1, 4.);
+    let b = a.clone().unwrap();
+    let s = u
+        .trsm(CblasSide::Left, CblasUplo::Upper, CblasDiag::NonUnit, &b)
+        .unwrap();
+    let back = u.matmul(&s).unwrap();
+    for j in 0..3 {
+        assert!((back.get(1, j) - b.get(1, j)).abs() < 1e-14);
+    }
+    assert_eq!(
+        u.trsm(CblasSide::Right, CblasUplo::Upper, CblasDiag::NonUnit, &b)
+            .unwrap_err(),
+        Value::BadLength
+    );
+    assert_eq!(
+        a.trsm(CblasSide::Left, CblasUplo::Upper, CblasDiag::NonUnit, &b)
+            .unwrap_err(),
+        Value::NotSquare
+    );
+
+    let v = VectorF64::from_slice(&[3., 4.]).unwrap();
+    assert_eq!(v.norm2(), 5.);
+    assert_eq!(v.dot(&v).unwrap(), 25.);
+    assert!(v.dot(&x).is_err());
+
+    let mut z = VectorComplexF64::new(2).unwrap();
+    z.set(0, &ComplexF64::rect(0., 1.));
+    z.set(1, &ComplexF64::rect(1., 0.));
+    assert_eq!(z.dot(&z).unwrap().real(), 0.);
+    assert_eq!(z.dotc(&z).unwrap().real(), 2.);
+    let mut m = MatrixComplexF64::new(2, 2).unwrap();
+    m.set(0, 1, &ComplexF64::rect(0., 1.));
+    let mh = m.h().matvec(&z).unwrap();
+    assert_eq!(mh.get(1).real(), 1.);
+    assert_eq!(m.h().syrk().unwrap_err(), Value::Invalid);
+    assert_eq!(m.h().t().err(), Some(Value::Invalid));
+}
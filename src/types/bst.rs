@@ -0,0 +1,390 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Binary Search Trees
+
+A binary search tree is a data structure which stores its items ordered by a comparison function, allowing insertion,
+removal and look-up in O(\log n) time when the tree is balanced. GSL provides two kinds of balanced trees: AVL trees and
+red-black trees. They are based on the GNU libavl library by Ben Pfaff.
+
+[`Bst`](struct.Bst.html) stores key/value pairs ordered by the `Ord` implementation of the keys:
+
+```
+use rgsl::{Bst, BstType};
+
+let mut tree = Bst::new(BstType::avl()).unwrap();
+tree.insert(3, "three");
+tree.insert(1, "one");
+tree.insert(2, "two");
+
+assert_eq!(tree.find(&2), Some(&"two"));
+assert_eq!(tree.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3]);
+```
+
+## References and Further Reading
+
+Ben Pfaff, An Introduction to Binary Search Trees and Balanced Trees, Free Software Foundation, 2004.
+!*/
+
+use ffi::FFI;
+use std::cmp::Ordering;
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::os::raw::{c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+
+ffi_wrapper!(BstType, *const sys::gsl_bst_type);
+
+impl BstType {
+    /// AVL tree: the heights of the two subtrees of any node differ by at most one.
+    pub fn avl() -> BstType {
+        ffi_wrap!(gsl_bst_avl)
+    }
+
+    /// Red-black tree: no path from the root to a leaf is more than twice as long as any other.
+    pub fn rb() -> BstType {
+        ffi_wrap!(gsl_bst_rb)
+    }
+}
+
+// The items stored in the tree. The key comes first so that a pointer to a key can be used to search for an item.
+#[repr(C)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+}
+
+unsafe extern "C" fn compare<K: Ord>(a: *const c_void, b: *const c_void, _: *mut c_void) -> c_int {
+    let (a, b) = (&*(a as *const K), &*(b as *const K));
+    // A panic must not unwind through the C frames of GSL, and the tree can't be used once its comparison failed.
+    match panic::catch_unwind(AssertUnwindSafe(|| a.cmp(b))) {
+        Ok(Ordering::Less) => -1,
+        Ok(Ordering::Equal) => 0,
+        Ok(Ordering::Greater) => 1,
+        Err(_) => process::abort(),
+    }
+}
+
+// `gsl_bst_trav` is opaque in the bindings. This buffer is larger than the C struct, whose biggest member is the red-black
+// traverser (a table pointer, a node pointer, a 48-entry stack, a height and a generation counter).
+#[repr(C)]
+struct Trav([usize; 64]);
+
+impl Trav {
+    fn as_mut_ptr(&mut self) -> *mut sys::gsl_bst_trav {
+        self as *mut Trav as *mut sys::gsl_bst_trav
+    }
+}
+
+/// A balanced binary search tree mapping keys of type `K` to values of type `V`, ordered by `K::cmp`.
+///
+/// `K::cmp` is called from GSL: if it panics, the process is aborted.
+pub struct Bst<K: Ord, V> {
+    inner: *mut sys::gsl_bst_workspace,
+    phantom: PhantomData<Box<Node<K, V>>>,
+}
+
+impl<K: Ord, V> Bst<K, V> {
+    /// This function allocates an empty binary search tree of type `t`.
+    #[doc(alias = "gsl_bst_alloc")]
+    pub fn new(t: BstType) -> Option<Bst<K, V>> {
+        let tmp = unsafe {
+            sys::gsl_bst_alloc(
+                t.unwrap_shared(),
+                ::std::ptr::null(),
+                Some(compare::<K>),
+                ::std::ptr::null_mut(),
+            )
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Bst {
+                inner: tmp,
+                phantom: PhantomData,
+            })
+        }
+    }
+
+    /// Returns the name of the tree type (`"AVL"` or `"red-black"`).
+    #[doc(alias = "gsl_bst_name")]
+    pub fn name(&self) -> String {
+        let n = unsafe { sys::gsl_bst_name(self.inner) };
+        if n.is_null() {
+            return String::new();
+        }
+        unsafe { CStr::from_ptr(n) }.to_string_lossy().into_owned()
+    }
+
+    /// Returns the number of items in the tree.
+    #[doc(alias = "gsl_bst_nodes")]
+    pub fn nodes(&self) -> usize {
+        unsafe { sys::gsl_bst_nodes(self.inner) }
+    }
+
+    /// Returns the number of items in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes()
+    }
+
+    /// Returns `true` if the tree contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.nodes() == 0
+    }
+
+    /// Inserts `value` under `key`. If the tree already contained an item with an equal key, its value is replaced and
+    /// the previous one is returned.
+    #[doc(alias = "gsl_bst_insert")]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let item = Box::into_raw(Box::new(Node { key, value }));
+        let dup =
+            unsafe { sys::gsl_bst_insert(item as *mut c_void, self.inner) } as *mut Node<K, V>;
+        if dup.is_null() {
+            None
+        } else {
+            let item = unsafe { Box::from_raw(item) };
+            Some(::std::mem::replace(
+                unsafe { &mut (*dup).value },
+                item.value,
+            ))
+        }
+    }
+
+    /// Returns a reference to the value stored under `key`, if any.
+    #[doc(alias = "gsl_bst_find")]
+    pub fn find(&self, key: &K) -> Option<&V> {
+        let item = unsafe { sys::gsl_bst_find(key as *const K as *const c_void, self.inner) }
+            as *const Node<K, V>;
+        if item.is_null() {
+            None
+        } else {
+            Some(unsafe { &(*item).value })
+        }
+    }
+
+    /// Returns a mutable reference to the value stored under `key`, if any.
+    #[doc(alias = "gsl_bst_find")]
+    pub fn find_mut(&mut self, key: &K) -> Option<&mut V> {
+        let item = unsafe { sys::gsl_bst_find(key as *const K as *const c_void, self.inner) }
+            as *mut Node<K, V>;
+        if item.is_null() {
+            None
+        } else {
+            Some(unsafe { &mut (*item).value })
+        }
+    }
+
+    /// Returns `true` if the tree contains an item with the given key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Removes the item stored under `key` from the tree and returns it.
+    #[doc(alias = "gsl_bst_remove")]
+    pub fn remove(&mut self, key: &K) -> Option<(K, V)> {
+        let item = unsafe { sys::gsl_bst_remove(key as *const K as *const c_void, self.inner) }
+            as *mut Node<K, V>;
+        if item.is_null() {
+            None
+        } else {
+            let item = unsafe { Box::from_raw(item) };
+            Some((item.key, item.value))
+        }
+    }
+
+    /// Returns an iterator over the items of the tree, in ascending key order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            raw: RawIter::new(self.inner),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the items of the tree, in ascending key order, with mutable references to the values.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            raw: RawIter::new(self.inner),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the keys of the tree, in ascending order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over the values of the tree, in ascending key order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// Returns the item with the smallest key.
+    #[doc(alias = "gsl_bst_trav_first")]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let mut trav = Trav([0; 64]);
+        let item =
+            unsafe { sys::gsl_bst_trav_first(trav.as_mut_ptr(), self.inner) } as *const Node<K, V>;
+        unsafe { item.as_ref() }.map(|n| (&n.key, &n.value))
+    }
+
+    /// Returns the item with the largest key.
+    #[doc(alias = "gsl_bst_trav_last")]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let mut trav = Trav([0; 64]);
+        let item =
+            unsafe { sys::gsl_bst_trav_last(trav.as_mut_ptr(), self.inner) } as *const Node<K, V>;
+        unsafe { item.as_ref() }.map(|n| (&n.key, &n.value))
+    }
+
+    /// Removes all the items of the tree.
+    #[doc(alias = "gsl_bst_empty")]
+    pub fn clear(&mut self) {
+        let items = RawIter::new(self.inner).collect::<Vec<_>>();
+        unsafe { sys::gsl_bst_empty(self.inner) };
+        for item in items {
+            drop(unsafe { Box::from_raw(item as *mut Node<K, V>) });
+        }
+    }
+}
+
+impl<K: Ord, V> Drop for Bst<K, V> {
+    fn drop(&mut self) {
+        self.clear();
+        unsafe { sys::gsl_bst_free(self.inner) };
+        self.inner = ::std::ptr::null_mut();
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a Bst<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+struct RawIter {
+    tree: *const sys::gsl_bst_workspace,
+    trav: Trav,
+    started: bool,
+    remaining: usize,
+}
+
+impl RawIter {
+    fn new(tree: *const sys::gsl_bst_workspace) -> RawIter {
+        RawIter {
+            tree,
+            trav: Trav([0; 64]),
+            started: false,
+            remaining: unsafe { sys::gsl_bst_nodes(tree) },
+        }
+    }
+}
+
+impl Iterator for RawIter {
+    type Item = *mut c_void;
+
+    fn next(&mut self) -> Option<*mut c_void> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = if self.started {
+            unsafe { sys::gsl_bst_trav_next(self.trav.as_mut_ptr()) }
+        } else {
+            self.started = true;
+            unsafe { sys::gsl_bst_trav_first(self.trav.as_mut_ptr(), self.tree) }
+        };
+        if item.is_null() {
+            self.remaining = 0;
+            None
+        } else {
+            self.remaining -= 1;
+            Some(item)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// In-order iterator over the items of a [`Bst`](struct.Bst.html), created by [`Bst::iter`](struct.Bst.html#method.iter).
+#[doc(alias = "gsl_bst_trav_next")]
+pub struct Iter<'a, K, V> {
+    raw: RawIter,
+    phantom: PhantomData<&'a Node<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.raw.next().map(|item| {
+            let n = unsafe { &*(item as *const Node<K, V>) };
+            (&n.key, &n.value)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.raw.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+/// In-order iterator over the items of a [`Bst`](struct.Bst.html), created by
+/// [`Bst::iter_mut`](struct.Bst.html#method.iter_mut).
+#[doc(alias = "gsl_bst_trav_next")]
+pub struct IterMut<'a, K, V> {
+    raw: RawIter,
+    phantom: PhantomData<&'a mut Node<K, V>>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a mut V)> {
+        self.raw.next().map(|item| {
+            let n = unsafe { &mut *(item as *mut Node<K, V>) };
+            (&n.key, &mut n.value)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.raw.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {}
+
+#[test]
+fn bst() {
+    for t in [BstType::avl(), BstType::rb()].iter() {
+        let mut tree = Bst::new(*t).unwrap();
+        for k in [5, 3, 8, 1, 4, 7, 9].iter() {
+            assert_eq!(tree.insert(*k, k * 10), None);
+        }
+        assert_eq!(tree.insert(4, 0), Some(40));
+        assert_eq!(tree.nodes(), 7);
+        assert_eq!(tree.find(&4), Some(&0));
+        assert_eq!(tree.find(&6), None);
+        assert_eq!(tree.remove(&5), Some((5, 50)));
+        assert_eq!(tree.remove(&5), None);
+        for (_, v) in tree.iter_mut() {
+            *v += 1;
+        }
+        assert_eq!(
+            tree.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+            [(1, 11), (3, 31), (4, 1), (7, 71), (8, 81), (9, 91)]
+        );
+        assert_eq!(tree.first(), Some((&1, &11)));
+        assert_eq!(tree.last(), Some((&9, &91)));
+        tree.clear();
+        assert!(tree.is_empty());
+    }
+}
@@ -8,6 +8,9 @@ use std::default::Default;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 
+#[cfg(feature = "num-complex")]
+use num_complex::{Complex32, Complex64};
+
 #[doc(hidden)]
 #[allow(clippy::upper_case_acronyms)]
 pub trait CFFI<T> {
@@ -22,6 +25,21 @@ pub trait FFFI<T> {
     fn unwrap(t: T) -> Self;
 }
 
+/// A complex number, stored as its real and imaginary parts.
+///
+/// The type is `#[repr(C)]` and has the same layout as GSL's `gsl_complex` and as `[f64; 2]`. A `&[ComplexF64]` can
+/// therefore be used where GSL expects a packed complex array of `f64` (real and imaginary parts interleaved), see
+/// [`as_packed`](ComplexF64::as_packed) and [`as_packed_mut`](ComplexF64::as_packed_mut).
+///
+/// The arithmetic operators are implemented between complex numbers and with real numbers on the right-hand side:
+///
+/// ```
+/// use rgsl::ComplexF64;
+///
+/// let z = ComplexF64::rect(1., 2.) * ComplexF64::rect(3., -1.) + 1.;
+/// assert_eq!(z, ComplexF64::rect(6., 5.));
+/// assert_eq!(format!("{}", -z), "-6-5i");
+/// ```
 #[repr(C)]
 #[derive(Copy, PartialEq)]
 pub struct ComplexF64 {
@@ -515,6 +533,21 @@ impl FFFI<ComplexF64> for sys::gsl_complex {
     }
 }
 
+/// A complex number, stored as its real and imaginary parts.
+///
+/// The type is `#[repr(C)]` and has the same layout as GSL's `gsl_complex_float` and as `[f32; 2]`. A `&[ComplexF32]` can
+/// therefore be used where GSL expects a packed complex array of `f32` (real and imaginary parts interleaved), see
+/// [`as_packed`](ComplexF32::as_packed) and [`as_packed_mut`](ComplexF32::as_packed_mut).
+///
+/// The arithmetic operators are implemented between complex numbers and with real numbers on the right-hand side:
+///
+/// ```
+/// use rgsl::ComplexF32;
+///
+/// let z = ComplexF32::rect(1., 2.) * ComplexF32::rect(3., -1.) + 1.;
+/// assert_eq!(z, ComplexF32::rect(6., 5.));
+/// assert_eq!(format!("{}", -z), "-6-5i");
+/// ```
 #[repr(C)]
 #[derive(Copy, PartialEq)]
 pub struct ComplexF32 {
@@ -981,6 +1014,227 @@ impl CFFI<sys::gsl_complex_float> for ComplexF32 {
     }
 }
 
+macro_rules! complex_ops {
+    ($name:ident, $ty:ident) => {
+        impl $name {
+            /// Returns the complex numbers of `s` as a packed array, with the real and imaginary parts
+            /// interleaved, as expected by the GSL functions taking a `gsl_complex_packed_array`.
+            pub fn as_packed(s: &[$name]) -> &[$ty] {
+                // Safe because of the `#[repr(C)]` layout, which is the one of `[$ty; 2]`.
+                unsafe { ::std::slice::from_raw_parts(s.as_ptr() as *const $ty, s.len() * 2) }
+            }
+
+            /// Mutable version of [`as_packed`](Self::as_packed).
+            pub fn as_packed_mut(s: &mut [$name]) -> &mut [$ty] {
+                unsafe {
+                    ::std::slice::from_raw_parts_mut(s.as_mut_ptr() as *mut $ty, s.len() * 2)
+                }
+            }
+
+            fn fmt_with(
+                &self,
+                f: &mut Formatter,
+                fmt: fn(&$ty, &mut Formatter) -> fmt::Result,
+            ) -> fmt::Result {
+                fmt(&self.dat[0], f)?;
+                if f.sign_plus() {
+                    // the sign of the imaginary part is already written by `fmt`
+                    fmt(&self.dat[1], f)?;
+                } else {
+                    f.write_str(if self.dat[1].is_sign_negative() { "-" } else { "+" })?;
+                    fmt(&self.dat[1].abs(), f)?;
+                }
+                f.write_str("i")
+            }
+        }
+
+        impl From<$ty> for $name {
+            fn from(re: $ty) -> $name {
+                $name { dat: [re, 0.] }
+            }
+        }
+
+        impl From<($ty, $ty)> for $name {
+            fn from((re, im): ($ty, $ty)) -> $name {
+                $name { dat: [re, im] }
+            }
+        }
+
+        impl From<$name> for ($ty, $ty) {
+            fn from(z: $name) -> ($ty, $ty) {
+                (z.dat[0], z.dat[1])
+            }
+        }
+
+        /// Formats the number as `a+bi`. The formatting options apply to both parts.
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                self.fmt_with(f, fmt::Display::fmt)
+            }
+        }
+
+        impl fmt::LowerExp for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                self.fmt_with(f, fmt::LowerExp::fmt)
+            }
+        }
+
+        impl fmt::UpperExp for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                self.fmt_with(f, fmt::UpperExp::fmt)
+            }
+        }
+
+        impl ::std::ops::Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name::negative(&self)
+            }
+        }
+
+        impl<'a> ::std::ops::Neg for &'a $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name::negative(self)
+            }
+        }
+
+        complex_ops!(@binary $name, $ty, Add, add, AddAssign, add_assign, add_real);
+        complex_ops!(@binary $name, $ty, Sub, sub, SubAssign, sub_assign, sub_real);
+        complex_ops!(@binary $name, $ty, Mul, mul, MulAssign, mul_assign, mul_real);
+        complex_ops!(@binary $name, $ty, Div, div, DivAssign, div_assign, div_real);
+
+        impl ::std::iter::Sum for $name {
+            fn sum<I: Iterator<Item = $name>>(iter: I) -> $name {
+                iter.fold($name::default(), |acc, z| acc + z)
+            }
+        }
+
+        impl<'a> ::std::iter::Sum<&'a $name> for $name {
+            fn sum<I: Iterator<Item = &'a $name>>(iter: I) -> $name {
+                iter.fold($name::default(), |acc, z| acc + z)
+            }
+        }
+
+        impl ::std::iter::Product for $name {
+            fn product<I: Iterator<Item = $name>>(iter: I) -> $name {
+                iter.fold($name::from(1.), |acc, z| acc * z)
+            }
+        }
+
+        impl<'a> ::std::iter::Product<&'a $name> for $name {
+            fn product<I: Iterator<Item = &'a $name>>(iter: I) -> $name {
+                iter.fold($name::from(1.), |acc, z| acc * z)
+            }
+        }
+    };
+    (@binary $name:ident, $ty:ident, $op:ident, $method:ident, $op_assign:ident, $method_assign:ident,
+     $method_real:ident) => {
+        impl ::std::ops::$op for $name {
+            type Output = $name;
+
+            fn $method(self, other: $name) -> $name {
+                $name::$method(&self, &other)
+            }
+        }
+
+        impl<'a> ::std::ops::$op<&'a $name> for $name {
+            type Output = $name;
+
+            fn $method(self, other: &$name) -> $name {
+                $name::$method(&self, other)
+            }
+        }
+
+        impl<'a> ::std::ops::$op<$name> for &'a $name {
+            type Output = $name;
+
+            fn $method(self, other: $name) -> $name {
+                $name::$method(self, &other)
+            }
+        }
+
+        impl<'a, 'b> ::std::ops::$op<&'b $name> for &'a $name {
+            type Output = $name;
+
+            fn $method(self, other: &$name) -> $name {
+                $name::$method(self, other)
+            }
+        }
+
+        impl ::std::ops::$op<$ty> for $name {
+            type Output = $name;
+
+            fn $method(self, other: $ty) -> $name {
+                $name::$method_real(&self, other)
+            }
+        }
+
+        impl ::std::ops::$op_assign for $name {
+            fn $method_assign(&mut self, other: $name) {
+                *self = $name::$method(self, &other);
+            }
+        }
+
+        impl<'a> ::std::ops::$op_assign<&'a $name> for $name {
+            fn $method_assign(&mut self, other: &$name) {
+                *self = $name::$method(self, other);
+            }
+        }
+
+        impl ::std::ops::$op_assign<$ty> for $name {
+            fn $method_assign(&mut self, other: $ty) {
+                *self = $name::$method_real(self, other);
+            }
+        }
+    };
+}
+
+complex_ops!(ComplexF64, f64);
+complex_ops!(ComplexF32, f32);
+
+impl From<ComplexF32> for ComplexF64 {
+    fn from(z: ComplexF32) -> ComplexF64 {
+        ComplexF64 {
+            dat: [z.dat[0] as f64, z.dat[1] as f64],
+        }
+    }
+}
+
+#[cfg(feature = "num-complex")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+impl From<Complex64> for ComplexF64 {
+    fn from(z: Complex64) -> ComplexF64 {
+        ComplexF64 { dat: [z.re, z.im] }
+    }
+}
+
+#[cfg(feature = "num-complex")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+impl From<ComplexF64> for Complex64 {
+    fn from(z: ComplexF64) -> Complex64 {
+        Complex64::new(z.dat[0], z.dat[1])
+    }
+}
+
+#[cfg(feature = "num-complex")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+impl From<Complex32> for ComplexF32 {
+    fn from(z: Complex32) -> ComplexF32 {
+        ComplexF32 { dat: [z.re, z.im] }
+    }
+}
+
+#[cfg(feature = "num-complex")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+impl From<ComplexF32> for Complex32 {
+    fn from(z: ComplexF32) -> Complex32 {
+        Complex32::new(z.dat[0], z.dat[1])
+    }
+}
+
 // All these tests have been tested against the following C code:
 //
 // ```ignore
@@ -1813,3 +2067,48 @@ fn complex_f32() {
         "0.1493 -0.1336".to_owned()
     );
 }
+
+#[test]
+fn complex_ops() {
+    let a = ComplexF64::rect(1., 2.);
+    let b = ComplexF64::rect(3., -1.);
+    assert_eq!(a + b, ComplexF64::rect(4., 1.));
+    let (ra, rb) = (&a, &b);
+    assert_eq!(ra - rb, ComplexF64::rect(-2., 3.));
+    assert_eq!(a * b, ComplexF64::rect(5., 5.));
+    let q = (a * b) / b;
+    assert_eq!(format!("{:.4} {:.4}", q.dat[0], q.dat[1]), "1.0000 2.0000");
+    assert_eq!(-a, ComplexF64::rect(-1., -2.));
+    assert_eq!(a * 2., ComplexF64::rect(2., 4.));
+
+    let mut c = a;
+    c += b;
+    c -= 1.;
+    assert_eq!(c, ComplexF64::rect(3., 1.));
+
+    let v = [a, b, ComplexF64::from(2.)];
+    assert_eq!(v.iter().sum::<ComplexF64>(), ComplexF64::rect(6., 1.));
+    assert_eq!(v.iter().product::<ComplexF64>(), ComplexF64::rect(10., 10.));
+    assert_eq!(ComplexF64::as_packed(&v), &[1., 2., 3., -1., 2., 0.]);
+
+    assert_eq!(format!("{}", b), "3-1i");
+    assert_eq!(format!("{:.1}", a), "1.0+2.0i");
+    assert_eq!(format!("{:+}", b), "+3-1i");
+    assert_eq!(format!("{:e}", a), "1e0+2e0i");
+
+    let z = ComplexF32::rect(1., -2.) + ComplexF32::from((1., 1.));
+    assert_eq!(z, ComplexF32::rect(2., -1.));
+    assert_eq!(ComplexF64::from(z), ComplexF64::rect(2., -1.));
+    assert_eq!(<(f32, f32)>::from(z), (2., -1.));
+}
+
+#[cfg(feature = "num-complex")]
+#[test]
+fn num_complex_conversions() {
+    let z = Complex64::new(1.5, -2.);
+    assert_eq!(ComplexF64::from(z).real(), 1.5);
+    assert_eq!(Complex64::from(ComplexF64::from(z)), z);
+    let z = Complex32::new(0.25, 3.);
+    assert_eq!(ComplexF32::from(z).imaginary(), 3.);
+    assert_eq!(Complex32::from(ComplexF32::from(z)), z);
+}
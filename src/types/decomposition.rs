@@ -0,0 +1,1177 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Matrix decompositions
+
+Owned versions of the decompositions of the [`linear_algebra`](../../linear_algebra/index.html) module. Each type holds
+the factored matrix together with the permutation, Householder coefficients or singular vectors that go with it, and
+checks the dimensions of its inputs before calling GSL, so the packed outputs can't be mixed up or misused:
+
+```
+use rgsl::{Lu, MatrixF64, VectorF64};
+
+let mut a = MatrixF64::new(2, 2).unwrap();
+a.set(0, 0, 4.);
+a.set(0, 1, 3.);
+a.set(1, 0, 6.);
+a.set(1, 1, 3.);
+
+let lu = Lu::new(&a).unwrap();
+assert!((lu.det() + 6.).abs() < 1e-12);
+let x = lu.solve(&VectorF64::from_slice(&[10., 12.]).unwrap()).unwrap();
+assert!((x.get(0) - 1.).abs() < 1e-12 && (x.get(1) - 2.).abs() < 1e-12);
+```
+
+Decompositions of an existing matrix are done on a copy (`new`), or in place when the matrix is given by value
+(`from_matrix`).
+!*/
+
+use crate::Value;
+use ffi::FFI;
+use linear_algebra;
+use {MatrixF64, Permutation, VectorF64};
+
+pub(crate) fn matrix(n1: usize, n2: usize) -> Result<MatrixF64, Value> {
+    MatrixF64::new(n1, n2).ok_or(Value::NoMemory)
+}
+
+fn vector(n: usize) -> Result<VectorF64, Value> {
+    VectorF64::new(n).ok_or(Value::NoMemory)
+}
+
+pub(crate) fn identity(n: usize) -> Result<MatrixF64, Value> {
+    let mut m = matrix(n, n)?;
+    m.set_identity();
+    Ok(m)
+}
+
+fn copy_matrix(a: &MatrixF64) -> Result<MatrixF64, Value> {
+    let mut m = matrix(a.size1(), a.size2())?;
+    m.copy_from(a).to_result()?;
+    Ok(m)
+}
+
+fn copy_vector(v: &VectorF64) -> Result<VectorF64, Value> {
+    let mut c = vector(v.len())?;
+    c.copy_from(v).to_result()?;
+    Ok(c)
+}
+
+pub(crate) fn check_square(a: &MatrixF64) -> Result<usize, Value> {
+    if a.size1() == a.size2() {
+        Ok(a.size1())
+    } else {
+        Err(Value::NotSquare)
+    }
+}
+
+fn check_len(v: &VectorF64, n: usize) -> Result<(), Value> {
+    if v.len() == n {
+        Ok(())
+    } else {
+        Err(Value::BadLength)
+    }
+}
+
+fn check_rows(b: &MatrixF64, n: usize) -> Result<(), Value> {
+    if b.size1() == n {
+        Ok(())
+    } else {
+        Err(Value::BadLength)
+    }
+}
+
+/// Returns an error if the diagonal of the n x n upper triangle of `a` has a zero, like GSL does before solving.
+fn check_nonsingular(a: &MatrixF64, n: usize) -> Result<(), Value> {
+    if (0..n).any(|i| a.get(i, i) == 0.) {
+        Err(Value::Domain)
+    } else {
+        Ok(())
+    }
+}
+
+fn diagonal_product(a: &MatrixF64, n: usize) -> f64 {
+    (0..n).map(|i| a.get(i, i)).product()
+}
+
+fn diagonal_ln_abs(a: &MatrixF64, n: usize) -> f64 {
+    (0..n).map(|i| a.get(i, i).abs().ln()).sum()
+}
+
+/// Returns the sign of the determinant of the Q encoded in `tau`: each non-trivial Householder reflection flips it.
+fn householder_sign(tau: &VectorF64) -> f64 {
+    if (0..tau.len()).filter(|&i| tau.get(i) != 0.).count() % 2 == 0 {
+        1.
+    } else {
+        -1.
+    }
+}
+
+/// Returns a copy of the lower (or upper) triangle of the n1 x n2 top-left part of `a`, with `unit` on the diagonal if
+/// given.
+fn triangle(
+    a: &MatrixF64,
+    n1: usize,
+    n2: usize,
+    lower: bool,
+    unit: Option<f64>,
+) -> Result<MatrixF64, Value> {
+    let mut t = matrix(n1, n2)?;
+    for i in 0..n1 {
+        for j in 0..n2 {
+            if i == j {
+                t.set(i, j, unit.unwrap_or_else(|| a.get(i, j)));
+            } else if (i > j) == lower {
+                t.set(i, j, a.get(i, j));
+            }
+        }
+    }
+    Ok(t)
+}
+
+fn solve_columns<F: Fn(&VectorF64) -> Result<VectorF64, Value>>(
+    b: &MatrixF64,
+    rows: usize,
+    solve: F,
+) -> Result<MatrixF64, Value> {
+    let mut x = matrix(rows, b.size2())?;
+    for j in 0..b.size2() {
+        let (ret, col) = b.get_col(j).ok_or(Value::NoMemory)?;
+        ret.to_result()?;
+        x.set_col(j, &solve(&col)?).to_result()?;
+    }
+    Ok(x)
+}
+
+fn solve_columns_in_place<F: Fn(&mut VectorF64) -> Result<(), Value>>(
+    b: &mut MatrixF64,
+    solve: F,
+) -> Result<(), Value> {
+    for j in 0..b.size2() {
+        let (ret, mut col) = b.get_col(j).ok_or(Value::NoMemory)?;
+        ret.to_result()?;
+        solve(&mut col)?;
+        b.set_col(j, &col).to_result()?;
+    }
+    Ok(())
+}
+
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
+fn upper_rcond(a: &MatrixF64, n: usize) -> Result<f64, Value> {
+    let mut work = vector(3 * n)?;
+    let mut rcond = 0.;
+    let r = unsafe { sys::gsl_matrix_const_submatrix(a.unwrap_shared(), 0, 0, n, n) };
+    Value::from(unsafe {
+        sys::gsl_linalg_tri_upper_rcond(&r.matrix, &mut rcond, work.unwrap_unique())
+    })
+    .to_result()?;
+    Ok(rcond)
+}
+
+/// The LU decomposition P A = L U of a square matrix.
+pub struct Lu {
+    lu: MatrixF64,
+    p: Permutation,
+    signum: i32,
+}
+
+impl Lu {
+    /// Decomposes a copy of `a`.
+    pub fn new(a: &MatrixF64) -> Result<Lu, Value> {
+        check_square(a)?;
+        Lu::from_matrix(copy_matrix(a)?)
+    }
+
+    /// Decomposes `a` in place.
+    #[doc(alias = "gsl_linalg_LU_decomp")]
+    pub fn from_matrix(mut a: MatrixF64) -> Result<Lu, Value> {
+        let n = check_square(&a)?;
+        let mut p = Permutation::new(n).ok_or(Value::NoMemory)?;
+        let mut signum = 0;
+        linear_algebra::LU_decomp(&mut a, &mut p, &mut signum).to_result()?;
+        Ok(Lu { lu: a, p, signum })
+    }
+
+    /// Returns the order of the decomposed matrix.
+    pub fn size(&self) -> usize {
+        self.lu.size1()
+    }
+
+    /// Returns the packed decomposition: L below the diagonal (its unit diagonal isn't stored) and U on and above it.
+    pub fn packed(&self) -> &MatrixF64 {
+        &self.lu
+    }
+
+    /// Returns the permutation P.
+    pub fn permutation(&self) -> &Permutation {
+        &self.p
+    }
+
+    /// Returns the unit lower triangular matrix L.
+    pub fn l(&self) -> Result<MatrixF64, Value> {
+        triangle(&self.lu, self.size(), self.size(), true, Some(1.))
+    }
+
+    /// Returns the upper triangular matrix U.
+    pub fn u(&self) -> Result<MatrixF64, Value> {
+        triangle(&self.lu, self.size(), self.size(), false, None)
+    }
+
+    /// Returns `true` if U has a zero on its diagonal.
+    pub fn is_singular(&self) -> bool {
+        check_nonsingular(&self.lu, self.size()).is_err()
+    }
+
+    /// Solves A x = b.
+    #[doc(alias = "gsl_linalg_LU_solve")]
+    pub fn solve(&self, b: &VectorF64) -> Result<VectorF64, Value> {
+        let mut x = copy_vector(b)?;
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves A x = b in place: `x` holds b on input and the solution on output.
+    #[doc(alias = "gsl_linalg_LU_svx")]
+    pub fn solve_in_place(&self, x: &mut VectorF64) -> Result<(), Value> {
+        check_len(x, self.size())?;
+        check_nonsingular(&self.lu, self.size())?;
+        linear_algebra::LU_svx(&self.lu, &self.p, x).to_result()
+    }
+
+    /// Solves A X = B, one column of B at a time.
+    pub fn solve_matrix(&self, b: &MatrixF64) -> Result<MatrixF64, Value> {
+        let mut x = copy_matrix(b)?;
+        self.solve_matrix_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves A X = B in place: `x` holds B on input and the solution on output.
+    pub fn solve_matrix_in_place(&self, x: &mut MatrixF64) -> Result<(), Value> {
+        check_rows(x, self.size())?;
+        solve_columns_in_place(x, |col| self.solve_in_place(col))
+    }
+
+    /// Returns the determinant of A.
+    #[doc(alias = "gsl_linalg_LU_det")]
+    pub fn det(&self) -> f64 {
+        // GSL only reads the matrix.
+        unsafe { sys::gsl_linalg_LU_det(self.lu.unwrap_shared() as *mut _, self.signum) }
+    }
+
+    /// Returns the logarithm of the absolute value of the determinant of A.
+    #[doc(alias = "gsl_linalg_LU_lndet")]
+    pub fn ln_det(&self) -> f64 {
+        unsafe { sys::gsl_linalg_LU_lndet(self.lu.unwrap_shared() as *mut _) }
+    }
+
+    /// Returns the sign of the determinant of A.
+    #[doc(alias = "gsl_linalg_LU_sgndet")]
+    pub fn sign_det(&self) -> i32 {
+        unsafe { sys::gsl_linalg_LU_sgndet(self.lu.unwrap_shared() as *mut _, self.signum) }
+    }
+
+    /// Returns the inverse of A.
+    #[doc(alias = "gsl_linalg_LU_invert")]
+    pub fn inverse(&self) -> Result<MatrixF64, Value> {
+        check_nonsingular(&self.lu, self.size())?;
+        let mut inverse = matrix(self.size(), self.size())?;
+        linear_algebra::LU_invert(&self.lu, &self.p, &mut inverse).to_result()?;
+        Ok(inverse)
+    }
+}
+
+/// The QR decomposition A = Q R of a M x N matrix.
+pub struct Qr {
+    qr: MatrixF64,
+    tau: VectorF64,
+}
+
+impl Qr {
+    /// Decomposes a copy of `a`.
+    pub fn new(a: &MatrixF64) -> Result<Qr, Value> {
+        Qr::from_matrix(copy_matrix(a)?)
+    }
+
+    /// Decomposes `a` in place.
+    #[doc(alias = "gsl_linalg_QR_decomp")]
+    pub fn from_matrix(mut a: MatrixF64) -> Result<Qr, Value> {
+        let mut tau = vector(a.size1().min(a.size2()))?;
+        linear_algebra::QR_decomp(&mut a, &mut tau).to_result()?;
+        Ok(Qr { qr: a, tau })
+    }
+
+    /// Returns the packed decomposition: R on and above the diagonal, the Householder vectors below it.
+    pub fn packed(&self) -> &MatrixF64 {
+        &self.qr
+    }
+
+    /// Returns the Householder coefficients.
+    pub fn tau(&self) -> &VectorF64 {
+        &self.tau
+    }
+
+    fn square(&self) -> Result<usize, Value> {
+        check_square(&self.qr)
+    }
+
+    /// Returns the M x M orthogonal matrix Q.
+    #[doc(alias = "gsl_linalg_QR_unpack")]
+    pub fn q(&self) -> Result<MatrixF64, Value> {
+        Ok(self.unpack()?.0)
+    }
+
+    /// Returns the M x N upper triangular matrix R.
+    pub fn r(&self) -> Result<MatrixF64, Value> {
+        triangle(&self.qr, self.qr.size1(), self.qr.size2(), false, None)
+    }
+
+    fn unpack(&self) -> Result<(MatrixF64, MatrixF64), Value> {
+        let (m, n) = (self.qr.size1(), self.qr.size2());
+        let mut q = matrix(m, m)?;
+        let mut r = matrix(m, n)?;
+        linear_algebra::QR_unpack(&self.qr, &self.tau, &mut q, &mut r).to_result()?;
+        Ok((q, r))
+    }
+
+    /// Solves A x = b. If A has more rows than columns, returns the least squares solution.
+    #[doc(alias = "gsl_linalg_QR_solve")]
+    pub fn solve(&self, b: &VectorF64) -> Result<VectorF64, Value> {
+        if self.qr.size1() == self.qr.size2() {
+            let mut x = copy_vector(b)?;
+            self.solve_in_place(&mut x)?;
+            Ok(x)
+        } else {
+            Ok(self.lssolve(b)?.0)
+        }
+    }
+
+    /// Solves the square system A x = b in place: `x` holds b on input and the solution on output.
+    #[doc(alias = "gsl_linalg_QR_svx")]
+    pub fn solve_in_place(&self, x: &mut VectorF64) -> Result<(), Value> {
+        let n = self.square()?;
+        check_len(x, n)?;
+        check_nonsingular(&self.qr, n)?;
+        linear_algebra::QR_svx(&self.qr, &self.tau, x).to_result()
+    }
+
+    /// Returns the least squares solution of A x = b, where A has at least as many rows as columns, and the residual
+    /// b - A x.
+    #[doc(alias = "gsl_linalg_QR_lssolve")]
+    pub fn lssolve(&self, b: &VectorF64) -> Result<(VectorF64, VectorF64), Value> {
+        let (m, n) = (self.qr.size1(), self.qr.size2());
+        if m < n {
+            return Err(Value::BadLength);
+        }
+        check_len(b, m)?;
+        check_nonsingular(&self.qr, n)?;
+        let mut x = vector(n)?;
+        let mut residual = vector(m)?;
+        linear_algebra::QR_lssolve(&self.qr, &self.tau, b, &mut x, &mut residual).to_result()?;
+        Ok((x, residual))
+    }
+
+    /// Solves A X = B, one column of B at a time. If A has more rows than columns, returns the least squares solution.
+    pub fn solve_matrix(&self, b: &MatrixF64) -> Result<MatrixF64, Value> {
+        check_rows(b, self.qr.size1())?;
+        solve_columns(b, self.qr.size2(), |col| self.solve(col))
+    }
+
+    /// Solves the square system A X = B in place: `x` holds B on input and the solution on output.
+    pub fn solve_matrix_in_place(&self, x: &mut MatrixF64) -> Result<(), Value> {
+        check_rows(x, self.square()?)?;
+        solve_columns_in_place(x, |col| self.solve_in_place(col))
+    }
+
+    /// Returns the determinant of the square matrix A.
+    pub fn det(&self) -> Result<f64, Value> {
+        let n = self.square()?;
+        Ok(householder_sign(&self.tau) * diagonal_product(&self.qr, n))
+    }
+
+    /// Returns the logarithm of the absolute value of the determinant of the square matrix A.
+    pub fn ln_det(&self) -> Result<f64, Value> {
+        let n = self.square()?;
+        Ok(diagonal_ln_abs(&self.qr, n))
+    }
+
+    /// Returns the inverse of the square matrix A.
+    pub fn inverse(&self) -> Result<MatrixF64, Value> {
+        let mut inverse = identity(self.square()?)?;
+        self.solve_matrix_in_place(&mut inverse)?;
+        Ok(inverse)
+    }
+
+    /// Returns the reciprocal condition number (in the 1-norm) of the N x N upper triangle of R, for A with at least as
+    /// many rows as columns.
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[doc(alias = "gsl_linalg_tri_upper_rcond")]
+    pub fn rcond(&self) -> Result<f64, Value> {
+        let (m, n) = (self.qr.size1(), self.qr.size2());
+        if m < n {
+            return Err(Value::BadLength);
+        }
+        upper_rcond(&self.qr, n)
+    }
+}
+
+/// The QR decomposition with column pivoting A P = Q R of a M x N matrix.
+pub struct QrPivoted {
+    qr: MatrixF64,
+    tau: VectorF64,
+    p: Permutation,
+    signum: i32,
+}
+
+impl QrPivoted {
+    /// Decomposes a copy of `a`.
+    pub fn new(a: &MatrixF64) -> Result<QrPivoted, Value> {
+        QrPivoted::from_matrix(copy_matrix(a)?)
+    }
+
+    /// Decomposes `a` in place.
+    #[doc(alias = "gsl_linalg_QRPT_decomp")]
+    pub fn from_matrix(mut a: MatrixF64) -> Result<QrPivoted, Value> {
+        let n = a.size2();
+        let mut tau = vector(a.size1().min(n))?;
+        let mut p = Permutation::new(n).ok_or(Value::NoMemory)?;
+        let mut norm = vector(n)?;
+        let mut signum = 0;
+        linear_algebra::QRPT_decomp(&mut a, &mut tau, &mut p, &mut signum, &mut norm)
+            .to_result()?;
+        Ok(QrPivoted {
+            qr: a,
+            tau,
+            p,
+            signum,
+        })
+    }
+
+    /// Returns the packed decomposition: R on and above the diagonal, the Householder vectors below it.
+    pub fn packed(&self) -> &MatrixF64 {
+        &self.qr
+    }
+
+    /// Returns the Householder coefficients.
+    pub fn tau(&self) -> &VectorF64 {
+        &self.tau
+    }
+
+    /// Returns the column permutation P.
+    pub fn permutation(&self) -> &Permutation {
+        &self.p
+    }
+
+    fn square(&self) -> Result<usize, Value> {
+        check_square(&self.qr)
+    }
+
+    /// Returns the M x M orthogonal matrix Q.
+    #[doc(alias = "gsl_linalg_QR_unpack")]
+    pub fn q(&self) -> Result<MatrixF64, Value> {
+        let (m, n) = (self.qr.size1(), self.qr.size2());
+        let mut q = matrix(m, m)?;
+        let mut r = matrix(m, n)?;
+        linear_algebra::QR_unpack(&self.qr, &self.tau, &mut q, &mut r).to_result()?;
+        Ok(q)
+    }
+
+    /// Returns the M x N upper triangular matrix R.
+    pub fn r(&self) -> Result<MatrixF64, Value> {
+        triangle(&self.qr, self.qr.size1(), self.qr.size2(), false, None)
+    }
+
+    /// Returns the number of diagonal elements of R larger than `tol` in absolute value, which estimates the rank of A.
+    pub fn rank(&self, tol: f64) -> usize {
+        let k = self.tau.len();
+        (0..k).filter(|&i| self.qr.get(i, i).abs() > tol).count()
+    }
+
+    /// Solves the square system A x = b.
+    #[doc(alias = "gsl_linalg_QRPT_solve")]
+    pub fn solve(&self, b: &VectorF64) -> Result<VectorF64, Value> {
+        let mut x = copy_vector(b)?;
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves the square system A x = b in place: `x` holds b on input and the solution on output.
+    #[doc(alias = "gsl_linalg_QRPT_svx")]
+    pub fn solve_in_place(&self, x: &mut VectorF64) -> Result<(), Value> {
+        let n = self.square()?;
+        check_len(x, n)?;
+        check_nonsingular(&self.qr, n)?;
+        linear_algebra::QRPT_svx(&self.qr, &self.tau, &self.p, x).to_result()
+    }
+
+    /// Returns the least squares solution of A x = b, where A has at least as many rows as columns, and the residual
+    /// b - A x.
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[doc(alias = "gsl_linalg_QRPT_lssolve")]
+    pub fn lssolve(&self, b: &VectorF64) -> Result<(VectorF64, VectorF64), Value> {
+        let (m, n) = (self.qr.size1(), self.qr.size2());
+        if m < n {
+            return Err(Value::BadLength);
+        }
+        check_len(b, m)?;
+        check_nonsingular(&self.qr, n)?;
+        let mut x = vector(n)?;
+        let mut residual = vector(m)?;
+        Value::from(unsafe {
+            sys::gsl_linalg_QRPT_lssolve(
+                self.qr.unwrap_shared(),
+                self.tau.unwrap_shared(),
+                self.p.unwrap_shared(),
+                b.unwrap_shared(),
+                x.unwrap_unique(),
+                residual.unwrap_unique(),
+            )
+        })
+        .to_result()?;
+        Ok((x, residual))
+    }
+
+    /// Solves the square system A X = B, one column of B at a time.
+    pub fn solve_matrix(&self, b: &MatrixF64) -> Result<MatrixF64, Value> {
+        let mut x = copy_matrix(b)?;
+        self.solve_matrix_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves the square system A X = B in place: `x` holds B on input and the solution on output.
+    pub fn solve_matrix_in_place(&self, x: &mut MatrixF64) -> Result<(), Value> {
+        check_rows(x, self.square()?)?;
+        solve_columns_in_place(x, |col| self.solve_in_place(col))
+    }
+
+    /// Returns the determinant of the square matrix A.
+    pub fn det(&self) -> Result<f64, Value> {
+        let n = self.square()?;
+        Ok(self.signum as f64 * householder_sign(&self.tau) * diagonal_product(&self.qr, n))
+    }
+
+    /// Returns the logarithm of the absolute value of the determinant of the square matrix A.
+    pub fn ln_det(&self) -> Result<f64, Value> {
+        let n = self.square()?;
+        Ok(diagonal_ln_abs(&self.qr, n))
+    }
+
+    /// Returns the inverse of the square matrix A.
+    pub fn inverse(&self) -> Result<MatrixF64, Value> {
+        let mut inverse = identity(self.square()?)?;
+        self.solve_matrix_in_place(&mut inverse)?;
+        Ok(inverse)
+    }
+
+    /// Returns the reciprocal condition number (in the 1-norm) of R, for A with at least as many rows as columns.
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[doc(alias = "gsl_linalg_QRPT_rcond")]
+    pub fn rcond(&self) -> Result<f64, Value> {
+        let (m, n) = (self.qr.size1(), self.qr.size2());
+        if m < n {
+            return Err(Value::BadLength);
+        }
+        let mut work = vector(3 * n)?;
+        let mut rcond = 0.;
+        Value::from(unsafe {
+            sys::gsl_linalg_QRPT_rcond(self.qr.unwrap_shared(), &mut rcond, work.unwrap_unique())
+        })
+        .to_result()?;
+        Ok(rcond)
+    }
+}
+
+/// The Cholesky decomposition A = L L^T of a symmetric, positive definite matrix.
+pub struct Cholesky {
+    llt: MatrixF64,
+}
+
+impl Cholesky {
+    /// Decomposes a copy of `a`. Only its diagonal and lower triangle are read. See
+    /// [`from_matrix`](#method.from_matrix) for the matrices which aren't positive definite.
+    pub fn new(a: &MatrixF64) -> Result<Cholesky, Value> {
+        check_square(a)?;
+        Cholesky::from_matrix(copy_matrix(a)?)
+    }
+
+    /// Decomposes `a` in place.
+    ///
+    /// GSL reports a matrix which isn't positive definite through its error handler, which aborts by default. Turn it
+    /// off with [`set_error_handler_off`](../../error/fn.set_error_handler_off.html) first to get `Value::Domain`
+    /// instead, e.g. when testing whether `a` is positive definite.
+    #[doc(alias = "gsl_linalg_cholesky_decomp")]
+    pub fn from_matrix(mut a: MatrixF64) -> Result<Cholesky, Value> {
+        check_square(&a)?;
+        linear_algebra::cholesky_decomp(&mut a).to_result()?;
+        Ok(Cholesky { llt: a })
+    }
+
+    /// Returns the order of the decomposed matrix.
+    pub fn size(&self) -> usize {
+        self.llt.size1()
+    }
+
+    /// Returns the packed decomposition: L on and below the diagonal, L^T on and above it.
+    pub fn packed(&self) -> &MatrixF64 {
+        &self.llt
+    }
+
+    /// Returns the lower triangular matrix L.
+    pub fn l(&self) -> Result<MatrixF64, Value> {
+        triangle(&self.llt, self.size(), self.size(), true, None)
+    }
+
+    /// Solves A x = b.
+    #[doc(alias = "gsl_linalg_cholesky_solve")]
+    pub fn solve(&self, b: &VectorF64) -> Result<VectorF64, Value> {
+        let mut x = copy_vector(b)?;
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves A x = b in place: `x` holds b on input and the solution on output.
+    #[doc(alias = "gsl_linalg_cholesky_svx")]
+    pub fn solve_in_place(&self, x: &mut VectorF64) -> Result<(), Value> {
+        check_len(x, self.size())?;
+        linear_algebra::cholesky_svx(&self.llt, x).to_result()
+    }
+
+    /// Solves A X = B, one column of B at a time.
+    pub fn solve_matrix(&self, b: &MatrixF64) -> Result<MatrixF64, Value> {
+        let mut x = copy_matrix(b)?;
+        self.solve_matrix_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves A X = B in place: `x` holds B on input and the solution on output.
+    pub fn solve_matrix_in_place(&self, x: &mut MatrixF64) -> Result<(), Value> {
+        check_rows(x, self.size())?;
+        solve_columns_in_place(x, |col| self.solve_in_place(col))
+    }
+
+    /// Returns the determinant of A, the squared product of the diagonal of L.
+    pub fn det(&self) -> f64 {
+        let d = diagonal_product(&self.llt, self.size());
+        d * d
+    }
+
+    /// Returns the logarithm of the determinant of A.
+    pub fn ln_det(&self) -> f64 {
+        2. * diagonal_ln_abs(&self.llt, self.size())
+    }
+
+    /// Returns the inverse of A.
+    #[doc(alias = "gsl_linalg_cholesky_invert")]
+    pub fn inverse(&self) -> Result<MatrixF64, Value> {
+        let mut inverse = copy_matrix(&self.llt)?;
+        linear_algebra::cholesky_invert(&mut inverse).to_result()?;
+        Ok(inverse)
+    }
+
+    /// Returns the reciprocal condition number of A, in the 1-norm.
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[doc(alias = "gsl_linalg_cholesky_rcond")]
+    pub fn rcond(&self) -> Result<f64, Value> {
+        let mut work = vector(3 * self.size())?;
+        let mut rcond = 0.;
+        Value::from(unsafe {
+            sys::gsl_linalg_cholesky_rcond(
+                self.llt.unwrap_shared(),
+                &mut rcond,
+                work.unwrap_unique(),
+            )
+        })
+        .to_result()?;
+        Ok(rcond)
+    }
+}
+
+/// The decomposition A = L D L^T of a symmetric matrix, with L unit lower triangular and D diagonal.
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+pub struct Ldlt {
+    ldlt: MatrixF64,
+}
+
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+impl Ldlt {
+    /// Decomposes a copy of `a`. Only its diagonal and lower triangle are read.
+    pub fn new(a: &MatrixF64) -> Result<Ldlt, Value> {
+        check_square(a)?;
+        Ldlt::from_matrix(copy_matrix(a)?)
+    }
+
+    /// Decomposes `a` in place.
+    #[doc(alias = "gsl_linalg_ldlt_decomp")]
+    pub fn from_matrix(mut a: MatrixF64) -> Result<Ldlt, Value> {
+        check_square(&a)?;
+        linear_algebra::ldlt_decomp(&mut a).to_result()?;
+        Ok(Ldlt { ldlt: a })
+    }
+
+    /// Returns the order of the decomposed matrix.
+    pub fn size(&self) -> usize {
+        self.ldlt.size1()
+    }
+
+    /// Returns the packed decomposition: D on the diagonal and L below it (its unit diagonal isn't stored).
+    pub fn packed(&self) -> &MatrixF64 {
+        &self.ldlt
+    }
+
+    /// Returns the unit lower triangular matrix L.
+    pub fn l(&self) -> Result<MatrixF64, Value> {
+        triangle(&self.ldlt, self.size(), self.size(), true, Some(1.))
+    }
+
+    /// Returns the diagonal of D.
+    pub fn d(&self) -> Result<VectorF64, Value> {
+        let mut d = vector(self.size())?;
+        for i in 0..self.size() {
+            d.set(i, self.ldlt.get(i, i));
+        }
+        Ok(d)
+    }
+
+    /// Solves A x = b.
+    #[doc(alias = "gsl_linalg_ldlt_solve")]
+    pub fn solve(&self, b: &VectorF64) -> Result<VectorF64, Value> {
+        let mut x = copy_vector(b)?;
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves A x = b in place: `x` holds b on input and the solution on output.
+    #[doc(alias = "gsl_linalg_ldlt_svx")]
+    pub fn solve_in_place(&self, x: &mut VectorF64) -> Result<(), Value> {
+        check_len(x, self.size())?;
+        check_nonsingular(&self.ldlt, self.size())?;
+        linear_algebra::ldlt_svx(&self.ldlt, x).to_result()
+    }
+
+    /// Solves A X = B, one column of B at a time.
+    pub fn solve_matrix(&self, b: &MatrixF64) -> Result<MatrixF64, Value> {
+        let mut x = copy_matrix(b)?;
+        self.solve_matrix_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves A X = B in place: `x` holds B on input and the solution on output.
+    pub fn solve_matrix_in_place(&self, x: &mut MatrixF64) -> Result<(), Value> {
+        check_rows(x, self.size())?;
+        solve_columns_in_place(x, |col| self.solve_in_place(col))
+    }
+
+    /// Returns the determinant of A, the product of the diagonal of D.
+    pub fn det(&self) -> f64 {
+        diagonal_product(&self.ldlt, self.size())
+    }
+
+    /// Returns the logarithm of the absolute value of the determinant of A.
+    pub fn ln_det(&self) -> f64 {
+        diagonal_ln_abs(&self.ldlt, self.size())
+    }
+
+    /// Returns the inverse of A.
+    pub fn inverse(&self) -> Result<MatrixF64, Value> {
+        let mut inverse = identity(self.size())?;
+        self.solve_matrix_in_place(&mut inverse)?;
+        Ok(inverse)
+    }
+
+    /// Returns the reciprocal condition number of A, in the 1-norm.
+    #[doc(alias = "gsl_linalg_ldlt_rcond")]
+    pub fn rcond(&self) -> Result<f64, Value> {
+        let mut work = vector(3 * self.size())?;
+        let (ret, rcond) = linear_algebra::ldlt_rcond(&self.ldlt, &mut work);
+        ret.to_result()?;
+        Ok(rcond)
+    }
+}
+
+/// The singular value decomposition A = U S V^T of a M x N matrix, with M >= N.
+pub struct Svd {
+    u: MatrixF64,
+    s: VectorF64,
+    v: MatrixF64,
+}
+
+impl Svd {
+    /// Decomposes a copy of `a` with the Golub-Reinsch algorithm.
+    pub fn new(a: &MatrixF64) -> Result<Svd, Value> {
+        Svd::check(a)?;
+        Svd::from_matrix(copy_matrix(a)?)
+    }
+
+    /// Decomposes `a` in place with the Golub-Reinsch algorithm.
+    #[doc(alias = "gsl_linalg_SV_decomp")]
+    pub fn from_matrix(mut a: MatrixF64) -> Result<Svd, Value> {
+        let n = Svd::check(&a)?;
+        let mut v = matrix(n, n)?;
+        let mut s = vector(n)?;
+        let mut work = vector(n)?;
+        linear_algebra::SV_decomp(&mut a, &mut v, &mut s, &mut work).to_result()?;
+        Ok(Svd { u: a, s, v })
+    }
+
+    /// Decomposes a copy of `a` with one-sided Jacobi orthogonalization, which is slower but computes the singular
+    /// values to a higher relative accuracy.
+    #[doc(alias = "gsl_linalg_SV_decomp_jacobi")]
+    pub fn new_jacobi(a: &MatrixF64) -> Result<Svd, Value> {
+        let n = Svd::check(a)?;
+        let mut u = copy_matrix(a)?;
+        let mut v = matrix(n, n)?;
+        let mut s = vector(n)?;
+        linear_algebra::SV_decomp_jacobi(&mut u, &mut v, &mut s).to_result()?;
+        Ok(Svd { u, s, v })
+    }
+
+    fn check(a: &MatrixF64) -> Result<usize, Value> {
+        if a.size1() < a.size2() {
+            Err(Value::BadLength)
+        } else {
+            Ok(a.size2())
+        }
+    }
+
+    /// Returns the M x N matrix U.
+    pub fn u(&self) -> &MatrixF64 {
+        &self.u
+    }
+
+    /// Returns the N x N orthogonal matrix V (not transposed).
+    pub fn v(&self) -> &MatrixF64 {
+        &self.v
+    }
+
+    /// Returns the singular values, in non-increasing order.
+    pub fn singular_values(&self) -> &VectorF64 {
+        &self.s
+    }
+
+    /// Returns the number of singular values larger than `tol`.
+    pub fn rank(&self, tol: f64) -> usize {
+        (0..self.s.len()).filter(|&i| self.s.get(i) > tol).count()
+    }
+
+    /// Returns the reciprocal condition number of A (in the 2-norm), the ratio of the smallest and largest singular
+    /// values.
+    pub fn rcond(&self) -> f64 {
+        let n = self.s.len();
+        if n == 0 || self.s.get(0) == 0. {
+            0.
+        } else {
+            self.s.get(n - 1) / self.s.get(0)
+        }
+    }
+
+    /// Solves A x = b, in the least squares sense if A has more rows than columns. Zero singular values are ignored.
+    #[doc(alias = "gsl_linalg_SV_solve")]
+    pub fn solve(&self, b: &VectorF64) -> Result<VectorF64, Value> {
+        check_len(b, self.u.size1())?;
+        let mut x = vector(self.v.size1())?;
+        linear_algebra::SV_solve(&self.u, &self.v, &self.s, b, &mut x).to_result()?;
+        Ok(x)
+    }
+
+    /// Solves the square system A x = b in place: `x` holds b on input and the solution on output.
+    pub fn solve_in_place(&self, x: &mut VectorF64) -> Result<(), Value> {
+        check_square(&self.u)?;
+        let solution = self.solve(x)?;
+        x.copy_from(&solution).to_result()
+    }
+
+    /// Solves A X = B, one column of B at a time.
+    pub fn solve_matrix(&self, b: &MatrixF64) -> Result<MatrixF64, Value> {
+        check_rows(b, self.u.size1())?;
+        solve_columns(b, self.v.size1(), |col| self.solve(col))
+    }
+
+    /// Returns the N x M pseudo-inverse of A, which is its inverse when A is square and non-singular.
+    pub fn inverse(&self) -> Result<MatrixF64, Value> {
+        self.solve_matrix(&identity(self.u.size1())?)
+    }
+}
+
+/// The bidiagonalization A = U B V^T of a M x N matrix, with M >= N.
+pub struct Bidiag {
+    a: MatrixF64,
+    tau_u: VectorF64,
+    tau_v: VectorF64,
+}
+
+impl Bidiag {
+    /// Decomposes a copy of `a`.
+    pub fn new(a: &MatrixF64) -> Result<Bidiag, Value> {
+        if a.size1() < a.size2() {
+            return Err(Value::BadLength);
+        }
+        Bidiag::from_matrix(copy_matrix(a)?)
+    }
+
+    /// Decomposes `a` in place.
+    #[doc(alias = "gsl_linalg_bidiag_decomp")]
+    pub fn from_matrix(mut a: MatrixF64) -> Result<Bidiag, Value> {
+        let n = a.size2();
+        if a.size1() < n || n < 2 {
+            return Err(Value::BadLength);
+        }
+        let mut tau_u = vector(n)?;
+        let mut tau_v = vector(n - 1)?;
+        linear_algebra::bidiag_decomp(&mut a, &mut tau_u, &mut tau_v).to_result()?;
+        Ok(Bidiag { a, tau_u, tau_v })
+    }
+
+    /// Returns the packed decomposition: B on the diagonal and superdiagonal, the Householder vectors elsewhere.
+    pub fn packed(&self) -> &MatrixF64 {
+        &self.a
+    }
+
+    /// Returns the diagonal and the superdiagonal of B.
+    #[doc(alias = "gsl_linalg_bidiag_unpack_B")]
+    pub fn b(&self) -> Result<(VectorF64, VectorF64), Value> {
+        let n = self.a.size2();
+        let mut diag = vector(n)?;
+        let mut superdiag = vector(n - 1)?;
+        linear_algebra::bidiag_unpack_B(&self.a, &mut diag, &mut superdiag).to_result()?;
+        Ok((diag, superdiag))
+    }
+
+    /// Returns the M x N matrix U and the N x N orthogonal matrix V.
+    #[doc(alias = "gsl_linalg_bidiag_unpack")]
+    pub fn u_v(&self) -> Result<(MatrixF64, MatrixF64), Value> {
+        let (m, n) = (self.a.size1(), self.a.size2());
+        let mut u = matrix(m, n)?;
+        let mut v = matrix(n, n)?;
+        let mut diag = vector(n)?;
+        let mut superdiag = vector(n - 1)?;
+        Value::from(unsafe {
+            sys::gsl_linalg_bidiag_unpack(
+                self.a.unwrap_shared(),
+                self.tau_u.unwrap_shared(),
+                u.unwrap_unique(),
+                self.tau_v.unwrap_shared(),
+                v.unwrap_unique(),
+                diag.unwrap_unique(),
+                superdiag.unwrap_unique(),
+            )
+        })
+        .to_result()?;
+        Ok((u, v))
+    }
+}
+
+/// The Hessenberg decomposition A = U H U^T of a square matrix.
+pub struct Hessenberg {
+    h: MatrixF64,
+    tau: VectorF64,
+}
+
+impl Hessenberg {
+    /// Decomposes a copy of `a`.
+    pub fn new(a: &MatrixF64) -> Result<Hessenberg, Value> {
+        check_square(a)?;
+        Hessenberg::from_matrix(copy_matrix(a)?)
+    }
+
+    /// Decomposes `a` in place.
+    #[doc(alias = "gsl_linalg_hessenberg_decomp")]
+    pub fn from_matrix(mut a: MatrixF64) -> Result<Hessenberg, Value> {
+        let n = check_square(&a)?;
+        let mut tau = vector(n)?;
+        linear_algebra::hessenberg_decomp(&mut a, &mut tau).to_result()?;
+        Ok(Hessenberg { h: a, tau })
+    }
+
+    /// Returns the packed decomposition: H on and above the subdiagonal, the Householder vectors below it.
+    pub fn packed(&self) -> &MatrixF64 {
+        &self.h
+    }
+
+    /// Returns the upper Hessenberg matrix H.
+    #[doc(alias = "gsl_linalg_hessenberg_set_zero")]
+    pub fn h(&self) -> Result<MatrixF64, Value> {
+        let mut h = copy_matrix(&self.h)?;
+        linear_algebra::hessenberg_set_zero(&mut h).to_result()?;
+        Ok(h)
+    }
+
+    /// Returns the orthogonal matrix U.
+    #[doc(alias = "gsl_linalg_hessenberg_unpack")]
+    pub fn u(&self) -> Result<MatrixF64, Value> {
+        let n = self.h.size1();
+        let mut h = copy_matrix(&self.h)?;
+        let mut tau = copy_vector(&self.tau)?;
+        let mut u = matrix(n, n)?;
+        linear_algebra::hessenberg_unpack(&mut h, &mut tau, &mut u).to_result()?;
+        Ok(u)
+    }
+}
+
+#[test]
+fn decompositions() {
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-10
+    }
+    fn mul(a: &MatrixF64, b: &MatrixF64) -> MatrixF64 {
+        let mut c = MatrixF64::new(a.size1(), b.size2()).unwrap();
+        for i in 0..a.size1() {
+            for j in 0..b.size2() {
+                c.set(
+                    i,
+                    j,
+                    (0..a.size2()).map(|k| a.get(i, k) * b.get(k, j)).sum(),
+                );
+            }
+        }
+        c
+    }
+    fn transpose(a: &MatrixF64) -> MatrixF64 {
+        a.transpose_memcpy().unwrap().1
+    }
+    fn same(a: &MatrixF64, b: &MatrixF64) -> bool {
+        (a.size1(), a.size2()) == (b.size1(), b.size2())
+            && (0..a.size1()).all(|i| (0..a.size2()).all(|j| close(a.get(i, j), b.get(i, j))))
+    }
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
+    fn norm1(a: &MatrixF64) -> f64 {
+        (0..a.size2())
+            .map(|j| (0..a.size1()).map(|i| a.get(i, j).abs()).sum::<f64>())
+            .fold(0., f64::max)
+    }
+
+    let mut a = MatrixF64::new(3, 3).unwrap();
+    for (i, x) in [4., 2., 2., 2., 5., 3., 2., 3., 6.].iter().enumerate() {
+        a.set(i / 3, i % 3, *x);
+    }
+    let b = VectorF64::from_slice(&[8., 10., 11.]).unwrap();
+    // A (1, 1, 1) = b
+    let det = 4. * (5. * 6. - 9.) - 2. * (12. - 6.) + 2. * (6. - 10.);
+
+    let lu = Lu::new(&a).unwrap();
+    let x = lu.solve(&b).unwrap();
+    assert!((0..3).all(|i| close(x.get(i), 1.)));
+    assert!(close(lu.det(), det));
+    assert!(close(lu.ln_det(), det.ln()));
+    assert_eq!(lu.sign_det(), 1);
+    assert_eq!(
+        lu.solve(&VectorF64::new(2).unwrap()).err(),
+        Some(Value::BadLength)
+    );
+
+    let qr = Qr::new(&a).unwrap();
+    assert!(close(qr.det().unwrap(), det));
+    let x = qr.solve(&b).unwrap();
+    assert!((0..3).all(|i| close(x.get(i), 1.)));
+    assert!(same(&mul(&qr.q().unwrap(), &qr.r().unwrap()), &a));
+
+    // An overdetermined system: the residual of the least squares solution is orthogonal to the columns of A.
+    let mut tall = MatrixF64::new(4, 3).unwrap();
+    for (i, x) in [4., 2., 2., 2., 5., 3., 2., 3., 6., 1., 1., 1.]
+        .iter()
+        .enumerate()
+    {
+        tall.set(i / 3, i % 3, *x);
+    }
+    let tall_b = VectorF64::from_slice(&[8., 10., 11., 6.]).unwrap();
+    let (x, residual) = Qr::new(&tall).unwrap().lssolve(&tall_b).unwrap();
+    for i in 0..4 {
+        let ax = (0..3).map(|j| tall.get(i, j) * x.get(j)).sum::<f64>();
+        assert!(close(residual.get(i), tall_b.get(i) - ax));
+    }
+    for j in 0..3 {
+        assert!(close(
+            (0..4).map(|i| tall.get(i, j) * residual.get(i)).sum(),
+            0.
+        ));
+    }
+    assert!(residual.get(3).abs() > 1.);
+
+    let chol = Cholesky::new(&a).unwrap();
+    assert!(close(chol.det(), det));
+    let inverse = chol.inverse().unwrap();
+    let x = chol.solve_matrix(&a).unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!(close(x.get(i, j), if i == j { 1. } else { 0. }));
+            assert!(close(inverse.get(i, j), lu.inverse().unwrap().get(i, j)));
+        }
+    }
+
+    let svd = Svd::new(&a).unwrap();
+    let x = svd.solve(&b).unwrap();
+    assert!((0..3).all(|i| close(x.get(i), 1.)));
+    assert_eq!(svd.rank(1e-10), 3);
+
+    let pivoted = QrPivoted::new(&a).unwrap();
+    assert!(close(pivoted.det().unwrap(), det));
+
+    let (s0, s2) = (svd.singular_values().get(0), svd.singular_values().get(2));
+    assert!(close(
+        (0..3).map(|i| svd.singular_values().get(i)).product(),
+        det
+    ));
+    assert!(close(svd.rcond(), s2 / s0));
+
+    // The 1-norm estimates are lower bounds of the norm of the inverse, which makes their rcond an upper bound.
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
+    {
+        let rcond = 1. / (norm1(&a) * norm1(&lu.inverse().unwrap()));
+        let estimate = chol.rcond().unwrap();
+        assert!(estimate >= rcond - 1e-10 && estimate <= 3. * rcond);
+        let r = qr.rcond().unwrap();
+        assert!(r > 0. && r <= 1.);
+        let r = pivoted.rcond().unwrap();
+        assert!(r > 0. && r <= 1.);
+    }
+
+    #[cfg(any(feature = "v2_6", gsl_v2_6))]
+    {
+        let ldlt = Ldlt::new(&a).unwrap();
+        let d = ldlt.d().unwrap();
+        let mut dm = MatrixF64::new(3, 3).unwrap();
+        for i in 0..3 {
+            dm.set(i, i, d.get(i));
+        }
+        let l = ldlt.l().unwrap();
+        assert!(same(&mul(&mul(&l, &dm), &transpose(&l)), &a));
+        assert!(close(ldlt.det(), det));
+        let x = ldlt.solve(&b).unwrap();
+        assert!((0..3).all(|i| close(x.get(i), 1.)));
+        let rcond = 1. / (norm1(&a) * norm1(&lu.inverse().unwrap()));
+        let estimate = ldlt.rcond().unwrap();
+        assert!(estimate >= rcond - 1e-10 && estimate <= 3. * rcond);
+    }
+
+    let bidiag = Bidiag::new(&tall).unwrap();
+    let (diag, superdiag) = bidiag.b().unwrap();
+    let mut bm = MatrixF64::new(3, 3).unwrap();
+    for i in 0..3 {
+        bm.set(i, i, diag.get(i));
+        if i < 2 {
+            bm.set(i, i + 1, superdiag.get(i));
+        }
+    }
+    let (u, v) = bidiag.u_v().unwrap();
+    assert!(same(&mul(&mul(&u, &bm), &transpose(&v)), &tall));
+
+    let hessenberg = Hessenberg::new(&a).unwrap();
+    let (h, u) = (hessenberg.h().unwrap(), hessenberg.u().unwrap());
+    assert_eq!(h.get(2, 0), 0.);
+    assert!(same(&mul(&mul(&u, &h), &transpose(&u)), &a));
+
+    assert_eq!(
+        Lu::new(&MatrixF64::new(2, 3).unwrap()).err(),
+        Some(Value::NotSquare)
+    );
+    assert_eq!(
+        Svd::new(&MatrixF64::new(2, 3).unwrap()).err(),
+        Some(Value::BadLength)
+    );
+}
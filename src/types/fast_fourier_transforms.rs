@@ -3,7 +3,7 @@
 //
 
 use crate::paste::paste;
-use crate::Value;
+use crate::{ComplexF64, Value, VectorComplexF64, VectorComplexF64View};
 use ffi::FFI;
 
 macro_rules! gsl_fft_wavetable {
@@ -171,3 +171,418 @@ gsl_fft_wavetable!(
     f32,
     _float
 );
+
+/// Panics if `data` doesn't hold the `n` elements of a transform with the given `stride`, which GSL would read and
+/// write out of bounds.
+fn check_bounds<T>(data: &[T], stride: usize, n: usize) {
+    assert!(stride > 0, "the stride must be positive");
+    assert!(
+        n == 0 || (n - 1) * stride < data.len(),
+        "{} elements with a stride of {} don't fit in a slice of length {}",
+        n,
+        stride,
+        data.len()
+    );
+}
+
+macro_rules! gsl_fft_real {
+    ($real_wavetable:ident, $half_wavetable:ident, $workspace:ident, $ty:ident $(, $extra:ident)?) => (
+paste! {
+
+ffi_wrapper!(
+    $real_wavetable,
+    *mut sys::[<gsl_fft_real_wavetable $($extra)?>],
+    [<gsl_fft_real_wavetable $($extra)? _free>]
+);
+
+impl $real_wavetable {
+    /// This function prepares a trigonometric lookup table for a real FFT of length n. The
+    /// wavetable can be reused for any real transform of the same length.
+    #[doc(alias = gsl_fft_real_wavetable $($extra)? _alloc)]
+    pub fn new(n: usize) -> Option<Self> {
+        let tmp = unsafe { sys::[<gsl_fft_real_wavetable $($extra)? _alloc>](n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+}
+
+ffi_wrapper!(
+    $half_wavetable,
+    *mut sys::[<gsl_fft_halfcomplex_wavetable $($extra)?>],
+    [<gsl_fft_halfcomplex_wavetable $($extra)? _free>]
+);
+
+impl $half_wavetable {
+    /// This function prepares a trigonometric lookup table for the inverse transform of a
+    /// half-complex sequence of length n.
+    #[doc(alias = gsl_fft_halfcomplex_wavetable $($extra)? _alloc)]
+    pub fn new(n: usize) -> Option<Self> {
+        let tmp = unsafe { sys::[<gsl_fft_halfcomplex_wavetable $($extra)? _alloc>](n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+}
+
+ffi_wrapper!(
+    $workspace,
+    *mut sys::[<gsl_fft_real_workspace $($extra)?>],
+    [<gsl_fft_real_workspace $($extra)? _free>]
+);
+
+impl $workspace {
+    /// This function allocates a workspace for a real transform of length n. The same workspace
+    /// can be used for both forward real and inverse halfcomplex transforms.
+    #[doc(alias = gsl_fft_real_workspace $($extra)? _alloc)]
+    pub fn new(n: usize) -> Option<Self> {
+        let tmp = unsafe { sys::[<gsl_fft_real_workspace $($extra)? _alloc>](n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function computes the FFT of `data`, a real array of length n, using a mixed radix
+    /// decimation-in-frequency algorithm. The output is a half-complex sequence, which is stored
+    /// in-place.
+    #[doc(alias = gsl_fft_real $($extra)? _transform)]
+    pub fn transform(
+        &mut self,
+        data: &mut [$ty],
+        stride: usize,
+        n: usize,
+        wavetable: &$real_wavetable,
+    ) -> Value {
+        check_bounds(data, stride, n);
+        Value::from(unsafe {
+            sys::[<gsl_fft_real $($extra)? _transform>](
+                data.as_mut_ptr(),
+                stride,
+                n,
+                wavetable.unwrap_shared(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the inverse FFT of the half-complex sequence `data` of length n,
+    /// as returned by [`transform`](Self::transform). The result is a real array stored in
+    /// natural order.
+    #[doc(alias = gsl_fft_halfcomplex $($extra)? _inverse)]
+    pub fn halfcomplex_inverse(
+        &mut self,
+        data: &mut [$ty],
+        stride: usize,
+        n: usize,
+        wavetable: &$half_wavetable,
+    ) -> Value {
+        check_bounds(data, stride, n);
+        Value::from(unsafe {
+            sys::[<gsl_fft_halfcomplex $($extra)? _inverse>](
+                data.as_mut_ptr(),
+                stride,
+                n,
+                wavetable.unwrap_shared(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// Same as [`halfcomplex_inverse`](Self::halfcomplex_inverse) but without the 1/n scaling.
+    #[doc(alias = gsl_fft_halfcomplex $($extra)? _backward)]
+    pub fn halfcomplex_backward(
+        &mut self,
+        data: &mut [$ty],
+        stride: usize,
+        n: usize,
+        wavetable: &$half_wavetable,
+    ) -> Value {
+        check_bounds(data, stride, n);
+        Value::from(unsafe {
+            sys::[<gsl_fft_halfcomplex $($extra)? _backward>](
+                data.as_mut_ptr(),
+                stride,
+                n,
+                wavetable.unwrap_shared(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+}
+
+} // end of paste! block
+); // end of macro block
+}
+
+gsl_fft_real!(
+    FftRealF64WaveTable,
+    FftHalfComplexF64WaveTable,
+    FftRealF64Workspace,
+    f64
+);
+gsl_fft_real!(
+    FftRealF32WaveTable,
+    FftHalfComplexF32WaveTable,
+    FftRealF32Workspace,
+    f32,
+    _float
+);
+
+/// Complex buffers which can be transformed by a [`FftPlan`].
+///
+/// The length and stride of the transform are taken from the buffer itself, so they can't
+/// disagree with the memory that is actually available. It is implemented for slices and `Vec`s
+/// of [`ComplexF64`], [`VectorComplexF64`] and [`VectorComplexF64View`].
+///
+/// # Safety
+///
+/// `fft_parts` must return a pointer to `n` complex numbers (in GSL packed layout) spaced
+/// `stride` complex numbers apart, all valid for writes.
+pub unsafe trait FftComplexData {
+    /// Returns the packed data pointer, the stride (in complex elements) and the number of
+    /// elements.
+    #[doc(hidden)]
+    fn fft_parts(&mut self) -> (*mut f64, usize, usize);
+}
+
+unsafe impl FftComplexData for [ComplexF64] {
+    fn fft_parts(&mut self) -> (*mut f64, usize, usize) {
+        (self.as_mut_ptr() as *mut f64, 1, self.len())
+    }
+}
+
+unsafe impl FftComplexData for Vec<ComplexF64> {
+    fn fft_parts(&mut self) -> (*mut f64, usize, usize) {
+        self.as_mut_slice().fft_parts()
+    }
+}
+
+unsafe impl FftComplexData for VectorComplexF64 {
+    fn fft_parts(&mut self) -> (*mut f64, usize, usize) {
+        let ptr = self.unwrap_unique();
+        if ptr.is_null() {
+            (::std::ptr::null_mut(), 1, 0)
+        } else {
+            unsafe { ((*ptr).data, (*ptr).stride, (*ptr).size) }
+        }
+    }
+}
+
+unsafe impl<'a> FftComplexData for VectorComplexF64View<'a> {
+    fn fft_parts(&mut self) -> (*mut f64, usize, usize) {
+        let mut parts = (::std::ptr::null_mut(), 1, 0);
+        self.vector_mut(|v| {
+            if let Some(v) = v {
+                parts = v.fft_parts();
+            }
+        });
+        parts
+    }
+}
+
+/// A mixed-radix complex FFT plan for a fixed length n.
+///
+/// The plan owns both the wavetable and the workspace, so it can be reused for any number of
+/// transforms of the same length without further allocation. The length of every buffer is
+/// checked against the plan before GSL is called.
+///
+/// ```
+/// use rgsl::{ComplexF64, FftPlan};
+///
+/// let mut data = vec![ComplexF64::rect(1., 0.); 8];
+/// let mut plan = FftPlan::new(8).unwrap();
+/// plan.forward(&mut data).unwrap();
+/// assert_eq!(data[0].real(), 8.);
+/// ```
+pub struct FftPlan {
+    wavetable: FftComplexF64WaveTable,
+    workspace: FftComplexF64Workspace,
+    n: usize,
+}
+
+impl FftPlan {
+    /// Creates a plan for complex transforms of length `n`. Returns `None` if `n` is zero or if
+    /// the allocation failed.
+    pub fn new(n: usize) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+        Some(Self {
+            wavetable: FftComplexF64WaveTable::new(n)?,
+            workspace: FftComplexF64Workspace::new(n)?,
+            n,
+        })
+    }
+
+    /// Returns the length of the transforms computed by this plan.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Computes the forward transform of `data` in-place.
+    #[doc(alias = "gsl_fft_complex_forward")]
+    pub fn forward<D: FftComplexData + ?Sized>(&mut self, data: &mut D) -> Result<(), Value> {
+        self.transform(data, ::FftDirection::Forward)
+    }
+
+    /// Computes the backward (unscaled inverse) transform of `data` in-place.
+    #[doc(alias = "gsl_fft_complex_backward")]
+    pub fn backward<D: FftComplexData + ?Sized>(&mut self, data: &mut D) -> Result<(), Value> {
+        self.transform(data, ::FftDirection::Backward)
+    }
+
+    /// Computes the inverse transform of `data` in-place, including the 1/n scaling.
+    #[doc(alias = "gsl_fft_complex_inverse")]
+    pub fn inverse<D: FftComplexData + ?Sized>(&mut self, data: &mut D) -> Result<(), Value> {
+        let (ptr, stride, n) = self.check(data)?;
+        Value::from(unsafe {
+            sys::gsl_fft_complex_inverse(
+                ptr,
+                stride,
+                n,
+                self.wavetable.unwrap_shared(),
+                self.workspace.unwrap_unique(),
+            )
+        })
+        .to_result()
+    }
+
+    /// Computes the transform of `data` in-place in the given direction.
+    #[doc(alias = "gsl_fft_complex_transform")]
+    pub fn transform<D: FftComplexData + ?Sized>(
+        &mut self,
+        data: &mut D,
+        sign: ::FftDirection,
+    ) -> Result<(), Value> {
+        let (ptr, stride, n) = self.check(data)?;
+        Value::from(unsafe {
+            sys::gsl_fft_complex_transform(
+                ptr,
+                stride,
+                n,
+                self.wavetable.unwrap_shared(),
+                self.workspace.unwrap_unique(),
+                sign.into(),
+            )
+        })
+        .to_result()
+    }
+
+    fn check<D: FftComplexData + ?Sized>(
+        &self,
+        data: &mut D,
+    ) -> Result<(*mut f64, usize, usize), Value> {
+        let (ptr, stride, n) = data.fft_parts();
+        if ptr.is_null() {
+            Err(Value::Fault)
+        } else if n != self.n || stride == 0 {
+            Err(Value::BadLength)
+        } else {
+            Ok((ptr, stride, n))
+        }
+    }
+}
+
+/// A mixed-radix FFT plan for real sequences of a fixed length n.
+///
+/// The forward transform returns the `n / 2 + 1` non-redundant complex coefficients of the
+/// real input, the remaining ones being given by the symmetry z_k = z_{n-k}^*. The inverse
+/// transform takes those same coefficients back to a real sequence.
+pub struct FftRealPlan {
+    real_wavetable: FftRealF64WaveTable,
+    halfcomplex_wavetable: FftHalfComplexF64WaveTable,
+    workspace: FftRealF64Workspace,
+    scratch: Vec<f64>,
+}
+
+impl FftRealPlan {
+    /// Creates a plan for real transforms of length `n`. Returns `None` if `n` is zero or if
+    /// the allocation failed.
+    pub fn new(n: usize) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+        Some(Self {
+            real_wavetable: FftRealF64WaveTable::new(n)?,
+            halfcomplex_wavetable: FftHalfComplexF64WaveTable::new(n)?,
+            workspace: FftRealF64Workspace::new(n)?,
+            scratch: vec![0.; n],
+        })
+    }
+
+    /// Returns the length of the real sequences handled by this plan.
+    pub fn len(&self) -> usize {
+        self.scratch.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scratch.is_empty()
+    }
+
+    /// Returns the number of complex coefficients produced by the forward transform, which is
+    /// `n / 2 + 1`.
+    pub fn complex_len(&self) -> usize {
+        self.len() / 2 + 1
+    }
+
+    /// Computes the forward transform of the real sequence `input` and stores the `n / 2 + 1`
+    /// non-redundant coefficients in `output`.
+    #[doc(alias = "gsl_fft_real_transform")]
+    pub fn forward(&mut self, input: &[f64], output: &mut [ComplexF64]) -> Result<(), Value> {
+        let n = self.len();
+        if input.len() != n || output.len() != self.complex_len() {
+            return Err(Value::BadLength);
+        }
+        self.scratch.copy_from_slice(input);
+        self.workspace
+            .transform(&mut self.scratch, 1, n, &self.real_wavetable)
+            .to_result()?;
+
+        // Mixed-radix half-complex layout: r0, (r1, i1), (r2, i2), ..., [r(n/2) if n is even].
+        output[0] = ComplexF64 {
+            dat: [self.scratch[0], 0.],
+        };
+        for (k, out) in output.iter_mut().enumerate().skip(1) {
+            let re = self.scratch[2 * k - 1];
+            let im = if 2 * k < n { self.scratch[2 * k] } else { 0. };
+            *out = ComplexF64 { dat: [re, im] };
+        }
+        Ok(())
+    }
+
+    /// Computes the inverse transform of the `n / 2 + 1` coefficients in `input` and stores the
+    /// resulting real sequence in `output`. The imaginary parts of the zero-frequency term (and
+    /// of the Nyquist term when n is even) are ignored.
+    #[doc(alias = "gsl_fft_halfcomplex_inverse")]
+    pub fn inverse(&mut self, input: &[ComplexF64], output: &mut [f64]) -> Result<(), Value> {
+        let n = self.len();
+        if output.len() != n || input.len() != self.complex_len() {
+            return Err(Value::BadLength);
+        }
+        self.scratch[0] = input[0].dat[0];
+        for (k, z) in input.iter().enumerate().skip(1) {
+            self.scratch[2 * k - 1] = z.dat[0];
+            if 2 * k < n {
+                self.scratch[2 * k] = z.dat[1];
+            }
+        }
+        self.workspace
+            .halfcomplex_inverse(&mut self.scratch, 1, n, &self.halfcomplex_wavetable)
+            .to_result()?;
+        output.copy_from_slice(&self.scratch);
+        Ok(())
+    }
+}
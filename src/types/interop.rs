@@ -0,0 +1,275 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Interoperability with ndarray and nalgebra
+
+With the `ndarray` feature, `ndarray` arrays can be viewed as GSL vectors and matrices (and the other way around)
+without copying the elements:
+
+```
+# #[cfg(feature = "ndarray")] {
+use rgsl::{MatrixF64View, VectorF64View};
+
+let mut a = ndarray::Array2::<f64>::zeros((4, 3));
+let mut v = a.column_mut(1);
+let mut view = VectorF64View::from_ndarray(&mut v).unwrap();
+view.vector_mut(|v| {
+    v.unwrap().set_all(2.);
+});
+assert_eq!(a.column(1).sum(), 8.);
+
+let mut sub = a.slice_mut(ndarray::s![1.., ..2]);
+let mut view = MatrixF64View::from_ndarray(&mut sub).unwrap();
+view.matrix_mut(|m| {
+    m.unwrap().set_identity();
+});
+assert_eq!(a[[1, 0]], 1.);
+assert_eq!(a[[3, 1]], 2.);
+# }
+```
+
+With the `nalgebra` feature, `DVector<f64>` and `DMatrix<f64>` convert to and from `VectorF64` and `MatrixF64`. As
+`nalgebra` stores its matrices in column-major order, only vectors can be viewed without copying, with
+[`VectorF64View::from_nalgebra`](../struct.VectorF64View.html#method.from_nalgebra).
+!*/
+
+#[cfg(feature = "ndarray")]
+use ndarray::{ArrayView1, ArrayView2, ArrayViewMut1, ArrayViewMut2, ShapeBuilder};
+#[cfg(feature = "ndarray")]
+use std::marker::PhantomData;
+#[cfg(feature = "ndarray")]
+use std::ptr::NonNull;
+
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, DVector};
+
+#[cfg(feature = "ndarray")]
+use types::{GslMatrix, GslVector};
+#[cfg(feature = "ndarray")]
+use MatrixF64View;
+use {MatrixF64, VectorF64, VectorF64View};
+
+#[cfg(feature = "ndarray")]
+fn ndarray_ptr(ptr: *const f64) -> *const f64 {
+    // ndarray wants a non-null pointer, even for empty arrays
+    if ptr.is_null() {
+        NonNull::dangling().as_ptr()
+    } else {
+        ptr
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a> VectorF64View<'a> {
+    /// Returns a vector view of the elements of `a`, honouring its stride. Returns `None` if `a` is empty or if its
+    /// stride isn't positive.
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+    pub fn from_ndarray(a: &'a mut ArrayViewMut1<f64>) -> Option<Self> {
+        let n = a.len();
+        let stride = if n > 1 { a.strides()[0] } else { 1 };
+        if n == 0 || stride <= 0 {
+            return None;
+        }
+        // Built from the pointer: a slice would also cover the elements between the strided ones, which `a` doesn't
+        // borrow.
+        Some(VectorF64View {
+            v: unsafe {
+                sys::gsl_vector_view_array_with_stride(a.as_mut_ptr(), stride as usize, n)
+            },
+            phantom: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a> MatrixF64View<'a> {
+    /// Returns a matrix view of the elements of `a`, using its row stride as the physical number of columns (`tda`).
+    /// Returns `None` if `a` is empty, if the elements of a row aren't contiguous or if the row stride is shorter than
+    /// a row.
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+    pub fn from_ndarray(a: &'a mut ArrayViewMut2<f64>) -> Option<Self> {
+        let (n1, n2) = a.dim();
+        let (s1, s2) = (a.strides()[0], a.strides()[1]);
+        if n1 == 0 || n2 == 0 || (n2 > 1 && s2 != 1) {
+            return None;
+        }
+        let tda = if n1 > 1 {
+            if s1 < 0 || (s1 as usize) < n2 {
+                return None;
+            }
+            s1 as usize
+        } else {
+            n2
+        };
+        // Built from the pointer: a slice would also cover the padding at the end of the rows, which `a` doesn't
+        // borrow.
+        Some(MatrixF64View {
+            mat: unsafe { sys::gsl_matrix_view_array_with_tda(a.as_mut_ptr(), n1, n2, tda) },
+            phantom: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl VectorF64 {
+    /// Returns an `ndarray` view of the elements, honouring the stride.
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+    pub fn as_ndarray(&self) -> ArrayView1<'_, f64> {
+        let ptr = ndarray_ptr(GslVector::as_ptr(self));
+        unsafe { ArrayView1::from_shape_ptr((self.len(),).strides((self.stride(),)), ptr) }
+    }
+
+    /// Returns a mutable `ndarray` view of the elements, honouring the stride.
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+    pub fn as_ndarray_mut(&mut self) -> ArrayViewMut1<'_, f64> {
+        let ptr = ndarray_ptr(GslVector::as_ptr(self)) as *mut f64;
+        unsafe { ArrayViewMut1::from_shape_ptr((self.len(),).strides((self.stride(),)), ptr) }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl MatrixF64 {
+    /// Returns an `ndarray` view of the elements, honouring the physical number of columns (`tda`).
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+    pub fn as_ndarray(&self) -> ArrayView2<'_, f64> {
+        let ptr = ndarray_ptr(GslMatrix::as_ptr(self));
+        let shape = (self.size1(), self.size2()).strides((self.tda(), 1));
+        unsafe { ArrayView2::from_shape_ptr(shape, ptr) }
+    }
+
+    /// Returns a mutable `ndarray` view of the elements, honouring the physical number of columns (`tda`).
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+    pub fn as_ndarray_mut(&mut self) -> ArrayViewMut2<'_, f64> {
+        let ptr = ndarray_ptr(GslMatrix::as_ptr(self)) as *mut f64;
+        let shape = (self.size1(), self.size2()).strides((self.tda(), 1));
+        unsafe { ArrayViewMut2::from_shape_ptr(shape, ptr) }
+    }
+}
+
+/// Copies the elements of `v`.
+///
+/// # Panics
+///
+/// Panics if GSL fails to allocate the vector.
+#[cfg(feature = "nalgebra")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+impl<'a> From<&'a DVector<f64>> for VectorF64 {
+    fn from(v: &'a DVector<f64>) -> VectorF64 {
+        VectorF64::from_slice(v.as_slice()).expect("failed to allocate the vector")
+    }
+}
+
+/// Copies the elements of `v`.
+#[cfg(feature = "nalgebra")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+impl<'a> From<&'a VectorF64> for DVector<f64> {
+    fn from(v: &'a VectorF64) -> DVector<f64> {
+        DVector::from_fn(v.len(), |i, _| v.get(i))
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<'a> VectorF64View<'a> {
+    /// Returns a vector view of the elements of `v` without copying them. Returns `None` if `v` is empty, as GSL has no
+    /// empty views.
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+    pub fn from_nalgebra(v: &'a mut DVector<f64>) -> Option<Self> {
+        if v.is_empty() {
+            return None;
+        }
+        Some(VectorF64View::from_array(v.as_mut_slice()))
+    }
+}
+
+/// Copies the elements of `m`.
+///
+/// # Panics
+///
+/// Panics if GSL fails to allocate the matrix.
+#[cfg(feature = "nalgebra")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+impl<'a> From<&'a DMatrix<f64>> for MatrixF64 {
+    fn from(m: &'a DMatrix<f64>) -> MatrixF64 {
+        let mut out = MatrixF64::new(m.nrows(), m.ncols()).expect("failed to allocate the matrix");
+        for i in 0..m.nrows() {
+            for j in 0..m.ncols() {
+                out.set(i, j, m[(i, j)]);
+            }
+        }
+        out
+    }
+}
+
+/// Copies the elements of `m`.
+#[cfg(feature = "nalgebra")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+impl<'a> From<&'a MatrixF64> for DMatrix<f64> {
+    fn from(m: &'a MatrixF64) -> DMatrix<f64> {
+        DMatrix::from_fn(m.size1(), m.size2(), |i, j| m.get(i, j))
+    }
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn ndarray_views() {
+    let mut a = ndarray::Array2::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64);
+
+    let mut col = a.column_mut(2);
+    VectorF64View::from_ndarray(&mut col)
+        .unwrap()
+        .vector_mut(|v| {
+            let v = v.unwrap();
+            assert_eq!(v.len(), 3);
+            assert_eq!(v.get(2), 10.);
+            v.scale(-1.);
+        });
+    assert_eq!(a[[1, 2]], -6.);
+
+    let mut sub = a.slice_mut(ndarray::s![1.., 1..3]);
+    MatrixF64View::from_ndarray(&mut sub)
+        .unwrap()
+        .matrix_mut(|m| {
+            let m = m.unwrap();
+            assert_eq!((m.size1(), m.size2()), (2, 2));
+            assert_eq!(m.get(1, 0), 9.);
+            m.set_zero();
+        });
+    assert_eq!(a.row(1).to_vec(), vec![4., 0., 0., 7.]);
+
+    let mut t = a.view_mut().reversed_axes();
+    assert!(MatrixF64View::from_ndarray(&mut t).is_none());
+    let mut empty = a.slice_mut(ndarray::s![..0, ..]);
+    assert!(MatrixF64View::from_ndarray(&mut empty).is_none());
+    let mut empty = a.slice_mut(ndarray::s![0, ..0]);
+    assert!(VectorF64View::from_ndarray(&mut empty).is_none());
+
+    let mut m = MatrixF64::new(3, 4).unwrap();
+    m.as_ndarray_mut().assign(&a);
+    assert_eq!(m.get(2, 3), 11.);
+    assert_eq!(m.as_ndarray(), a.view());
+    let mut v = VectorF64::from_slice(&[1., 2., 3.]).unwrap();
+    v.as_ndarray_mut()[1] = 5.;
+    assert_eq!(v.get(1), 5.);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_conversions() {
+    let m = DMatrix::from_fn(2, 3, |i, j| (i * 3 + j) as f64);
+    let g = MatrixF64::from(&m);
+    assert_eq!(g.get(1, 0), 3.);
+    assert_eq!(g.get(0, 2), 2.);
+    assert_eq!(DMatrix::from(&g), m);
+
+    let mut v = DVector::from_vec(vec![1., 2., 3.]);
+    assert_eq!(DVector::from(&VectorF64::from(&v)), v);
+    VectorF64View::from_nalgebra(&mut v)
+        .unwrap()
+        .vector_mut(|g| {
+            g.unwrap().set(0, 4.);
+        });
+    assert_eq!(v[0], 4.);
+    assert!(VectorF64View::from_nalgebra(&mut DVector::zeros(0)).is_none());
+}
@@ -96,6 +96,28 @@ impl MathieuWorkspace {
         })
     }
 
+    /// This routine computes the Fourier coefficients of the even-periodic Mathieu function ce_order(q,x) for the characteristic value a,
+    /// storing them in coeff. coeff must hold at least [`SF_MATHIEU_COEFF`](../../static.SF_MATHIEU_COEFF.html) elements, otherwise
+    /// `Value::BadLength` is returned.
+    #[doc(alias = "gsl_sf_mathieu_a_coeff")]
+    pub fn mathieu_a_coeff(order: i32, q: f64, a: f64, coeff: &mut [f64]) -> Value {
+        if coeff.len() < ::SF_MATHIEU_COEFF as usize {
+            return Value::BadLength;
+        }
+        Value::from(unsafe { sys::gsl_sf_mathieu_a_coeff(order, q, a, coeff.as_mut_ptr()) })
+    }
+
+    /// This routine computes the Fourier coefficients of the odd-periodic Mathieu function se_order(q,x) for the characteristic value b,
+    /// storing them in coeff. coeff must hold at least [`SF_MATHIEU_COEFF`](../../static.SF_MATHIEU_COEFF.html) elements, otherwise
+    /// `Value::BadLength` is returned.
+    #[doc(alias = "gsl_sf_mathieu_b_coeff")]
+    pub fn mathieu_b_coeff(order: i32, q: f64, b: f64, coeff: &mut [f64]) -> Value {
+        if coeff.len() < ::SF_MATHIEU_COEFF as usize {
+            return Value::BadLength;
+        }
+        Value::from(unsafe { sys::gsl_sf_mathieu_b_coeff(order, q, b, coeff.as_mut_ptr()) })
+    }
+
     /// This routine computes the angular Mathieu functions ce_n(q,x) and se_n(q,x), respectively.
     #[doc(alias = "gsl_sf_mathieu_ce_e")]
     pub fn mathieu_ce(n: i32, q: f64, x: f64) -> (Value, ::types::Result) {
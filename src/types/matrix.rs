@@ -55,8 +55,15 @@ use crate::Value;
 use ffi::{self, FFI};
 use std::fmt::{self, Debug, Formatter};
 use std::marker::PhantomData;
-use types::{VectorF32, VectorF64, VectorI32, VectorU32};
-use types::{VectorF32View, VectorF64View, VectorI32View, VectorU32View};
+use types::{VectorF32, VectorF64, VectorI16, VectorI32, VectorU16, VectorU32, VectorU8};
+use types::{
+    VectorF32View, VectorF64View, VectorI16View, VectorI32View, VectorU16View, VectorU32View,
+    VectorU8View,
+};
+#[cfg(gsl_long_64)]
+use types::{VectorI64, VectorI64View, VectorU64, VectorU64View};
+#[cfg(gsl_char_signed)]
+use types::{VectorI8, VectorI8View};
 
 macro_rules! gsl_matrix {
     ($rust_name:ident, $name:ident, $rust_ty:ident, $vec_name:ident, $vec_c_name:ident) => (
@@ -91,7 +98,7 @@ impl $rust_name {
     /// invoked and 0 is returned.
     #[doc(alias = $name _get)]
     pub fn get(&self, y: usize, x: usize) -> $rust_ty {
-        unsafe { sys::[<$name _get>](self.unwrap_shared(), y, x) }
+        unsafe { sys::[<$name _get>](self.unwrap_shared(), y, x) as _ }
     }
 
     /// This function sets the value of the (i,j)-th element of the matrix to value.
@@ -99,14 +106,14 @@ impl $rust_name {
     /// is invoked.
     #[doc(alias = $name _set)]
     pub fn set(&mut self, y: usize, x: usize, value: $rust_ty) -> &$rust_name {
-        unsafe { sys::[<$name _set>](self.unwrap_unique(), y, x, value) };
+        unsafe { sys::[<$name _set>](self.unwrap_unique(), y, x, value as _) };
         self
     }
 
     /// This function sets all the elements of the matrix to the value x.
     #[doc(alias = $name _set_all)]
     pub fn set_all(&mut self, x: $rust_ty) -> &$rust_name {
-        unsafe { sys::[<$name _set_all>](self.unwrap_unique(), x) };
+        unsafe { sys::[<$name _set_all>](self.unwrap_unique(), x as _) };
         self
     }
 
@@ -289,13 +296,13 @@ impl $rust_name {
     /// This function returns the maximum value in the self matrix.
     #[doc(alias = $name _max)]
     pub fn max(&self) -> $rust_ty {
-        unsafe { sys::[<$name _max>](self.unwrap_shared()) }
+        unsafe { sys::[<$name _max>](self.unwrap_shared()) as _ }
     }
 
     /// This function returns the minimum value in the self matrix.
     #[doc(alias = $name _min)]
     pub fn min(&self) -> $rust_ty {
-        unsafe { sys::[<$name _min>](self.unwrap_shared()) }
+        unsafe { sys::[<$name _min>](self.unwrap_shared()) as _ }
     }
 
     /// This function returns the minimum and maximum values in the self matrix.
@@ -304,7 +311,7 @@ impl $rust_name {
         let mut min_out = 0 as _;
         let mut max_out = 0 as _;
         unsafe { sys::[<$name _minmax>](self.unwrap_shared(), &mut min_out, &mut max_out) };
-        (min_out, max_out)
+        (min_out as _, max_out as _)
     }
 
     /// This function returns the indices of the maximum value in the self matrix. When there are
@@ -527,9 +534,9 @@ impl Debug for $rust_name {
 }
 
 pub struct [<$rust_name View>]<'a> {
-    mat: sys::[<$name _view>],
+    pub(crate) mat: sys::[<$name _view>],
     #[allow(dead_code)]
-    phantom: PhantomData<&'a ()>,
+    pub(crate) phantom: PhantomData<&'a ()>,
 }
 
 impl<'a> [<$rust_name View>]<'a> {
@@ -594,7 +601,7 @@ impl<'a> [<$rust_name View>]<'a> {
         );
         unsafe {
             Self {
-                mat: sys::[<$name _view_array>](base.as_mut_ptr(), n1, n2),
+                mat: sys::[<$name _view_array>](base.as_mut_ptr() as _, n1, n2),
                 phantom: PhantomData,
             }
         }
@@ -620,7 +627,7 @@ impl<'a> [<$rust_name View>]<'a> {
     pub fn from_array_with_tda(base: &'a mut [$rust_ty], n1: usize, n2: usize, tda: usize) -> Self {
         unsafe {
             Self {
-                mat: sys::[<$name _view_array_with_tda>](base.as_mut_ptr(), n1, n2, tda),
+                mat: sys::[<$name _view_array_with_tda>](base.as_mut_ptr() as _, n1, n2, tda),
                 phantom: PhantomData,
             }
         }
@@ -712,3 +719,30 @@ gsl_matrix!(
 gsl_matrix!(MatrixF64, gsl_matrix, f64, VectorF64, gsl_vector);
 gsl_matrix!(MatrixI32, gsl_matrix_int, i32, VectorI32, gsl_vector_int);
 gsl_matrix!(MatrixU32, gsl_matrix_uint, u32, VectorU32, gsl_vector_uint);
+#[cfg(gsl_long_64)]
+gsl_matrix!(MatrixI64, gsl_matrix_long, i64, VectorI64, gsl_vector_long);
+#[cfg(gsl_long_64)]
+gsl_matrix!(
+    MatrixU64,
+    gsl_matrix_ulong,
+    u64,
+    VectorU64,
+    gsl_vector_ulong
+);
+gsl_matrix!(
+    MatrixI16,
+    gsl_matrix_short,
+    i16,
+    VectorI16,
+    gsl_vector_short
+);
+gsl_matrix!(
+    MatrixU16,
+    gsl_matrix_ushort,
+    u16,
+    VectorU16,
+    gsl_vector_ushort
+);
+#[cfg(gsl_char_signed)]
+gsl_matrix!(MatrixI8, gsl_matrix_char, i8, VectorI8, gsl_vector_char);
+gsl_matrix!(MatrixU8, gsl_matrix_uchar, u8, VectorU8, gsl_vector_uchar);
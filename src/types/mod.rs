@@ -3,10 +3,17 @@
 //
 
 pub use self::basis_spline::BSpLineWorkspace;
+pub use self::blas_ops::MatrixOp;
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+pub use self::bst::{Bst, BstType};
 
 pub use self::chebyshev::ChebSeries;
 pub use self::combination::Combination;
 pub use self::complex::{ComplexF32, ComplexF64};
+#[cfg(any(feature = "v2_6", gsl_v2_6))]
+pub use self::decomposition::Ldlt;
+pub use self::decomposition::{Bidiag, Cholesky, Hessenberg, Lu, Qr, QrPivoted, Svd};
 pub use self::discrete_hankel::DiscreteHankel;
 pub use self::eigen_symmetric_workspace::{
     EigenGenHermVWorkspace, EigenGenHermWorkspace, EigenGenSymmVWorkspace, EigenGenSymmWorkspace,
@@ -15,9 +22,12 @@ pub use self::eigen_symmetric_workspace::{
     EigenSymmetricWorkspace,
 };
 pub use self::fast_fourier_transforms::{
-    FftComplexF32WaveTable, FftComplexF32Workspace, FftComplexF64WaveTable, FftComplexF64Workspace,
+    FftComplexData, FftComplexF32WaveTable, FftComplexF32Workspace, FftComplexF64WaveTable,
+    FftComplexF64Workspace, FftHalfComplexF32WaveTable, FftHalfComplexF64WaveTable, FftPlan,
+    FftRealF32WaveTable, FftRealF32Workspace, FftRealF64WaveTable, FftRealF64Workspace,
+    FftRealPlan,
 };
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub use self::filter::{
     FilterGaussianWorkspace, FilterImpulseWorkspace, FilterMedianWorkspace, FilterRMedianWorkspace,
@@ -30,9 +40,13 @@ pub use self::integration::{
 pub use self::interpolation::{Interp, InterpAccel, InterpType, Spline};
 pub use self::mathieu::MathieuWorkspace;
 pub use self::matrix::{
-    MatrixF32, MatrixF32View, MatrixF64, MatrixF64View, MatrixI32, MatrixI32View, MatrixU32,
-    MatrixU32View,
+    MatrixF32, MatrixF32View, MatrixF64, MatrixF64View, MatrixI16, MatrixI16View, MatrixI32,
+    MatrixI32View, MatrixU16, MatrixU16View, MatrixU32, MatrixU32View, MatrixU8, MatrixU8View,
 };
+#[cfg(gsl_long_64)]
+pub use self::matrix::{MatrixI64, MatrixI64View, MatrixU64, MatrixU64View};
+#[cfg(gsl_char_signed)]
+pub use self::matrix::{MatrixI8, MatrixI8View};
 pub use self::matrix_complex::{MatrixComplexF32, MatrixComplexF64};
 pub use self::minimizer::{Minimizer, MinimizerType};
 pub use self::monte_carlo::{
@@ -42,7 +56,7 @@ pub use self::multifit_linear::MultifitLinearWorkspace;
 pub use self::multifit_solver::{
     MultiFitFdfSolver, MultiFitFdfSolverType, MultiFitFunction, MultiFitFunctionFdf,
 };
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub use self::multilarge_linear::{MultilargeLinearType, MultilargeLinearWorkspace};
 pub use self::multiset::MultiSet;
@@ -60,27 +74,43 @@ pub use self::roots::{RootFSolver, RootFSolverType, RootFdfSolver, RootFdfSolver
 pub use self::rstat::{RStatQuantileWorkspace, RStatWorkspace};
 pub use self::series_acceleration::{LevinUTruncWorkspace, LevinUWorkspace};
 pub use self::siman::{SimAnnealing, SimAnnealingParams};
+pub use self::traits::{GslMatrix, GslScalar, GslVector};
 pub use self::vector::{
-    VectorF32, VectorF32View, VectorF64, VectorF64View, VectorI32, VectorI32View, VectorU32,
-    VectorU32View,
+    VectorF32, VectorF32View, VectorF64, VectorF64View, VectorI16, VectorI16View, VectorI32,
+    VectorI32View, VectorU16, VectorU16View, VectorU32, VectorU32View, VectorU8, VectorU8View,
 };
+#[cfg(gsl_long_64)]
+pub use self::vector::{VectorI64, VectorI64View, VectorU64, VectorU64View};
+#[cfg(gsl_char_signed)]
+pub use self::vector::{VectorI8, VectorI8View};
 pub use self::vector_complex::{
     VectorComplexF32, VectorComplexF32View, VectorComplexF64, VectorComplexF64View,
 };
 pub use self::wavelet_transforms::{Wavelet, WaveletType, WaveletWorkspace};
 
 pub mod basis_spline;
+pub mod blas_ops;
+#[cfg(any(feature = "v2_7", gsl_v2_7))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+pub mod bst;
 pub mod chebyshev;
 pub mod combination;
 pub mod complex;
+pub mod decomposition;
 pub mod discrete_hankel;
 pub mod eigen_symmetric_workspace;
 pub mod fast_fourier_transforms;
-#[cfg(feature = "v2_5")]
+#[cfg(any(feature = "v2_5", gsl_v2_5))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub mod filter;
 pub mod histograms;
 pub mod integration;
+#[cfg(any(feature = "ndarray", feature = "nalgebra"))]
+#[cfg_attr(
+    feature = "dox",
+    doc(cfg(any(feature = "ndarray", feature = "nalgebra")))
+)]
+pub mod interop;
 pub mod interpolation;
 pub mod mathieu;
 pub mod matrix;
@@ -89,7 +119,7 @@ pub mod minimizer;
 pub mod monte_carlo;
 pub mod multifit_linear;
 pub mod multifit_solver;
-#[cfg(feature = "v2_1")]
+#[cfg(any(feature = "v2_1", gsl_v2_1))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub mod multilarge_linear;
 pub mod multiset;
@@ -105,6 +135,7 @@ pub mod roots;
 pub mod rstat;
 pub mod series_acceleration;
 pub mod siman;
+pub mod traits;
 pub mod vector;
 pub mod vector_complex;
 pub mod wavelet_transforms;
@@ -46,7 +46,7 @@ impl MultifitLinearWorkspace {
     }
 
     /// Returns `(Value, chisq, rank)`.
-    #[cfg(feature = "v2_3")]
+    #[cfg(any(feature = "v2_3", gsl_v2_3))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_linear_tsvd")]
     pub fn linear_tsvd(
@@ -94,7 +94,7 @@ impl MultifitLinearWorkspace {
         }
     }
 
-    #[cfg(feature = "v2_3")]
+    #[cfg(any(feature = "v2_3", gsl_v2_3))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_linear_rank")]
     pub fn linear_rank(&self, tol: f64) -> usize {
@@ -350,7 +350,7 @@ impl MultifitLinearWorkspace {
     }
 
     /// Returns `(Value, chisq, rank)`.
-    #[cfg(feature = "v2_3")]
+    #[cfg(any(feature = "v2_3", gsl_v2_3))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_wlinear_tsvd")]
     pub fn wlinear_tsvd(
@@ -438,7 +438,7 @@ impl MultifitLinearWorkspace {
         (Value::from(ret), rank, chisq)
     }
 
-    #[cfg(feature = "v2_1")]
+    #[cfg(any(feature = "v2_1", gsl_v2_1))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     #[doc(alias = "gsl_multifit_linear_rcond")]
     pub fn linear_rcond(&mut self) -> f64 {
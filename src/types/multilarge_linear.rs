@@ -92,7 +92,7 @@ impl MultilargeLinearWorkspace {
         (Value::from(ret), rcond)
     }
 
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     #[doc(alias = "gsl_multilarge_linear_lcurve")]
     pub fn lcurve(
@@ -234,7 +234,7 @@ impl MultilargeLinearWorkspace {
         })
     }
 
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     #[doc(alias = "gsl_multilarge_linear_matrix_ptr")]
     pub fn matrix<F: FnOnce(&MatrixF64)>(&self, f: F) {
@@ -243,7 +243,7 @@ impl MultilargeLinearWorkspace {
         }))
     }
 
-    #[cfg(feature = "v2_7")]
+    #[cfg(any(feature = "v2_7", gsl_v2_7))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     #[doc(alias = "gsl_multilarge_linear_rhs_ptr")]
     pub fn rhs<F: FnOnce(&VectorF64)>(&self, f: F) {
@@ -2,7 +2,8 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-#[cfg(feature = "v2_2")]
+use crate::paste::paste;
+#[cfg(any(feature = "v2_2", gsl_v2_2))]
 use crate::MatrixF64;
 use crate::Value;
 use crate::{MatrixComplexF32, MatrixComplexF64, MatrixF32, VectorF64};
@@ -12,6 +13,28 @@ use std::fmt::{Debug, Formatter};
 
 ffi_wrapper!(Permutation, *mut sys::gsl_permutation, gsl_permutation_free);
 
+macro_rules! permute_vector {
+    ($suffix:ident, $vector:ident) => {
+        paste! {
+            #[doc = "Same as [`permute_vector`](#method.permute_vector), for [`" $vector "`](struct." $vector ".html)."]
+            #[doc(alias = "gsl_permute_vector_" $suffix)]
+            pub fn [<permute_vector_ $suffix>](&self, v: &mut ::$vector) -> Value {
+                Value::from(unsafe {
+                    sys::[<gsl_permute_vector_ $suffix>](self.unwrap_shared(), v.unwrap_unique())
+                })
+            }
+
+            #[doc = "Same as [`permute_vector_inverse`](#method.permute_vector_inverse), for [`" $vector "`](struct." $vector ".html)."]
+            #[doc(alias = "gsl_permute_vector_" $suffix "_inverse")]
+            pub fn [<permute_vector_ $suffix _inverse>](&self, v: &mut ::$vector) -> Value {
+                Value::from(unsafe {
+                    sys::[<gsl_permute_vector_ $suffix _inverse>](self.unwrap_shared(), v.unwrap_unique())
+                })
+            }
+        }
+    };
+}
+
 /// ## Permutations in cyclic form
 ///
 /// A permutation can be represented in both linear and cyclic notations. The functions described in this section convert between the two forms.
@@ -185,7 +208,20 @@ impl Permutation {
         })
     }
 
-    #[cfg(feature = "v2_2")]
+    permute_vector!(float, VectorF32);
+    permute_vector!(int, VectorI32);
+    permute_vector!(uint, VectorU32);
+    #[cfg(gsl_long_64)]
+    permute_vector!(long, VectorI64);
+    #[cfg(gsl_long_64)]
+    permute_vector!(ulong, VectorU64);
+    permute_vector!(short, VectorI16);
+    permute_vector!(ushort, VectorU16);
+    #[cfg(gsl_char_signed)]
+    permute_vector!(char, VectorI8);
+    permute_vector!(uchar, VectorU8);
+
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     #[doc(alias = "gsl_permute_matrix")]
     pub fn permute_matrix(&self, A: &mut MatrixF64) -> Value {
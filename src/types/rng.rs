@@ -407,6 +407,52 @@ impl Rng {
         (x, y)
     }
 
+    /// This function generates a random vector satisfying the k-dimensional multivariate Gaussian distribution with mean mu and
+    /// variance-covariance matrix \Sigma = L L^T, storing it in result. On input, L must be the lower triangular Cholesky factor of
+    /// \Sigma (see `randist::multivariate_gaussian::cholesky_factor`); its upper triangle is not referenced.
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[doc(alias = "gsl_ran_multivariate_gaussian")]
+    pub fn multivariate_gaussian(
+        &mut self,
+        mu: &::VectorF64,
+        L: &::MatrixF64,
+        result: &mut ::VectorF64,
+    ) -> Value {
+        Value::from(unsafe {
+            sys::gsl_ran_multivariate_gaussian(
+                self.unwrap_unique(),
+                mu.unwrap_shared(),
+                L.unwrap_shared(),
+                result.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function generates a random p-by-p symmetric positive-definite matrix from the Wishart distribution with n = df degrees
+    /// of freedom and scale matrix V = L L^T, storing it in result. L is the lower triangular Cholesky factor of V and work is a
+    /// p-by-p workspace. df must be greater than p - 1.
+    #[cfg(any(feature = "v2_5", gsl_v2_5))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = "gsl_ran_wishart")]
+    pub fn wishart(
+        &mut self,
+        df: f64,
+        L: &::MatrixF64,
+        result: &mut ::MatrixF64,
+        work: &mut ::MatrixF64,
+    ) -> Value {
+        Value::from(unsafe {
+            sys::gsl_ran_wishart(
+                self.unwrap_unique(),
+                df,
+                L.unwrap_shared(),
+                result.unwrap_unique(),
+                work.unwrap_unique(),
+            )
+        })
+    }
+
     /// This function returns a random variate from the Cauchy distribution with scale parameter a. The probability distribution for Cauchy random variates is,
     ///
     /// p(x) dx = {1 \over a\pi (1 + (x/a)^2) } dx
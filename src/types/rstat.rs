@@ -22,7 +22,7 @@ impl RStatQuantileWorkspace {
         }
     }
 
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     #[doc(alias = "gsl_rstat_quantile_reset")]
     pub fn reset(&mut self) -> Value {
@@ -92,7 +92,7 @@ impl RStatWorkspace {
         unsafe { sys::gsl_rstat_sd(self.unwrap_shared()) }
     }
 
-    #[cfg(feature = "v2_2")]
+    #[cfg(any(feature = "v2_2", gsl_v2_2))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     #[doc(alias = "gsl_rstat_rms")]
     pub fn rms(&self) -> f64 {
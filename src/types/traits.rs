@@ -0,0 +1,663 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Generic vectors and matrices
+
+The vector and matrix types of this crate are generated for each GSL element type (`VectorF64` wraps `gsl_vector`,
+`VectorF32` wraps `gsl_vector_float`, `MatrixComplexF64` wraps `gsl_matrix_complex`, ...). The
+[`GslVector`](trait.GslVector.html) and [`GslMatrix`](trait.GslMatrix.html) traits expose their common operations, and
+[`GslScalar`](trait.GslScalar.html) maps an element type to its vector and matrix types, so routines can be written once
+for all of them:
+
+```
+use rgsl::{GslScalar, GslVector, VectorF64, VectorI32};
+
+fn sum<V: GslVector>(v: &V) -> V::Elem
+where
+    V::Elem: std::ops::Add<Output = V::Elem> + Default,
+{
+    (0..v.len()).fold(V::Elem::default(), |acc, i| acc + v.get(i))
+}
+
+fn ramp<T: GslScalar + From<u8>>(n: u8) -> T::Vector {
+    let mut v = T::Vector::new(n as usize).unwrap();
+    for i in 0..n {
+        v.set(i as usize, T::from(i));
+    }
+    v
+}
+
+assert_eq!(sum(&VectorI32::from_slice(&[1, 2, 3]).unwrap()), 6);
+assert_eq!(sum(&ramp::<f64>(4)), 6.);
+```
+!*/
+
+use crate::paste::paste;
+use crate::Value;
+use ffi::FFI;
+use types::{ComplexF32, ComplexF64};
+
+/// The operations shared by all the GSL vector types.
+///
+/// Views are reached through closures, like with the `vector_mut` method of the view types: the view is only valid
+/// while the closure runs.
+///
+/// # Safety
+///
+/// Unless `as_ptr` returns a null pointer, it must point to `len()` valid elements laid out `stride()` elements apart,
+/// which stay alive as long as `self` is borrowed: the provided slice methods rely on it.
+pub unsafe trait GslVector: Sized {
+    /// The type of the elements.
+    type Elem: Copy;
+
+    /// Creates a new vector of length `size` with all elements set to zero.
+    fn new(size: usize) -> Option<Self>;
+    /// Creates a new vector holding a copy of `slice`.
+    fn from_slice(slice: &[Self::Elem]) -> Option<Self>;
+    /// Returns the number of elements.
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns the step, in elements, from one element to the next in memory.
+    fn stride(&self) -> usize;
+    /// Returns a pointer to the first element, or a null pointer if the vector has no data.
+    fn as_ptr(&self) -> *const Self::Elem;
+    /// Returns the elements as a slice. Returns `None` if the vector has no data or its stride isn't 1.
+    fn as_slice(&self) -> Option<&[Self::Elem]> {
+        let ptr = self.as_ptr();
+        if ptr.is_null() || self.stride() != 1 {
+            None
+        } else {
+            Some(unsafe { ::std::slice::from_raw_parts(ptr, self.len()) })
+        }
+    }
+    /// Returns the elements as a mutable slice. Returns `None` if the vector has no data or its stride isn't 1.
+    fn as_slice_mut(&mut self) -> Option<&mut [Self::Elem]> {
+        let ptr = self.as_ptr() as *mut Self::Elem;
+        if ptr.is_null() || self.stride() != 1 {
+            None
+        } else {
+            Some(unsafe { ::std::slice::from_raw_parts_mut(ptr, self.len()) })
+        }
+    }
+    /// Returns the i-th element. If i is out of range, the error handler is invoked and 0 is returned.
+    fn get(&self, i: usize) -> Self::Elem;
+    /// Sets the i-th element to x. If i is out of range, the error handler is invoked.
+    fn set(&mut self, i: usize, x: Self::Elem);
+    /// Sets all the elements to x.
+    fn set_all(&mut self, x: Self::Elem);
+    /// Sets all the elements to zero.
+    fn set_zero(&mut self);
+    /// Copies the elements of `other`, which must have the same length.
+    fn copy_from(&mut self, other: &Self) -> Value;
+    /// Exchanges the elements with the ones of `other`, which must have the same length.
+    fn swap(&mut self, other: &mut Self) -> Value;
+    /// Multiplies the elements by x.
+    fn scale(&mut self, x: Self::Elem) -> Value;
+    /// Adds the elements of `other`, which must have the same length.
+    fn add(&mut self, other: &Self) -> Value;
+    /// Subtracts the elements of `other`, which must have the same length.
+    fn sub(&mut self, other: &Self) -> Value;
+    /// Calls `f` with a view of the n elements starting at `offset`, or with `None` if they overrun the vector.
+    fn with_subvector<F: FnOnce(Option<&mut Self>)>(&mut self, offset: usize, n: usize, f: F);
+    /// Calls `f` with a view of the n elements `offset`, `offset + stride`, ..., or with `None` if they overrun the
+    /// vector.
+    fn with_subvector_with_stride<F: FnOnce(Option<&mut Self>)>(
+        &mut self,
+        offset: usize,
+        stride: usize,
+        n: usize,
+        f: F,
+    );
+}
+
+/// The operations shared by all the GSL matrix types.
+///
+/// # Safety
+///
+/// Unless `as_ptr` returns a null pointer, it must point to `size1()` rows of `size2()` valid elements, which start
+/// `tda()` elements apart and stay alive as long as `self` is borrowed: the provided slice methods rely on it.
+pub unsafe trait GslMatrix: Sized {
+    /// The type of the elements.
+    type Elem: Copy;
+    /// The vector type with the same elements, used for rows and columns.
+    type Vector: GslVector<Elem = Self::Elem>;
+
+    /// Creates a new n1 x n2 matrix with all elements set to zero.
+    fn new(n1: usize, n2: usize) -> Option<Self>;
+    /// Returns the number of rows.
+    fn size1(&self) -> usize;
+    /// Returns the number of columns.
+    fn size2(&self) -> usize;
+    /// Returns the physical row length, in elements.
+    fn tda(&self) -> usize;
+    /// Returns a pointer to the first element, or a null pointer if the matrix has no data.
+    fn as_ptr(&self) -> *const Self::Elem;
+    /// Returns the elements, in row-major order, as a slice. Returns `None` if the matrix has no data or its rows
+    /// aren't contiguous.
+    fn as_slice(&self) -> Option<&[Self::Elem]> {
+        let ptr = self.as_ptr();
+        if ptr.is_null() || (self.size1() > 1 && self.tda() != self.size2()) {
+            None
+        } else {
+            Some(unsafe { ::std::slice::from_raw_parts(ptr, self.size1() * self.size2()) })
+        }
+    }
+    /// Returns the elements, in row-major order, as a mutable slice. Returns `None` if the matrix has no data or its
+    /// rows aren't contiguous.
+    fn as_slice_mut(&mut self) -> Option<&mut [Self::Elem]> {
+        let ptr = self.as_ptr() as *mut Self::Elem;
+        if ptr.is_null() || (self.size1() > 1 && self.tda() != self.size2()) {
+            None
+        } else {
+            Some(unsafe { ::std::slice::from_raw_parts_mut(ptr, self.size1() * self.size2()) })
+        }
+    }
+    /// Returns the (i,j)-th element. If i or j is out of range, the error handler is invoked and 0 is returned.
+    fn get(&self, i: usize, j: usize) -> Self::Elem;
+    /// Sets the (i,j)-th element to x. If i or j is out of range, the error handler is invoked.
+    fn set(&mut self, i: usize, j: usize, x: Self::Elem);
+    /// Sets all the elements to x.
+    fn set_all(&mut self, x: Self::Elem);
+    /// Sets all the elements to zero.
+    fn set_zero(&mut self);
+    /// Sets the matrix to the identity matrix.
+    fn set_identity(&mut self);
+    /// Copies the elements of `other`, which must have the same dimensions.
+    fn copy_from(&mut self, other: &Self) -> Value;
+    /// Exchanges the elements with the ones of `other`, which must have the same dimensions.
+    fn swap(&mut self, other: &mut Self) -> Value;
+    /// Multiplies the elements by x.
+    fn scale(&mut self, x: Self::Elem) -> Value;
+    /// Adds the elements of `other`, which must have the same dimensions.
+    fn add(&mut self, other: &Self) -> Value;
+    /// Subtracts the elements of `other`, which must have the same dimensions.
+    fn sub(&mut self, other: &Self) -> Value;
+    /// Copies the i-th row into the returned vector.
+    fn get_row(&self, i: usize) -> Option<(Value, Self::Vector)>;
+    /// Copies the j-th column into the returned vector.
+    fn get_col(&self, j: usize) -> Option<(Value, Self::Vector)>;
+    /// Copies `v` into the i-th row.
+    fn set_row(&mut self, i: usize, v: &Self::Vector) -> Value;
+    /// Copies `v` into the j-th column.
+    fn set_col(&mut self, j: usize, v: &Self::Vector) -> Value;
+    /// Calls `f` with a view of the i-th row, or with `None` if i is out of range.
+    fn with_row<F: FnOnce(Option<&mut Self::Vector>)>(&mut self, i: usize, f: F);
+    /// Calls `f` with a view of the j-th column, or with `None` if j is out of range.
+    fn with_column<F: FnOnce(Option<&mut Self::Vector>)>(&mut self, j: usize, f: F);
+    /// Calls `f` with a view of the diagonal.
+    fn with_diagonal<F: FnOnce(Option<&mut Self::Vector>)>(&mut self, f: F);
+}
+
+/// An element type GSL provides vectors and matrices for.
+pub trait GslScalar: Copy {
+    /// The GSL vector type, e.g. `gsl_vector_float` for `f32`.
+    type RawVector;
+    /// The GSL matrix type, e.g. `gsl_matrix_float` for `f32`.
+    type RawMatrix;
+    /// The vector type wrapping `RawVector`, e.g. `VectorF32` for `f32`.
+    type Vector: GslVector<Elem = Self> + FFI<Self::RawVector>;
+    /// The matrix type wrapping `RawMatrix`, e.g. `MatrixF32` for `f32`.
+    type Matrix: GslMatrix<Elem = Self, Vector = Self::Vector> + FFI<Self::RawMatrix>;
+}
+
+macro_rules! real_vector {
+    ($vector:ident, $ty:ty) => {
+        paste! {
+            unsafe impl GslVector for ::$vector {
+                type Elem = $ty;
+
+                fn new(size: usize) -> Option<Self> {
+                    ::$vector::new(size)
+                }
+
+                fn from_slice(slice: &[$ty]) -> Option<Self> {
+                    ::$vector::from_slice(slice)
+                }
+
+                fn len(&self) -> usize {
+                    ::$vector::len(self)
+                }
+
+                fn stride(&self) -> usize {
+                    let ptr = self.unwrap_shared();
+                    if ptr.is_null() {
+                        1
+                    } else {
+                        unsafe { (*ptr).stride }
+                    }
+                }
+
+                fn as_ptr(&self) -> *const $ty {
+                    let ptr = self.unwrap_shared();
+                    if ptr.is_null() {
+                        ::std::ptr::null()
+                    } else {
+                        unsafe { (*ptr).data.cast::<$ty>() }
+                    }
+                }
+
+                fn get(&self, i: usize) -> $ty {
+                    ::$vector::get(self, i)
+                }
+
+                fn set(&mut self, i: usize, x: $ty) {
+                    ::$vector::set(self, i, x);
+                }
+
+                fn set_all(&mut self, x: $ty) {
+                    ::$vector::set_all(self, x);
+                }
+
+                fn set_zero(&mut self) {
+                    ::$vector::set_zero(self);
+                }
+
+                fn copy_from(&mut self, other: &Self) -> Value {
+                    ::$vector::copy_from(self, other)
+                }
+
+                fn swap(&mut self, other: &mut Self) -> Value {
+                    ::$vector::swap(self, other)
+                }
+
+                fn scale(&mut self, x: $ty) -> Value {
+                    ::$vector::scale(self, x)
+                }
+
+                fn add(&mut self, other: &Self) -> Value {
+                    ::$vector::add(self, other)
+                }
+
+                fn sub(&mut self, other: &Self) -> Value {
+                    ::$vector::sub(self, other)
+                }
+
+                fn with_subvector<F: FnOnce(Option<&mut Self>)>(&mut self, offset: usize, n: usize, f: F) {
+                    ::[<$vector View>]::from_vector(self, offset, n).vector_mut(f)
+                }
+
+                fn with_subvector_with_stride<F: FnOnce(Option<&mut Self>)>(
+                    &mut self,
+                    offset: usize,
+                    stride: usize,
+                    n: usize,
+                    f: F,
+                ) {
+                    ::[<$vector View>]::from_vector_with_stride(self, offset, stride, n).vector_mut(f)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! complex_vector {
+    ($vector:ident, $ty:ident) => {
+        paste! {
+            unsafe impl GslVector for ::$vector {
+                type Elem = $ty;
+
+                fn new(size: usize) -> Option<Self> {
+                    ::$vector::new(size)
+                }
+
+                fn from_slice(slice: &[$ty]) -> Option<Self> {
+                    ::$vector::from_slice(slice)
+                }
+
+                fn len(&self) -> usize {
+                    ::$vector::len(self)
+                }
+
+                fn stride(&self) -> usize {
+                    let ptr = self.unwrap_shared();
+                    if ptr.is_null() {
+                        1
+                    } else {
+                        unsafe { (*ptr).stride }
+                    }
+                }
+
+                fn as_ptr(&self) -> *const $ty {
+                    let ptr = self.unwrap_shared();
+                    if ptr.is_null() {
+                        ::std::ptr::null()
+                    } else {
+                        // the data holds interleaved real and imaginary parts, with the layout of `$ty`
+                        unsafe { (*ptr).data.cast::<$ty>() }
+                    }
+                }
+
+                fn get(&self, i: usize) -> $ty {
+                    ::$vector::get(self, i)
+                }
+
+                fn set(&mut self, i: usize, x: $ty) {
+                    ::$vector::set(self, i, &x);
+                }
+
+                fn set_all(&mut self, x: $ty) {
+                    ::$vector::set_all(self, &x);
+                }
+
+                fn set_zero(&mut self) {
+                    ::$vector::set_zero(self);
+                }
+
+                fn copy_from(&mut self, other: &Self) -> Value {
+                    ::$vector::copy_from(self, other)
+                }
+
+                fn swap(&mut self, other: &mut Self) -> Value {
+                    ::$vector::swap(self, other)
+                }
+
+                fn scale(&mut self, x: $ty) -> Value {
+                    ::$vector::scale(self, &x)
+                }
+
+                fn add(&mut self, other: &Self) -> Value {
+                    ::$vector::add(self, other)
+                }
+
+                fn sub(&mut self, other: &Self) -> Value {
+                    ::$vector::sub(self, other)
+                }
+
+                fn with_subvector<F: FnOnce(Option<&mut Self>)>(&mut self, offset: usize, n: usize, f: F) {
+                    ::[<$vector View>]::from_vector(self, offset, n).vector_mut(f)
+                }
+
+                fn with_subvector_with_stride<F: FnOnce(Option<&mut Self>)>(
+                    &mut self,
+                    offset: usize,
+                    stride: usize,
+                    n: usize,
+                    f: F,
+                ) {
+                    ::[<$vector View>]::from_vector_with_stride(self, offset, stride, n).vector_mut(f)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! matrix {
+    (
+        $matrix:ident,
+        $vector:ident,
+        $ty:ty,
+        $new:ident,
+        |$e:ident| $elem:expr,
+        |$s:ident| $scale:expr
+    ) => {
+        unsafe impl GslMatrix for ::$matrix {
+            type Elem = $ty;
+            type Vector = ::$vector;
+
+            fn new(n1: usize, n2: usize) -> Option<Self> {
+                ::$matrix::$new(n1, n2)
+            }
+
+            fn size1(&self) -> usize {
+                ::$matrix::size1(self)
+            }
+
+            fn size2(&self) -> usize {
+                ::$matrix::size2(self)
+            }
+
+            fn tda(&self) -> usize {
+                let ptr = self.unwrap_shared();
+                if ptr.is_null() {
+                    0
+                } else {
+                    unsafe { (*ptr).tda }
+                }
+            }
+
+            fn as_ptr(&self) -> *const $ty {
+                let ptr = self.unwrap_shared();
+                if ptr.is_null() {
+                    ::std::ptr::null()
+                } else {
+                    unsafe { (*ptr).data.cast::<$ty>() }
+                }
+            }
+
+            fn get(&self, i: usize, j: usize) -> $ty {
+                ::$matrix::get(self, i, j)
+            }
+
+            fn set(&mut self, i: usize, j: usize, $e: $ty) {
+                ::$matrix::set(self, i, j, $elem);
+            }
+
+            fn set_all(&mut self, $e: $ty) {
+                ::$matrix::set_all(self, $elem);
+            }
+
+            fn set_zero(&mut self) {
+                ::$matrix::set_zero(self);
+            }
+
+            fn set_identity(&mut self) {
+                ::$matrix::set_identity(self);
+            }
+
+            fn copy_from(&mut self, other: &Self) -> Value {
+                ::$matrix::copy_from(self, other)
+            }
+
+            fn swap(&mut self, other: &mut Self) -> Value {
+                ::$matrix::swap(self, other)
+            }
+
+            fn scale(&mut self, $s: $ty) -> Value {
+                ::$matrix::scale(self, $scale)
+            }
+
+            fn add(&mut self, other: &Self) -> Value {
+                ::$matrix::add(self, other)
+            }
+
+            fn sub(&mut self, other: &Self) -> Value {
+                ::$matrix::sub(self, other)
+            }
+
+            fn get_row(&self, i: usize) -> Option<(Value, ::$vector)> {
+                ::$matrix::get_row(self, i)
+            }
+
+            fn get_col(&self, j: usize) -> Option<(Value, ::$vector)> {
+                ::$matrix::get_col(self, j)
+            }
+
+            fn set_row(&mut self, i: usize, v: &::$vector) -> Value {
+                ::$matrix::set_row(self, i, v)
+            }
+
+            fn set_col(&mut self, j: usize, v: &::$vector) -> Value {
+                ::$matrix::set_col(self, j, v)
+            }
+
+            fn with_row<F: FnOnce(Option<&mut ::$vector>)>(&mut self, i: usize, f: F) {
+                ::$matrix::row(self, i, |v| match v {
+                    Some(mut v) => v.vector_mut(f),
+                    None => f(None),
+                })
+            }
+
+            fn with_column<F: FnOnce(Option<&mut ::$vector>)>(&mut self, j: usize, f: F) {
+                ::$matrix::column(self, j, |v| match v {
+                    Some(mut v) => v.vector_mut(f),
+                    None => f(None),
+                })
+            }
+
+            fn with_diagonal<F: FnOnce(Option<&mut ::$vector>)>(&mut self, f: F) {
+                ::$matrix::diagonal(self, |v| match v {
+                    Some(mut v) => v.vector_mut(f),
+                    None => f(None),
+                })
+            }
+        }
+    };
+}
+
+macro_rules! scalar {
+    ($ty:ty, $raw_vector:ident, $raw_matrix:ident, $vector:ident, $matrix:ident) => {
+        impl GslScalar for $ty {
+            type RawVector = sys::$raw_vector;
+            type RawMatrix = sys::$raw_matrix;
+            type Vector = ::$vector;
+            type Matrix = ::$matrix;
+        }
+    };
+}
+
+real_vector!(VectorF64, f64);
+real_vector!(VectorF32, f32);
+real_vector!(VectorI32, i32);
+real_vector!(VectorU32, u32);
+#[cfg(gsl_long_64)]
+real_vector!(VectorI64, i64);
+#[cfg(gsl_long_64)]
+real_vector!(VectorU64, u64);
+real_vector!(VectorI16, i16);
+real_vector!(VectorU16, u16);
+#[cfg(gsl_char_signed)]
+real_vector!(VectorI8, i8);
+real_vector!(VectorU8, u8);
+complex_vector!(VectorComplexF64, ComplexF64);
+complex_vector!(VectorComplexF32, ComplexF32);
+
+matrix!(MatrixF64, VectorF64, f64, new, |x| x, |x| x);
+matrix!(MatrixF32, VectorF32, f32, new, |x| x, |x| x as f64);
+matrix!(MatrixI32, VectorI32, i32, new, |x| x, |x| x as f64);
+matrix!(MatrixU32, VectorU32, u32, new, |x| x, |x| x as f64);
+#[cfg(gsl_long_64)]
+matrix!(MatrixI64, VectorI64, i64, new, |x| x, |x| x as f64);
+#[cfg(gsl_long_64)]
+matrix!(MatrixU64, VectorU64, u64, new, |x| x, |x| x as f64);
+matrix!(MatrixI16, VectorI16, i16, new, |x| x, |x| x as f64);
+matrix!(MatrixU16, VectorU16, u16, new, |x| x, |x| x as f64);
+#[cfg(gsl_char_signed)]
+matrix!(MatrixI8, VectorI8, i8, new, |x| x, |x| x as f64);
+matrix!(MatrixU8, VectorU8, u8, new, |x| x, |x| x as f64);
+matrix!(
+    MatrixComplexF64,
+    VectorComplexF64,
+    ComplexF64,
+    new_with_init,
+    |x| &x,
+    |x| &x
+);
+matrix!(
+    MatrixComplexF32,
+    VectorComplexF32,
+    ComplexF32,
+    new_with_init,
+    |x| &x,
+    |x| &x
+);
+
+scalar!(f64, gsl_vector, gsl_matrix, VectorF64, MatrixF64);
+scalar!(
+    f32,
+    gsl_vector_float,
+    gsl_matrix_float,
+    VectorF32,
+    MatrixF32
+);
+scalar!(i32, gsl_vector_int, gsl_matrix_int, VectorI32, MatrixI32);
+scalar!(u32, gsl_vector_uint, gsl_matrix_uint, VectorU32, MatrixU32);
+#[cfg(gsl_long_64)]
+scalar!(i64, gsl_vector_long, gsl_matrix_long, VectorI64, MatrixI64);
+#[cfg(gsl_long_64)]
+scalar!(
+    u64,
+    gsl_vector_ulong,
+    gsl_matrix_ulong,
+    VectorU64,
+    MatrixU64
+);
+scalar!(
+    i16,
+    gsl_vector_short,
+    gsl_matrix_short,
+    VectorI16,
+    MatrixI16
+);
+scalar!(
+    u16,
+    gsl_vector_ushort,
+    gsl_matrix_ushort,
+    VectorU16,
+    MatrixU16
+);
+#[cfg(gsl_char_signed)]
+scalar!(i8, gsl_vector_char, gsl_matrix_char, VectorI8, MatrixI8);
+scalar!(u8, gsl_vector_uchar, gsl_matrix_uchar, VectorU8, MatrixU8);
+scalar!(
+    ComplexF64,
+    gsl_vector_complex,
+    gsl_matrix_complex,
+    VectorComplexF64,
+    MatrixComplexF64
+);
+scalar!(
+    ComplexF32,
+    gsl_vector_complex_float,
+    gsl_matrix_complex_float,
+    VectorComplexF32,
+    MatrixComplexF32
+);
+
+#[test]
+fn generic_vector_matrix() {
+    fn fill<T: GslScalar>(n1: usize, n2: usize, x: T) -> T::Matrix {
+        let mut m = T::Matrix::new(n1, n2).unwrap();
+        m.set_all(x);
+        m
+    }
+
+    fn reverse<V: GslVector>(v: &mut V) {
+        let n = v.len();
+        for i in 0..n / 2 {
+            let (a, b) = (v.get(i), v.get(n - 1 - i));
+            v.set(i, b);
+            v.set(n - 1 - i, a);
+        }
+    }
+
+    let mut v = ::VectorU8::from_slice(&[1, 2, 3, 4, 5]).unwrap();
+    reverse(&mut v);
+    assert_eq!(GslVector::as_slice(&v), Some(&[5, 4, 3, 2, 1][..]));
+    GslVector::with_subvector_with_stride(&mut v, 0, 2, 3, |sub| {
+        let sub = sub.unwrap();
+        assert_eq!(GslVector::as_slice(sub), None);
+        reverse(sub);
+    });
+    assert_eq!(GslVector::as_slice(&v), Some(&[1, 4, 3, 2, 5][..]));
+
+    let mut m = fill::<i32>(2, 3, 7);
+    assert_eq!(GslMatrix::as_slice(&m), Some(&[7; 6][..]));
+    GslMatrix::with_row(&mut m, 1, |row| {
+        let row = row.unwrap();
+        assert_eq!(row.len(), 3);
+        GslVector::scale(row, 2);
+    });
+    assert_eq!(GslMatrix::get(&m, 1, 2), 14);
+    assert_eq!(GslMatrix::get(&m, 0, 2), 7);
+
+    let c = fill::<ComplexF64>(2, 2, ComplexF64::rect(1., 2.));
+    let (_, col) = GslMatrix::get_col(&c, 1).unwrap();
+    assert_eq!(
+        GslVector::as_slice(&col).unwrap()[1],
+        ComplexF64::rect(1., 2.)
+    );
+}
@@ -139,7 +139,7 @@ impl $rust_name {
         if ptr.is_null() {
             None
         } else {
-            Some(unsafe { ::std::slice::from_raw_parts(ptr, self.len()) })
+            Some(unsafe { ::std::slice::from_raw_parts(ptr.cast::<$rust_ty>(), self.len()) })
         }
     }
 
@@ -148,7 +148,7 @@ impl $rust_name {
         if ptr.is_null() {
             None
         } else {
-            Some(unsafe { ::std::slice::from_raw_parts_mut(ptr, self.len()) })
+            Some(unsafe { ::std::slice::from_raw_parts_mut(ptr.cast::<$rust_ty>(), self.len()) })
         }
     }
 
@@ -156,21 +156,21 @@ impl $rust_name {
     /// of 0 to n-1 then the error handler is invoked and 0 is returned.
     #[doc(alias = $name _get)]
     pub fn get(&self, i: usize) -> $rust_ty {
-        unsafe { sys::[<$name _get>](self.unwrap_shared(), i) }
+        unsafe { sys::[<$name _get>](self.unwrap_shared(), i) as _ }
     }
 
     /// This function sets the value of the i-th element of a vector v to x. If i lies outside the
     /// allowed range of 0 to n-1 then the error handler is invoked.
     #[doc(alias = $name _set)]
     pub fn set(&mut self, i: usize, x: $rust_ty) -> &mut $rust_name {
-        unsafe { sys::[<$name _set>](self.unwrap_unique(), i, x) };
+        unsafe { sys::[<$name _set>](self.unwrap_unique(), i, x as _) };
         self
     }
 
     /// This function sets all the elements of the vector v to the value x.
     #[doc(alias = $name _set_all)]
     pub fn set_all(&mut self, x: $rust_ty) -> &mut $rust_name {
-        unsafe { sys::[<$name _set_all>](self.unwrap_unique(), x) };
+        unsafe { sys::[<$name _set_all>](self.unwrap_unique(), x as _) };
         self
     }
 
@@ -261,7 +261,7 @@ impl $rust_name {
     /// result `a_i <- a_i` is stored in `self`.
     #[doc(alias = $name _scale)]
     pub fn scale(&mut self, x: $rust_ty) -> Value {
-        Value::from(unsafe { sys::[<$name _scale>](self.unwrap_unique(), x) })
+        Value::from(unsafe { sys::[<$name _scale>](self.unwrap_unique(), x as _) })
     }
 
     /// This function adds the constant value x to the elements of the self vector. The result
@@ -275,13 +275,13 @@ impl $rust_name {
     /// This function returns the maximum value in the self vector.
     #[doc(alias = $name _max)]
     pub fn max(&self) -> $rust_ty {
-        unsafe { sys::[<$name _max>](self.unwrap_shared()) }
+        unsafe { sys::[<$name _max>](self.unwrap_shared()) as _ }
     }
 
     /// This function returns the minimum value in the self vector.
     #[doc(alias = $name _min)]
     pub fn min(&self) -> $rust_ty {
-        unsafe { sys::[<$name _min>](self.unwrap_shared()) }
+        unsafe { sys::[<$name _min>](self.unwrap_shared()) as _ }
     }
 
     /// This function returns the minimum and maximum values in the self vector.
@@ -293,7 +293,7 @@ impl $rust_name {
         unsafe {
             sys::[<$name _minmax>](self.unwrap_shared(), &mut min_out, &mut max_out);
         }
-        (min_out, max_out)
+        (min_out as _, max_out as _)
     }
 
     /// This function returns the index of the maximum value in the self vector.
@@ -372,9 +372,9 @@ impl $rust_name {
 }
 
 pub struct [<$rust_name View>]<'a> {
-    v: sys::[<$name _view>],
+    pub(crate) v: sys::[<$name _view>],
     #[allow(dead_code)]
-    phantom: PhantomData<&'a ()>,
+    pub(crate) phantom: PhantomData<&'a ()>,
 }
 
 impl<'a> [<$rust_name View>]<'a> {
@@ -486,7 +486,7 @@ impl<'a> [<$rust_name View>]<'a> {
     /// The function gsl_vector_const_view_array is equivalent to gsl_vector_view_array but can be
     /// used for arrays which are declared const.
     #[doc(alias = $name _view_array)]
-    pub fn from_array(base: &'a mut [f64]) -> Self {
+    pub fn from_array(base: &'a mut [$rust_ty]) -> Self {
         unsafe {
             Self {
                 v: sys::[<$name _view_array>](base.as_mut_ptr() as _, base.len() as _),
@@ -502,7 +502,8 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// v'(i) = base[i*stride]
     ///
-    /// where the index i runs from 0 to n-1.
+    /// where the index i runs from 0 to n-1, n being the number of elements of `base` reachable
+    /// with this stride.
     ///
     /// Note that the view gives direct access to the underlying elements of the original array. A
     /// vector view can be passed to any subroutine which takes a vector argument just as a directly
@@ -515,9 +516,9 @@ impl<'a> [<$rust_name View>]<'a> {
         unsafe {
             Self {
                 v: sys::[<$name _view_array_with_stride>](
-                    base.as_mut_ptr(),
+                    base.as_mut_ptr() as _,
                     stride,
-                    base.len() as _,
+                    (base.len() + stride.max(1) - 1) / stride.max(1),
                 ),
                 phantom: PhantomData,
             }
@@ -553,3 +554,14 @@ gsl_vec!(VectorF32, gsl_vector_float, f32);
 gsl_vec!(VectorF64, gsl_vector, f64);
 gsl_vec!(VectorI32, gsl_vector_int, i32);
 gsl_vec!(VectorU32, gsl_vector_uint, u32);
+// `gsl_vector_long` stores C `long`s, which only have 64 bits on these targets.
+#[cfg(gsl_long_64)]
+gsl_vec!(VectorI64, gsl_vector_long, i64);
+#[cfg(gsl_long_64)]
+gsl_vec!(VectorU64, gsl_vector_ulong, u64);
+gsl_vec!(VectorI16, gsl_vector_short, i16);
+gsl_vec!(VectorU16, gsl_vector_ushort, u16);
+// `gsl_vector_char` stores C `char`s, which are only known to be signed on these targets.
+#[cfg(gsl_char_signed)]
+gsl_vec!(VectorI8, gsl_vector_char, i8);
+gsl_vec!(VectorU8, gsl_vector_uchar, u8);
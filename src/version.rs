@@ -0,0 +1,100 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+use std::ffi::CStr;
+use std::fmt;
+
+/// A GSL version, ordered by its components.
+///
+/// ```
+/// use rgsl::GslVersion;
+///
+/// // Fails early instead of at the first call of a missing function.
+/// assert!(rgsl::version() >= GslVersion::required());
+/// assert!(GslVersion::new(2, 7, 0) > GslVersion::parse("2.6+").unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GslVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl GslVersion {
+    pub fn new(major: u32, minor: u32, patch: u32) -> GslVersion {
+        GslVersion {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses a version like `2.7.1`, `2.7` or `2.6+`. A missing patch number is taken as 0.
+    pub fn parse(s: &str) -> Option<GslVersion> {
+        let mut parts = s
+            .trim()
+            .split('.')
+            .map(|p| p.trim_end_matches(|c: char| !c.is_ascii_digit()).parse());
+        let major = parts.next()?.ok()?;
+        let minor = parts.next()?.ok()?;
+        let patch = match parts.next() {
+            Some(p) => p.ok()?,
+            None => 0,
+        };
+        Some(GslVersion::new(major, minor, patch))
+    }
+
+    /// Returns the oldest GSL version providing all the functions enabled by the `v2_*` features of this crate, or by
+    /// the version detected at build time when no such feature is enabled.
+    pub fn required() -> GslVersion {
+        let minor = if cfg!(any(feature = "v2_7", gsl_v2_7)) {
+            7
+        } else if cfg!(any(feature = "v2_6", gsl_v2_6)) {
+            6
+        } else if cfg!(any(feature = "v2_5", gsl_v2_5)) {
+            5
+        } else if cfg!(any(feature = "v2_4", gsl_v2_4)) {
+            4
+        } else if cfg!(any(feature = "v2_3", gsl_v2_3)) {
+            3
+        } else if cfg!(any(feature = "v2_2", gsl_v2_2)) {
+            2
+        } else if cfg!(any(feature = "v2_1", gsl_v2_1)) {
+            1
+        } else {
+            0
+        };
+        GslVersion::new(2, minor, 0)
+    }
+
+    /// Returns the version of the GSL library found when this crate was built (with `pkg-config` or `gsl-config`),
+    /// if any. It can differ from the one loaded at runtime, which is returned by [`version`].
+    pub fn at_build() -> Option<GslVersion> {
+        GslVersion::parse(env!("RGSL_BUILD_GSL_VERSION"))
+    }
+}
+
+impl fmt::Display for GslVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Returns the version of the GSL library loaded at runtime.
+#[doc(alias = "gsl_version")]
+pub fn version() -> GslVersion {
+    let s = unsafe { CStr::from_ptr(sys::gsl_version) };
+    GslVersion::parse(&s.to_string_lossy()).unwrap_or_default()
+}
+
+#[test]
+fn versions() {
+    assert_eq!(GslVersion::parse("2.7.1"), Some(GslVersion::new(2, 7, 1)));
+    assert_eq!(GslVersion::parse("2.6+"), Some(GslVersion::new(2, 6, 0)));
+    assert_eq!(GslVersion::parse("2"), None);
+    assert!(GslVersion::new(2, 10, 0) > GslVersion::new(2, 9, 3));
+    assert_eq!(GslVersion::new(2, 7, 1).to_string(), "2.7.1");
+    assert!(version() >= GslVersion::required());
+    assert!(version().major >= 2);
+}